@@ -0,0 +1,201 @@
+//! Matching sin interfaz: parsea un libro de horarios, lo empareja con un export de
+//! Zoom y escribe los resultados. Con `--expected` compara contra resultados conocidos
+//! (ej: las asignaciones del mes pasado) y termina con código 1 si hay regresiones.
+//!
+//! ```text
+//! minerva-cli <schedules.xlsx> <zoom.json> [--format json|csv] [--output FILE]
+//!             [--expected FILE] [--config FILE] [--dictionary FILE] [--trace]
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use minerva_lib::excel::parser::parse_workbook;
+use minerva_lib::matching::aliases::AliasTable;
+use minerva_lib::matching::config::ConfigIssue;
+use minerva_lib::matching::dictionary::UserDictionary;
+use minerva_lib::matching::report::{compare, to_csv, ZoomExport};
+use minerva_lib::matching::{loader, MatchOptions, MatchResult, MatchingRules, MatchingService};
+
+const USAGE: &str = "\
+Usage: minerva-cli <schedules.xlsx> <zoom.json> [options]
+
+  <zoom.json>        Array of Zoom meetings, or {\"meetings\": [...], \"users\": [...]}
+
+Options:
+  --format json|csv  Output format (default: json)
+  --output FILE      Write results to FILE instead of stdout
+  --expected FILE    Compare against a previous JSON output and report regressions
+  --config FILE      Matching config override (same format as matching.config.json)
+  --dictionary FILE  User dictionary (same format as matching.dictionary.json)
+  --trace            Include the match trace in JSON results";
+
+#[derive(Default)]
+struct Args {
+    schedules: PathBuf,
+    zoom: PathBuf,
+    csv: bool,
+    output: Option<PathBuf>,
+    expected: Option<PathBuf>,
+    config: Option<PathBuf>,
+    dictionary: Option<PathBuf>,
+    trace: bool,
+}
+
+fn parse_args(mut argv: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut args = Args::default();
+    let mut positional = Vec::new();
+
+    while let Some(arg) = argv.next() {
+        let mut value = |name: &str| {
+            argv.next()
+                .ok_or_else(|| format!("{} requires a value", name))
+        };
+        match arg.as_str() {
+            "--format" => match value("--format")?.as_str() {
+                "json" => args.csv = false,
+                "csv" => args.csv = true,
+                other => return Err(format!("Unknown format: {}", other)),
+            },
+            "--output" => args.output = Some(value("--output")?.into()),
+            "--expected" => args.expected = Some(value("--expected")?.into()),
+            "--config" => args.config = Some(value("--config")?.into()),
+            "--dictionary" => args.dictionary = Some(value("--dictionary")?.into()),
+            "--trace" => args.trace = true,
+            _ if arg.starts_with("--") => return Err(format!("Unknown option: {}", arg)),
+            _ => positional.push(PathBuf::from(arg)),
+        }
+    }
+
+    let [schedules, zoom] = <[PathBuf; 2]>::try_from(positional)
+        .map_err(|_| "Expected a schedules workbook and a Zoom JSON export".to_string())?;
+    args.schedules = schedules;
+    args.zoom = zoom;
+    Ok(args)
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, String> {
+    fs::read(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, String> {
+    serde_json::from_slice(&read(path)?).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn issues_message(path: &Path, issues: &[ConfigIssue]) -> String {
+    issues
+        .iter()
+        .map(|i| format!("{}: {}", path.display(), i))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn run(args: Args) -> Result<ExitCode, String> {
+    // 1. Reglas: las incluidas, con el override de --config y el diccionario encima
+    let mut rules = match &args.config {
+        Some(path) => Arc::new(
+            loader::validate_override(read_json(path)?)
+                .map_err(|issues| issues_message(path, &issues))?,
+        ),
+        None => MatchingRules::bundled(),
+    };
+    if let Some(path) = &args.dictionary {
+        let dictionary: UserDictionary = read_json(path)?;
+        rules = Arc::new(
+            rules
+                .with_dictionary(&dictionary)
+                .map_err(|issues| issues_message(path, &issues))?,
+        );
+    }
+
+    // 2. Horarios y datos de Zoom
+    let parsed = parse_workbook(&read(&args.schedules)?)
+        .map_err(|e| format!("{}: {}", args.schedules.display(), e))?;
+    for error in &parsed.errors {
+        eprintln!("{}: {}", args.schedules.display(), error);
+    }
+    let schedules = parsed.schedules;
+    let (meetings, users) = read_json::<ZoomExport>(&args.zoom)?.into_parts();
+    eprintln!(
+        "{} schedules, {} meetings, {} users",
+        schedules.len(),
+        meetings.len(),
+        users.len()
+    );
+
+    // 3. Matching
+    let service = MatchingService::new(meetings, users, rules);
+    let options = MatchOptions {
+        trace: args.trace,
+        ..Default::default()
+    };
+    let outcomes = service
+        .match_batch(
+            &schedules,
+            options,
+            &AliasTable::default(),
+            0,
+            &AtomicBool::new(false),
+            |_, _| {},
+        )
+        .expect("matching is never cancelled");
+    let results: Vec<MatchResult> = schedules
+        .into_iter()
+        .zip(outcomes)
+        .map(|(schedule, outcome)| MatchResult { schedule, outcome })
+        .collect();
+
+    // 4. Salida
+    let output = if args.csv {
+        to_csv(&results)
+    } else {
+        serde_json::to_string_pretty(&results).map_err(|e| e.to_string())? + "\n"
+    };
+    match &args.output {
+        Some(path) => fs::write(path, output).map_err(|e| format!("{}: {}", path.display(), e))?,
+        None => print!("{}", output),
+    }
+
+    // 5. Comparación con los resultados esperados
+    let Some(expected_path) = &args.expected else {
+        return Ok(ExitCode::SUCCESS);
+    };
+    let expected: Vec<MatchResult> = read_json(expected_path)?;
+    let regressions = compare(&expected, &results);
+    for regression in &regressions {
+        eprintln!("{}", regression);
+    }
+    eprintln!(
+        "{} of {} expected rows changed",
+        regressions.len(),
+        expected.len()
+    );
+
+    Ok(if regressions.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+fn main() -> ExitCode {
+    if std::env::args().any(|a| a == "-h" || a == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    run(args).unwrap_or_else(|message| {
+        eprintln!("{}", message);
+        ExitCode::from(2)
+    })
+}
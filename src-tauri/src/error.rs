@@ -0,0 +1,244 @@
+//! Error común de los comandos Tauri.
+//!
+//! Se serializa como `{ code, messageKey, message, details }`:
+//! - `code`: identificador estable para decidir acciones en la UI (ej: "DISK_FULL")
+//! - `messageKey`: clave de `src/locales` (ej: "errors.disk_full"), interpolada con `details`
+//! - `message`: texto en inglés para logs y como respaldo si falta la traducción
+//! - `details`: datos del error (path, nombre de archivo, errores por campo...)
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use serde_json::{json, Value};
+
+use crate::matching::config::ConfigIssue;
+use crate::schedule::FieldError;
+
+#[derive(Debug)]
+pub enum AppError {
+    /// El diálogo nativo falló o retornó un path inválido
+    Dialog(String),
+    /// Sin permisos para leer o escribir en el path
+    PermissionDenied {
+        path: PathBuf,
+    },
+    /// No queda espacio en el disco del path
+    DiskFull {
+        path: PathBuf,
+    },
+    /// El path no existe (ej: unidad compartida desconectada)
+    NotFound {
+        path: PathBuf,
+    },
+    /// Cualquier otro error de entrada/salida
+    Io {
+        path: PathBuf,
+        message: String,
+    },
+    /// El archivo se guardó pero no se pudo abrir con la aplicación predeterminada
+    Opener {
+        path: PathBuf,
+        message: String,
+    },
+    FileTooLarge {
+        name: String,
+        max_size: u64,
+    },
+    FileTypeNotAllowed {
+        name: String,
+    },
+    /// Libro Excel ilegible
+    InvalidWorkbook {
+        name: String,
+        message: String,
+    },
+    /// Filas recibidas del frontend con campos inválidos
+    Validation(Vec<FieldError>),
+    /// `matching.config.json` no cumple el schema o tiene regex inválidas
+    InvalidConfig(Vec<ConfigIssue>),
+    /// Ajustes con claves desconocidas o valores inválidos (pointers relativos a los ajustes)
+    InvalidSettings(Vec<ConfigIssue>),
+    /// Argumentos del IPC mal formados (headers, cuerpo, ids de subida...)
+    InvalidRequest(String),
+    /// La base de datos local (`minerva.db`) falló o contiene datos ilegibles
+    Database(String),
+    /// SQLite reporta la base como dañada o como un archivo que no es una base de datos
+    DatabaseCorrupt(String),
+    /// La operación se canceló antes de terminar (ej: el usuario salió de la vista)
+    Cancelled,
+    /// Se pidió un matching antes de `init_matching` (ej: tras recargar la vista)
+    MatcherNotInitialized,
+    Internal(String),
+}
+
+impl AppError {
+    /// Clasifica un error de IO según su causa (permisos, disco lleno, inexistente)
+    pub fn io(err: io::Error, path: &Path) -> Self {
+        let path = path.to_path_buf();
+        match err.kind() {
+            io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem => {
+                AppError::PermissionDenied { path }
+            }
+            io::ErrorKind::StorageFull | io::ErrorKind::QuotaExceeded => {
+                AppError::DiskFull { path }
+            }
+            io::ErrorKind::NotFound => AppError::NotFound { path },
+            _ => AppError::Io {
+                path,
+                message: err.to_string(),
+            },
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::Dialog(_) => "DIALOG_FAILED",
+            AppError::PermissionDenied { .. } => "PERMISSION_DENIED",
+            AppError::DiskFull { .. } => "DISK_FULL",
+            AppError::NotFound { .. } => "NOT_FOUND",
+            AppError::Io { .. } => "IO_ERROR",
+            AppError::Opener { .. } => "OPENER_FAILED",
+            AppError::FileTooLarge { .. } => "FILE_TOO_LARGE",
+            AppError::FileTypeNotAllowed { .. } => "FILE_TYPE_NOT_ALLOWED",
+            AppError::InvalidWorkbook { .. } => "INVALID_WORKBOOK",
+            AppError::Validation(_) => "VALIDATION_FAILED",
+            AppError::InvalidConfig(_) => "INVALID_CONFIG",
+            AppError::InvalidSettings(_) => "INVALID_SETTINGS",
+            AppError::InvalidRequest(_) => "INVALID_REQUEST",
+            AppError::Database(_) => "DATABASE",
+            AppError::DatabaseCorrupt(_) => "DATABASE_CORRUPT",
+            AppError::Cancelled => "CANCELLED",
+            AppError::MatcherNotInitialized => "MATCHER_NOT_INITIALIZED",
+            AppError::Internal(_) => "INTERNAL",
+        }
+    }
+
+    /// Clave de traducción: "errors." + código en minúsculas
+    pub fn message_key(&self) -> String {
+        format!("errors.{}", self.code().to_lowercase())
+    }
+
+    pub fn details(&self) -> Value {
+        match self {
+            AppError::Dialog(message)
+            | AppError::Database(message)
+            | AppError::DatabaseCorrupt(message)
+            | AppError::InvalidRequest(message)
+            | AppError::Internal(message) => json!({ "message": message }),
+            AppError::PermissionDenied { path }
+            | AppError::DiskFull { path }
+            | AppError::NotFound { path } => json!({ "path": path }),
+            AppError::Io { path, message } | AppError::Opener { path, message } => {
+                json!({ "path": path, "message": message })
+            }
+            AppError::FileTooLarge { name, max_size } => json!({
+                "name": name,
+                "maxSize": max_size,
+                "maxSizeMb": max_size / (1024 * 1024),
+            }),
+            AppError::FileTypeNotAllowed { name } => json!({ "name": name }),
+            AppError::InvalidWorkbook { name, message } => {
+                json!({ "name": name, "message": message })
+            }
+            AppError::Validation(errors) => json!({
+                "count": errors.len(),
+                "errors": errors,
+            }),
+            AppError::InvalidConfig(issues) | AppError::InvalidSettings(issues) => json!({
+                "count": issues.len(),
+                "errors": issues,
+            }),
+            AppError::Cancelled | AppError::MatcherNotInitialized => json!({}),
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::Dialog(message) => write!(f, "Dialog failed: {}", message),
+            AppError::PermissionDenied { path } => {
+                write!(f, "Permission denied: {}", path.display())
+            }
+            AppError::DiskFull { path } => write!(f, "Disk full: {}", path.display()),
+            AppError::NotFound { path } => write!(f, "Not found: {}", path.display()),
+            AppError::Io { path, message } => write!(f, "{}: {}", path.display(), message),
+            AppError::Opener { path, message } => {
+                write!(f, "Could not open {}: {}", path.display(), message)
+            }
+            AppError::FileTooLarge { name, max_size } => write!(
+                f,
+                "File {} exceeds the maximum size of {} MB",
+                name,
+                max_size / (1024 * 1024)
+            ),
+            AppError::FileTypeNotAllowed { name } => write!(f, "File type not allowed: {}", name),
+            AppError::InvalidWorkbook { name, message } if name.is_empty() => {
+                write!(f, "Invalid workbook: {}", message)
+            }
+            AppError::InvalidWorkbook { name, message } => write!(f, "{}: {}", name, message),
+            AppError::Validation(errors) => {
+                let lines: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+                write!(f, "{}", lines.join("\n"))
+            }
+            AppError::InvalidConfig(issues) => {
+                let lines: Vec<String> = issues.iter().map(|i| i.to_string()).collect();
+                write!(f, "Invalid matching config:\n{}", lines.join("\n"))
+            }
+            AppError::InvalidSettings(issues) => {
+                let lines: Vec<String> = issues.iter().map(|i| i.to_string()).collect();
+                write!(f, "Invalid settings:\n{}", lines.join("\n"))
+            }
+            AppError::InvalidRequest(message) => write!(f, "Invalid request: {}", message),
+            AppError::Database(message) => write!(f, "Local database error: {}", message),
+            AppError::DatabaseCorrupt(message) => {
+                write!(f, "Local database is damaged: {}", message)
+            }
+            AppError::Cancelled => write!(f, "Operation cancelled"),
+            AppError::MatcherNotInitialized => write!(f, "Matcher not initialized"),
+            AppError::Internal(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("AppError", 4)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("messageKey", &self.message_key())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("details", &self.details())?;
+        state.end()
+    }
+}
+
+impl From<Vec<FieldError>> for AppError {
+    fn from(errors: Vec<FieldError>) -> Self {
+        AppError::Validation(errors)
+    }
+}
+
+impl From<rusqlite::Error> for AppError {
+    fn from(err: rusqlite::Error) -> Self {
+        match err.sqlite_error_code() {
+            Some(rusqlite::ErrorCode::DatabaseCorrupt | rusqlite::ErrorCode::NotADatabase) => {
+                AppError::DatabaseCorrupt(err.to_string())
+            }
+            _ => AppError::Database(err.to_string()),
+        }
+    }
+}
+
+impl From<tauri::Error> for AppError {
+    fn from(err: tauri::Error) -> Self {
+        AppError::Internal(err.to_string())
+    }
+}
+
+/// Resultado estándar de los comandos
+pub type AppResult<T> = Result<T, AppError>;
@@ -0,0 +1,75 @@
+//! Lectura y escritura de libros Excel (.xlsx / .xls) del lado nativo.
+
+pub mod parser;
+pub mod styles;
+pub mod writer;
+
+use serde_json::Value;
+use tauri::ipc::{InvokeBody, Request};
+
+use crate::error::{AppError, AppResult};
+use crate::files::{save_with_dialog, FileFilter, SaveDialogOptions, SaveKind};
+use crate::schedule;
+use parser::ParsedWorkbook;
+use writer::ExportKind;
+
+/// Parsea un libro de horarios elegido por el usuario y retorna sus filas, junto con
+/// los errores de las filas u hojas que no se pudieron leer.
+///
+/// El frontend envía los bytes del archivo como cuerpo crudo del IPC
+/// (`invoke("parse_excel_file", new Uint8Array(buffer))`) para evitar
+/// serializarlos como un arreglo JSON de números.
+#[tauri::command]
+pub async fn parse_excel_file(request: Request<'_>) -> AppResult<ParsedWorkbook> {
+    let InvokeBody::Raw(bytes) = request.body() else {
+        return Err(AppError::InvalidRequest(
+            "Expected raw binary body with the workbook contents".to_string(),
+        ));
+    };
+    let bytes = bytes.clone();
+
+    // El parseo es CPU-bound: se ejecuta fuera del runtime async para no bloquearlo
+    tauri::async_runtime::spawn_blocking(move || parser::parse_workbook(&bytes))
+        .await
+        .map_err(|e| AppError::Internal(e.to_string()))?
+        .map_err(|e| AppError::InvalidWorkbook {
+            name: String::new(),
+            message: e.to_string(),
+        })
+}
+
+/// Genera el libro con estilos y lo escribe en el path elegido en el diálogo "Guardar Como".
+/// Retorna true si se guardó, false si el usuario canceló.
+#[tauri::command]
+pub async fn export_excel(
+    app: tauri::AppHandle,
+    window: tauri::Window,
+    title: String,
+    default_name: String,
+    kind: ExportKind,
+    rows: Vec<Value>,
+    open_file: bool,
+) -> AppResult<bool> {
+    // 1. Validar y generar el libro ANTES de mostrar el diálogo (CPU-bound: fuera del runtime async)
+    let content = tauri::async_runtime::spawn_blocking(move || {
+        match kind {
+            ExportKind::Schedule => writer::write_schedules(&schedule::from_json_rows(rows)?),
+            ExportKind::Incidences => writer::write_incidences(&schedule::from_json_rows(rows)?),
+        }
+        .map_err(|e| AppError::Internal(e.to_string()))
+    })
+    .await
+    .map_err(|e| AppError::Internal(e.to_string()))??;
+
+    // 2. Mostrar diálogo nativo (solo .xlsx), escribir y abrir si se solicitó
+    let options = SaveDialogOptions {
+        title,
+        default_name,
+        filters: vec![FileFilter::new("Excel Workbook", &["xlsx"])],
+        kind: Some(match kind {
+            ExportKind::Schedule => SaveKind::ScheduleExport,
+            ExportKind::Incidences => SaveKind::IncidenceReport,
+        }),
+    };
+    save_with_dialog(&app, &window, options, &content, open_file).await
+}
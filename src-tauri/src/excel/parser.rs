@@ -0,0 +1,416 @@
+//! Parser de horarios desde Excel.
+//! Portado del parser con SheetJS que corría en el webview (misma salida que `sheet_to_json`).
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::LazyLock;
+
+use calamine::{open_workbook_auto_from_rs, Data, Range, Reader};
+use regex::Regex;
+use serde::Serialize;
+
+use crate::schedule::{FieldError, Schedule, ScheduleDate, ScheduleTime};
+
+// =============================================================================
+// TIPOS DE DATOS
+// =============================================================================
+
+/// Resultado del parseo: las filas válidas y los errores de las que no se pudieron leer
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ParsedWorkbook {
+    pub schedules: Vec<Schedule>,
+    pub errors: Vec<SheetError>,
+}
+
+/// Error de una hoja. `row` es el índice de la fila en la hoja (0 = Fila 1 en Excel).
+#[derive(Debug, Clone, Serialize)]
+pub struct SheetError {
+    pub sheet: String,
+    #[serde(flatten)]
+    pub error: FieldError,
+}
+
+impl std::fmt::Display for SheetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.sheet, self.error)
+    }
+}
+
+// =============================================================================
+// HELPERS ESPECÍFICOS DEL DOMINIO
+// =============================================================================
+
+const BRANCH_KEYWORDS: [&str; 5] = ["CORPORATE", "HUB", "LA MOLINA", "BAW", "KIDS"];
+
+// Mapeo de duraciones: la clave "60" se mapea a "30" por lógica heredada de Python
+const DURATION_MAP: [(&str, &str); 5] = [
+    ("30", "30"),
+    ("45", "45"),
+    ("60", "30"),
+    ("CEIBAL", "45"),
+    ("KIDS", "45"),
+];
+
+// Tags especiales que deben ser filtrados (grupos separados por "|")
+const SPECIAL_TAG_GROUPS: [&str; 4] = [
+    "@Corp | @Corporate",
+    "@Lima 2 | lima2 | @Lima Corporate",
+    "@LC Bulevar Artigas",
+    "@Argentina",
+];
+
+static BRANCH_PATTERNS: LazyLock<Vec<(&'static str, Regex)>> = LazyLock::new(|| {
+    BRANCH_KEYWORDS
+        .iter()
+        .map(|w| (*w, word_regex(w)))
+        .collect()
+});
+
+static DURATION_PATTERNS: LazyLock<Vec<(Regex, &'static str)>> = LazyLock::new(|| {
+    DURATION_MAP
+        .iter()
+        .map(|(keyword, duration)| (word_regex(keyword), *duration))
+        .collect()
+});
+
+static SPECIAL_TAGS: LazyLock<Vec<String>> = LazyLock::new(|| {
+    SPECIAL_TAG_GROUPS
+        .iter()
+        .flat_map(|group| group.split('|'))
+        .map(compact_lowercase)
+        .collect()
+});
+
+static PARENTHESIZED: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\((.*?)\)").unwrap());
+
+/// Regex case-insensitive con límite de palabra para una palabra clave
+fn word_regex(word: &str) -> Regex {
+    Regex::new(&format!(r"(?i)\b{}\b", regex::escape(word))).unwrap()
+}
+
+/// Elimina espacios y pasa a minúsculas (clave de comparación de tags)
+fn compact_lowercase(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase()
+}
+
+/// Extrae contenido entre paréntesis de un texto
+fn extract_parenthesized_content(text: &str) -> String {
+    let matches: Vec<&str> = PARENTHESIZED
+        .captures_iter(text)
+        .filter_map(|c| c.get(1).map(|m| m.as_str()))
+        .collect();
+    if matches.is_empty() {
+        text.to_string()
+    } else {
+        matches.join(", ")
+    }
+}
+
+/// Extrae palabra clave de sucursal del texto
+fn extract_branch_keyword(text: &str) -> Option<&'static str> {
+    BRANCH_PATTERNS
+        .iter()
+        .find(|(_, re)| re.is_match(text))
+        .map(|(word, _)| *word)
+}
+
+/// Filtra tags especiales, retorna None si el texto es un tag especial
+fn filter_special_tags(text: &str) -> Option<&str> {
+    let normalized = compact_lowercase(text);
+    if SPECIAL_TAGS.contains(&normalized) {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+/// Extrae duración del nombre del programa usando el mapeo de duraciones
+fn extract_duration(program_name: &str) -> Option<&'static str> {
+    DURATION_PATTERNS
+        .iter()
+        .find(|(re, _)| re.is_match(program_name))
+        .map(|(_, duration)| *duration)
+}
+
+/// Determina el turno según la hora de inicio
+fn determine_shift(hours: u32) -> &'static str {
+    // P. ZUÑIGA = turno mañana (antes de 14:00)
+    // H. GARCIA = turno tarde (14:00+)
+    if hours < 14 {
+        "P. ZUÑIGA"
+    } else {
+        "H. GARCIA"
+    }
+}
+
+// =============================================================================
+// UTILIDADES DE CELDAS
+// =============================================================================
+
+/// Convierte cualquier celda a string de forma segura (equivalente a `String(val ?? "")`)
+fn cell_to_string(cell: &Data) -> String {
+    match cell {
+        Data::Empty => String::new(),
+        Data::String(s) | Data::DateTimeIso(s) | Data::DurationIso(s) => s.clone(),
+        Data::Float(f) => format_number(*f),
+        Data::DateTime(dt) => format_number(dt.as_f64()),
+        other => other.to_string(),
+    }
+}
+
+/// Formatea un número igual que JavaScript (sin ".0" para enteros)
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        value.to_string()
+    }
+}
+
+/// Valor numérico de la celda, si es un número o fecha serial
+fn cell_number(cell: &Data) -> Option<f64> {
+    match cell {
+        Data::Int(i) => Some(*i as f64),
+        Data::Float(f) => Some(*f),
+        Data::DateTime(dt) => Some(dt.as_f64()),
+        _ => None,
+    }
+}
+
+/// Equivalente a la evaluación "truthy" de JavaScript sobre el valor de la celda
+fn is_truthy(cell: &Data) -> bool {
+    match cell {
+        Data::Empty => false,
+        Data::String(s) => !s.is_empty(),
+        Data::Int(i) => *i != 0,
+        Data::Float(f) => *f != 0.0 && !f.is_nan(),
+        Data::Bool(b) => *b,
+        _ => true,
+    }
+}
+
+/// Convierte una celda de fecha (serial numérico o texto) a fecha tipada
+fn cell_to_date(cell: &Data) -> Result<ScheduleDate, String> {
+    match cell_number(cell) {
+        Some(serial) => ScheduleDate::from_excel_serial(serial)
+            .ok_or_else(|| format!("invalid Excel date serial {}", serial)),
+        // FromStr también acepta strings numéricos (ej: "46044") como serial
+        None => cell_to_string(cell).parse(),
+    }
+}
+
+/// Convierte una celda de tiempo (serial numérico o texto) a hora tipada
+fn cell_to_time(cell: &Data) -> ScheduleTime {
+    match cell_number(cell) {
+        Some(serial) => ScheduleTime::from_excel_serial(serial),
+        None => ScheduleTime::parse_lenient(&cell_to_string(cell)),
+    }
+}
+
+/// Celda en posición (fila, columna) relativa a A1. Las celdas fuera del rango son vacías.
+fn cell(range: &Range<Data>, row: u32, col: u32) -> &Data {
+    static EMPTY: Data = Data::Empty;
+    range.get_value((row, col)).unwrap_or(&EMPTY)
+}
+
+// =============================================================================
+// FUNCIÓN PRINCIPAL DE PARSEO
+// =============================================================================
+
+/// Parsea todas las hojas de un libro Excel a filas de horario.
+/// Una hoja ilegible, una fecha de encabezado inválida (la hoja completa) o una fila con
+/// fecha inválida no se agregan y quedan en `errors`, para mostrarlos en la UI.
+pub fn parse_workbook(bytes: &[u8]) -> Result<ParsedWorkbook, calamine::Error> {
+    let mut workbook = open_workbook_auto_from_rs(Cursor::new(bytes))?;
+    let mut parsed = ParsedWorkbook::default();
+
+    for sheet_name in workbook.sheet_names() {
+        let mut report = |error: FieldError| {
+            parsed.errors.push(SheetError {
+                sheet: sheet_name.clone(),
+                error,
+            })
+        };
+        let range = match workbook.worksheet_range(&sheet_name) {
+            Ok(range) => range,
+            Err(err) => {
+                report(field_error(0, "", err.to_string()));
+                continue;
+            }
+        };
+        // Índices absolutos: fila 0 = Fila 1 en Excel, columna 0 = Columna A
+        let Some((last_row, last_col)) = range.end() else {
+            continue;
+        };
+
+        // Primero intentamos detectar si es un archivo exportado (formato simple)
+        // (Al exportar usamos las claves de la interfaz: date, shift, etc.)
+        let is_exported_format = (0..=last_col).any(|col| {
+            let val = cell_to_string(cell(&range, 0, col));
+            val == "start_time" || val == "instructor" || val == "program"
+        });
+
+        let rows = if is_exported_format {
+            parse_exported_sheet(&range, last_row, last_col, &mut report)
+        } else {
+            // Si no es formato exportado, usamos la lógica compleja original
+            parse_schedule_sheet(&range, last_row).unwrap_or_else(|error| {
+                report(error);
+                Vec::new()
+            })
+        };
+        parsed.schedules.extend(rows);
+    }
+
+    Ok(parsed)
+}
+
+fn field_error(row: u32, field: &str, message: String) -> FieldError {
+    FieldError {
+        row: row as usize,
+        field: field.to_string(),
+        message,
+    }
+}
+
+/// Parseo simple para archivos exportados (encabezados = claves de `Schedule`)
+fn parse_exported_sheet(
+    range: &Range<Data>,
+    last_row: u32,
+    last_col: u32,
+    report: &mut impl FnMut(FieldError),
+) -> Vec<Schedule> {
+    let headers: Vec<String> = (0..=last_col)
+        .map(|col| cell_to_string(cell(range, 0, col)))
+        .collect();
+
+    let mut schedules = Vec::new();
+    for row in 1..=last_row {
+        let values: HashMap<&str, &Data> = headers
+            .iter()
+            .enumerate()
+            .map(|(col, header)| (header.as_str(), cell(range, row, col as u32)))
+            .collect();
+
+        // Igual que sheet_to_json: omitir filas en blanco
+        if values.values().all(|c| matches!(c, Data::Empty)) {
+            continue;
+        }
+
+        let field = |key: &str| values.get(key).copied().unwrap_or(&Data::Empty);
+
+        let date = match cell_to_date(field("date")) {
+            Ok(date) => date,
+            Err(err) => {
+                report(field_error(row, "date", err));
+                continue;
+            }
+        };
+        let units = match field("units") {
+            Data::String(s) => s.trim().parse::<f64>().unwrap_or(0.0),
+            other => cell_number(other).unwrap_or(0.0),
+        };
+
+        // Normalizar tiempos a 24h para consistencia interna y corregir fechas numéricas
+        schedules.push(Schedule {
+            date,
+            shift: cell_to_string(field("shift")),
+            branch: cell_to_string(field("branch")),
+            start_time: cell_to_time(field("start_time")),
+            end_time: cell_to_time(field("end_time")),
+            code: cell_to_string(field("code")),
+            instructor: cell_to_string(field("instructor")),
+            program: cell_to_string(field("program")),
+            minutes: cell_to_string(field("minutes")),
+            units: units.max(0.0) as u32,
+        });
+    }
+    schedules
+}
+
+/// Parseo del formato original de horarios por instructor
+fn parse_schedule_sheet(range: &Range<Data>, last_row: u32) -> Result<Vec<Schedule>, FieldError> {
+    let mut schedules = Vec::new();
+    if last_row < 5 {
+        return Ok(schedules);
+    }
+
+    // Extraer metadatos del encabezado
+    let schedule_date =
+        cell_to_date(cell(range, 1, 14)).map_err(|err| field_error(1, "date", err))?; // Fila 2, Columna O
+    let location = cell_to_string(cell(range, 1, 21)); // Fila 2, Columna V
+    let instructor_code = cell_to_string(cell(range, 4, 0)); // Fila 5
+    let instructor_name = cell_to_string(cell(range, 5, 0)); // Fila 6
+
+    let branch_name = extract_branch_keyword(&location).unwrap_or("");
+
+    // Contar grupos para cálculo de unidades
+    let mut group_counts: HashMap<String, u32> = HashMap::new();
+    for row in 7..=last_row {
+        let group = cell(range, row, 17);
+        if is_truthy(group) {
+            *group_counts.entry(cell_to_string(group)).or_insert(0) += 1;
+        }
+    }
+
+    // Procesar filas de datos (desde Fila Excel 8 = índice 7)
+    for row in 7..=last_row {
+        let start_time = cell(range, row, 0);
+        let end_time = cell(range, row, 3);
+        let group_cell = cell(range, row, 17); // Columna R
+        let raw_block = cell(range, row, 19); // Columna T
+        let program_name = cell_to_string(cell(range, row, 25)); // Columna Z
+
+        if !is_truthy(start_time) || !is_truthy(end_time) {
+            continue;
+        }
+
+        // Usar bloque como fallback para nombre de grupo
+        let mut group_name = cell_to_string(group_cell);
+        if !is_truthy(group_cell) || group_name.trim().is_empty() {
+            let block = cell_to_string(raw_block);
+            match filter_special_tags(&block) {
+                Some(filtered) if is_truthy(raw_block) && !filtered.trim().is_empty() => {
+                    group_name = filtered.to_string();
+                }
+                _ => continue,
+            }
+        }
+
+        let start = time_from_cell(start_time);
+        let end = time_from_cell(end_time);
+
+        // Determinar sucursal (agregar KIDS si aplica)
+        let program_keyword = extract_branch_keyword(&program_name);
+        let branch = match program_keyword {
+            Some("KIDS") if !branch_name.is_empty() => format!("{}/KIDS", branch_name),
+            _ => branch_name.to_string(),
+        };
+
+        schedules.push(Schedule {
+            date: schedule_date,
+            shift: determine_shift(start.hours()).to_string(),
+            branch,
+            start_time: start,
+            end_time: end,
+            code: instructor_code.clone(),
+            instructor: instructor_name.clone(),
+            units: group_counts.get(&group_name).copied().unwrap_or(0),
+            program: group_name,
+            minutes: extract_duration(&program_name).unwrap_or("0").to_string(),
+        });
+    }
+
+    Ok(schedules)
+}
+
+/// Hora de una celda del formato original: "(08:00 AM)" o serial numérico
+fn time_from_cell(cell: &Data) -> ScheduleTime {
+    match cell_number(cell) {
+        Some(serial) => ScheduleTime::from_excel_serial(serial),
+        None => ScheduleTime::parse_lenient(&extract_parenthesized_content(&cell_to_string(cell))),
+    }
+}
@@ -0,0 +1,42 @@
+//! Estilos de exportación. Mismos valores que `src/features/schedules/utils/excel-styles.ts`.
+
+use rust_xlsxwriter::{Color, Format, TableStyle};
+
+/// Estilo visual de la tabla (Blue Medium)
+pub const TABLE_STYLE: TableStyle = TableStyle::Medium2;
+
+/// Ancho de columnas en caracteres (aprox. ancho de un '0'; 10 chars ≈ 70-80px)
+const COLUMN_WIDTHS: [(&str, f64); 17] = [
+    ("date", 12.0),
+    ("shift", 12.0),
+    ("branch", 15.0),
+    ("start_time", 12.0),
+    ("end_time", 12.0),
+    ("code", 10.0),
+    ("instructor", 25.0),
+    ("program", 40.0),
+    ("minutes", 8.0),
+    ("units", 8.0),
+    ("status", 12.0),
+    ("substitute", 20.0),
+    ("type", 15.0),
+    ("subtype", 15.0),
+    ("description", 60.0),
+    ("department", 20.0),
+    ("feedback", 50.0),
+];
+
+/// Ancho configurado para una columna, si existe
+pub fn column_width(column: &str) -> Option<f64> {
+    COLUMN_WIDTHS
+        .iter()
+        .find(|(name, _)| *name == column)
+        .map(|(_, width)| *width)
+}
+
+/// Formato de filas con cruces (relleno rojo claro con texto rojo oscuro)
+pub fn overlap_row_format() -> Format {
+    Format::new()
+        .set_font_color(Color::RGB(0x9C0006))
+        .set_background_color(Color::RGB(0xFFC7CE))
+}
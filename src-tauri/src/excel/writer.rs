@@ -0,0 +1,193 @@
+//! Escritura de libros de exportación con estilos (tabla, anchos y cruces resaltados).
+
+use std::collections::HashSet;
+
+use rust_xlsxwriter::{Format, Table, TableColumn, Workbook, Worksheet, XlsxError};
+use serde::Deserialize;
+
+use super::styles;
+use crate::schedule::overlap::detect_overlaps;
+use crate::schedule::{DailyIncidence, Schedule};
+
+const SCHEDULE_COLUMNS: [&str; 10] = [
+    "date",
+    "shift",
+    "branch",
+    "start_time",
+    "end_time",
+    "code",
+    "instructor",
+    "program",
+    "minutes",
+    "units",
+];
+
+const INCIDENCE_COLUMNS: [&str; 7] = [
+    "status",
+    "substitute",
+    "type",
+    "subtype",
+    "description",
+    "department",
+    "feedback",
+];
+
+/// Tipo de libro a exportar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportKind {
+    /// Horarios (columnas de `Schedule`)
+    Schedule,
+    /// Reporte de incidencias (columnas de `DailyIncidence`)
+    Incidences,
+}
+
+impl ExportKind {
+    fn sheet_name(self) -> &'static str {
+        match self {
+            ExportKind::Schedule => "Schedule",
+            ExportKind::Incidences => "Incidences",
+        }
+    }
+}
+
+/// Valor de celda a escribir
+enum Cell {
+    Text(String),
+    Number(f64),
+}
+
+/// Previene inyección de fórmulas al abrir/re-exportar como CSV
+fn sanitize(value: &str) -> String {
+    if value.starts_with(['=', '+', '-', '@']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    }
+}
+
+fn schedule_cells(s: &Schedule) -> Vec<Cell> {
+    vec![
+        Cell::Text(s.date.to_string()),
+        Cell::Text(s.shift.clone()),
+        Cell::Text(sanitize(&s.branch)),
+        Cell::Text(s.start_time.format_12h()),
+        Cell::Text(s.end_time.format_12h()),
+        Cell::Text(s.code.clone()),
+        Cell::Text(sanitize(&s.instructor)),
+        Cell::Text(sanitize(&s.program)),
+        Cell::Text(s.minutes.clone()),
+        Cell::Number(s.units as f64),
+    ]
+}
+
+fn incidence_cells(incidence: &DailyIncidence) -> Vec<Cell> {
+    let extra = [
+        &incidence.status,
+        &incidence.substitute,
+        &incidence.kind,
+        &incidence.subtype,
+        &incidence.description,
+        &incidence.department,
+        &incidence.feedback,
+    ];
+    let mut cells = schedule_cells(&incidence.schedule);
+    cells.extend(
+        extra
+            .into_iter()
+            .map(|value| Cell::Text(sanitize(value.as_deref().unwrap_or_default()))),
+    );
+    cells
+}
+
+/// Genera el libro de horarios (.xlsx) en memoria
+pub fn write_schedules(schedules: &[Schedule]) -> Result<Vec<u8>, XlsxError> {
+    let rows: Vec<Vec<Cell>> = schedules.iter().map(schedule_cells).collect();
+    write_sheet(ExportKind::Schedule, &SCHEDULE_COLUMNS, rows, schedules)
+}
+
+/// Genera el reporte de incidencias (.xlsx) en memoria
+pub fn write_incidences(incidences: &[DailyIncidence]) -> Result<Vec<u8>, XlsxError> {
+    let columns: Vec<&str> = SCHEDULE_COLUMNS
+        .iter()
+        .chain(INCIDENCE_COLUMNS.iter())
+        .copied()
+        .collect();
+    let rows: Vec<Vec<Cell>> = incidences.iter().map(incidence_cells).collect();
+    let schedules: Vec<Schedule> = incidences.iter().map(|i| i.schedule.clone()).collect();
+    write_sheet(ExportKind::Incidences, &columns, rows, &schedules)
+}
+
+fn write_sheet(
+    kind: ExportKind,
+    columns: &[&str],
+    rows: Vec<Vec<Cell>>,
+    schedules: &[Schedule],
+) -> Result<Vec<u8>, XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_name(kind.sheet_name())?;
+
+    // Filas con cruces de horario o clases duplicadas
+    let overlapping: HashSet<usize> = detect_overlaps(schedules)
+        .rows
+        .iter()
+        .map(|r| r.row)
+        .collect();
+    let default_format = Format::new();
+    let overlap_format = styles::overlap_row_format();
+
+    for (col, name) in columns.iter().enumerate() {
+        let col = col as u16;
+        worksheet.write_string(0, col, *name)?;
+        if let Some(width) = styles::column_width(name) {
+            worksheet.set_column_width(col, width)?;
+        }
+    }
+
+    for (index, cells) in rows.into_iter().enumerate() {
+        let row = index as u32 + 1;
+        let format = if overlapping.contains(&index) {
+            &overlap_format
+        } else {
+            &default_format
+        };
+        write_row(worksheet, row, cells, format)?;
+    }
+
+    // La tabla necesita al menos una fila de datos además del encabezado
+    if !schedules.is_empty() {
+        let table_columns: Vec<TableColumn> = columns
+            .iter()
+            .map(|name| TableColumn::new().set_header(*name))
+            .collect();
+        let table = Table::new()
+            .set_style(styles::TABLE_STYLE)
+            .set_columns(&table_columns);
+        worksheet.add_table(
+            0,
+            0,
+            schedules.len() as u32,
+            columns.len() as u16 - 1,
+            &table,
+        )?;
+    }
+
+    workbook.save_to_buffer()
+}
+
+fn write_row(
+    worksheet: &mut Worksheet,
+    row: u32,
+    cells: Vec<Cell>,
+    format: &Format,
+) -> Result<(), XlsxError> {
+    for (col, cell) in cells.into_iter().enumerate() {
+        let col = col as u16;
+        match cell {
+            Cell::Text(text) => worksheet.write_string_with_format(row, col, text, format)?,
+            Cell::Number(number) => worksheet.write_number_with_format(row, col, number, format)?,
+        };
+    }
+    Ok(())
+}
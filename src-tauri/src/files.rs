@@ -0,0 +1,812 @@
+//! Guardado y apertura de archivos fuera del sandbox mediante los diálogos nativos.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use percent_encoding::percent_decode_str;
+use serde::{Deserialize, Serialize};
+use tauri::async_runtime::{channel, Sender};
+use tauri::ipc::{InvokeBody, Request};
+use tauri::{Manager, State, WindowEvent};
+use tauri_plugin_dialog::{DialogExt, FilePath};
+use tauri_plugin_opener::OpenerExt;
+
+use crate::error::{AppError, AppResult};
+use crate::excel::parser::{parse_workbook, SheetError};
+use crate::schedule::Schedule;
+
+// Headers usados por las variantes binarias (valores codificados con encodeURIComponent)
+const HEADER_TITLE: &str = "x-save-title";
+const HEADER_DEFAULT_NAME: &str = "x-save-default-name";
+const HEADER_OPEN_FILE: &str = "x-save-open-file";
+const HEADER_FILTERS: &str = "x-save-filters";
+const HEADER_KIND: &str = "x-save-kind";
+
+// Último directorio usado por tipo de exportación (AppLocalData)
+const EXPORT_DIRS_FILE: &str = "minerva_export_dirs.json";
+const HEADER_UPLOAD_ID: &str = "x-upload-id";
+
+/// Filtro de tipo de archivo del diálogo (mismo formato que `DialogFilter` de JS)
+#[derive(Debug, Clone, Deserialize)]
+pub struct FileFilter {
+    pub name: String,
+    /// Extensiones sin punto (ej: "xlsx"); "*" acepta cualquiera
+    pub extensions: Vec<String>,
+}
+
+impl FileFilter {
+    pub fn new(name: &str, extensions: &[&str]) -> Self {
+        Self {
+            name: name.to_string(),
+            extensions: extensions.iter().map(|e| e.to_string()).collect(),
+        }
+    }
+}
+
+/// Tipo de exportación; cada uno recuerda su propio directorio
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SaveKind {
+    ScheduleExport,
+    IncidenceReport,
+    ZoomReport,
+}
+
+/// Opciones del diálogo "Guardar Como"
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveDialogOptions {
+    pub title: String,
+    pub default_name: String,
+    #[serde(default)]
+    pub filters: Vec<FileFilter>,
+    /// Sin tipo el diálogo abre en el directorio por defecto del sistema
+    #[serde(default)]
+    pub kind: Option<SaveKind>,
+}
+
+/// Resultado de esperar un diálogo nativo
+enum DialogOutcome<T> {
+    Picked(Option<T>),
+    WindowClosed,
+}
+
+type CancelDialog = Box<dyn FnOnce() + Send>;
+
+/// Diálogos nativos esperando respuesta, por ventana.
+/// Cada ventana registra un solo handler de eventos (con su primer diálogo), que cancela
+/// los diálogos pendientes cuando la ventana se destruye. `CloseRequested` no cancela:
+/// el cierre todavía se puede impedir y, si no se impide, llega `Destroyed`.
+#[derive(Default)]
+pub struct PendingDialogs {
+    next_id: AtomicU64,
+    windows: Mutex<HashMap<String, HashMap<u64, CancelDialog>>>,
+}
+
+impl PendingDialogs {
+    fn register<T: Send + 'static>(
+        &self,
+        window: &tauri::Window,
+        tx: Sender<DialogOutcome<T>>,
+    ) -> u64 {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let label = window.label().to_string();
+        let mut windows = self.windows.lock().unwrap();
+        let pending = windows.entry(label.clone()).or_insert_with(|| {
+            let app = window.app_handle().clone();
+            window.on_window_event(move |event| {
+                if matches!(event, WindowEvent::Destroyed) {
+                    app.state::<PendingDialogs>().cancel_window(&label);
+                }
+            });
+            HashMap::new()
+        });
+        pending.insert(
+            id,
+            Box::new(move || {
+                let _ = tx.try_send(DialogOutcome::WindowClosed);
+            }),
+        );
+        id
+    }
+
+    /// Cancela los diálogos de una ventana destruida. Si se vuelve a crear una ventana
+    /// con el mismo label, su primer diálogo registra un handler nuevo.
+    fn cancel_window(&self, label: &str) {
+        let pending = self.windows.lock().unwrap().remove(label);
+        for cancel in pending.into_iter().flat_map(HashMap::into_values) {
+            cancel();
+        }
+    }
+
+    fn finish(&self, label: &str, id: u64) {
+        if let Some(pending) = self.windows.lock().unwrap().get_mut(label) {
+            pending.remove(&id);
+        }
+    }
+}
+
+/// Diálogo registrado en [`PendingDialogs`]; se quita al salir de la espera
+struct PendingDialog<'a> {
+    dialogs: &'a PendingDialogs,
+    label: String,
+    id: u64,
+}
+
+impl Drop for PendingDialog<'_> {
+    fn drop(&mut self) {
+        self.dialogs.finish(&self.label, self.id);
+    }
+}
+
+/// Cancela la espera del diálogo si la ventana se destruye antes de que el usuario responda.
+/// Tras la primera respuesta el receptor ya no existe y el envío se ignora.
+fn cancel_on_close<T: Send + 'static>(
+    window: &tauri::Window,
+    tx: Sender<DialogOutcome<T>>,
+) -> PendingDialog<'_> {
+    let dialogs = window.state::<PendingDialogs>().inner();
+    PendingDialog {
+        dialogs,
+        label: window.label().to_string(),
+        id: dialogs.register(window, tx),
+    }
+}
+
+/// Muestra el diálogo nativo "Guardar Como" y retorna el path elegido (None si se canceló
+/// o si la ventana se cerró con el diálogo abierto)
+pub(crate) async fn pick_save_path(
+    app: &tauri::AppHandle,
+    window: &tauri::Window,
+    options: SaveDialogOptions,
+) -> AppResult<Option<PathBuf>> {
+    // Abrir en el último directorio usado para este tipo de exportación
+    let mut directory = options.kind.and_then(|kind| last_export_dir(app, kind));
+    let mut file_name = options.default_name;
+
+    let path = loop {
+        let Some(path) = show_save_dialog(
+            app,
+            window,
+            &options.title,
+            &file_name,
+            directory.take(),
+            &options.filters,
+        )
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        // Aseguramos que la extensión corresponda a los filtros. Si el path corregido
+        // ya existe, el diálogo no pidió confirmar que se reemplace: se vuelve a mostrar
+        // con el nombre corregido para que el sistema lo pregunte.
+        match resolve_save_path(path, &options.filters) {
+            SavePath::Ready(path) => break path,
+            SavePath::Exists(path) => {
+                directory = path.parent().map(Path::to_path_buf);
+                file_name = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or(file_name);
+            }
+        }
+    };
+
+    // Recordar el directorio elegido (un fallo aquí no debe impedir el guardado)
+    if let (Some(kind), Some(dir)) = (options.kind, path.parent()) {
+        if let Err(e) = remember_export_dir(app, kind, dir) {
+            eprintln!("Could not remember export directory: {}", e);
+        }
+    }
+
+    Ok(Some(path))
+}
+
+/// Muestra el diálogo nativo una vez y retorna el path elegido tal cual
+async fn show_save_dialog(
+    app: &tauri::AppHandle,
+    window: &tauri::Window,
+    title: &str,
+    file_name: &str,
+    directory: Option<PathBuf>,
+    filters: &[FileFilter],
+) -> AppResult<Option<PathBuf>> {
+    // Esto es el Core de la seguridad: El usuario DEBE interactuar para guardar fuera del sandbox
+    // Usamos el callback del diálogo para no bloquear un worker async mientras está abierto
+    let (tx, mut rx) = channel::<DialogOutcome<FilePath>>(2);
+    let _pending = cancel_on_close(window, tx.clone());
+
+    let mut dialog = app
+        .dialog()
+        .file()
+        .set_parent(window)
+        .set_title(title)
+        .set_file_name(file_name);
+    if let Some(dir) = directory {
+        dialog = dialog.set_directory(dir);
+    }
+    for filter in filters {
+        let extensions: Vec<&str> = filter.extensions.iter().map(|e| e.as_str()).collect();
+        dialog = dialog.add_filter(&filter.name, &extensions);
+    }
+
+    dialog.save_file(move |path| {
+        let _ = tx.try_send(DialogOutcome::Picked(path));
+    });
+
+    match rx.recv().await {
+        // Convertimos path a PathBuf para fs::write
+        Some(DialogOutcome::Picked(Some(path))) => path
+            .into_path()
+            .map(Some)
+            .map_err(|e| AppError::Dialog(e.to_string())),
+        Some(DialogOutcome::Picked(None) | DialogOutcome::WindowClosed) | None => Ok(None),
+    }
+}
+
+/// Path elegido en "Guardar Como" tras forzar la extensión
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SavePath {
+    /// Se puede escribir: es el path que confirmó el diálogo o el corregido no existe
+    Ready(PathBuf),
+    /// La extensión corregida apunta a un archivo existente que el diálogo no confirmó
+    Exists(PathBuf),
+}
+
+/// Fuerza la extensión de los filtros y detecta si el path corregido ya existe
+pub fn resolve_save_path(path: PathBuf, filters: &[FileFilter]) -> SavePath {
+    let fixed = enforce_extension(path.clone(), filters);
+    if fixed != path && fixed.exists() {
+        SavePath::Exists(fixed)
+    } else {
+        SavePath::Ready(fixed)
+    }
+}
+
+/// Agrega la extensión del primer filtro si el path no tiene una de las permitidas
+/// (ej: "reporte.txt" con filtro xlsx -> "reporte.txt.xlsx"). Sin filtros no se modifica.
+pub fn enforce_extension(path: PathBuf, filters: &[FileFilter]) -> PathBuf {
+    let Some(allowed) = allowed_extensions(filters) else {
+        return path;
+    };
+    if has_extension(&path, &allowed) {
+        return path;
+    }
+
+    let mut fixed = path.into_os_string();
+    fixed.push(".");
+    fixed.push(&allowed[0]);
+    PathBuf::from(fixed)
+}
+
+/// Extensiones permitidas por los filtros (minúsculas, sin punto).
+/// None si no hay filtros o alguno es comodín ("*"): se acepta cualquier extensión.
+fn allowed_extensions(filters: &[FileFilter]) -> Option<Vec<String>> {
+    let allowed: Vec<String> = filters
+        .iter()
+        .flat_map(|f| &f.extensions)
+        .map(|e| e.trim_start_matches('.').to_lowercase())
+        .filter(|e| !e.is_empty())
+        .collect();
+
+    if allowed.is_empty() || allowed.iter().any(|e| e == "*") {
+        None
+    } else {
+        Some(allowed)
+    }
+}
+
+fn has_extension(path: &Path, allowed: &[String]) -> bool {
+    path.extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .is_some_and(|e| allowed.contains(&e))
+}
+
+// =============================================================================
+// DIRECTORIOS RECIENTES
+// =============================================================================
+
+fn export_dirs_path(app: &tauri::AppHandle) -> AppResult<PathBuf> {
+    Ok(app.path().app_local_data_dir()?.join(EXPORT_DIRS_FILE))
+}
+
+/// Lee el mapa tipo -> directorio; archivo inexistente o corrupto equivale a vacío
+fn read_export_dirs(app: &tauri::AppHandle) -> HashMap<SaveKind, PathBuf> {
+    export_dirs_path(app)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Último directorio usado para el tipo, solo si todavía existe
+/// (ej: una unidad compartida desconectada no debe romper el diálogo)
+fn last_export_dir(app: &tauri::AppHandle, kind: SaveKind) -> Option<PathBuf> {
+    read_export_dirs(app)
+        .remove(&kind)
+        .filter(|dir| dir.is_dir())
+}
+
+fn remember_export_dir(app: &tauri::AppHandle, kind: SaveKind, dir: &Path) -> AppResult<()> {
+    let mut dirs = read_export_dirs(app);
+    if dirs.get(&kind).map(PathBuf::as_path) == Some(dir) {
+        return Ok(());
+    }
+    dirs.insert(kind, dir.to_path_buf());
+
+    write_json_atomic(&export_dirs_path(app)?, &dirs)
+}
+
+/// Escribe JSON en un temporal junto al destino, lo sincroniza a disco y lo renombra:
+/// un cierre a mitad de escritura deja el archivo anterior intacto
+pub(crate) fn write_json_atomic<T: Serialize>(path: &Path, value: &T) -> AppResult<()> {
+    let parent = path.parent().unwrap_or(Path::new("."));
+    fs::create_dir_all(parent).map_err(|e| AppError::io(e, parent))?;
+    let content =
+        serde_json::to_vec_pretty(value).map_err(|e| AppError::Internal(e.to_string()))?;
+    let tmp = path.with_extension("json.tmp");
+    let mut file = fs::File::create(&tmp).map_err(|e| AppError::io(e, &tmp))?;
+    file.write_all(&content)
+        .and_then(|_| file.sync_all())
+        .map_err(|e| AppError::io(e, &tmp))?;
+    fs::rename(&tmp, path).map_err(|e| AppError::io(e, path))?;
+    // El rename queda en disco al sincronizar el directorio (no disponible en Windows)
+    if let Ok(dir) = fs::File::open(parent) {
+        let _ = dir.sync_all();
+    }
+    Ok(())
+}
+
+/// Abre el archivo con la aplicación predeterminada (Feedback visual inmediato)
+pub(crate) fn open_saved_file(app: &tauri::AppHandle, path: &Path) -> AppResult<()> {
+    // Convertir path a string para el plugin opener
+    let path_str = path.to_string_lossy().to_string();
+    app.opener()
+        .open_path(path_str, None::<&str>)
+        .map_err(|e| AppError::Opener {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })
+}
+
+/// Flujo completo de guardado: diálogo, escritura y apertura opcional.
+/// Retorna true si se guardó, false si el usuario canceló.
+pub(crate) async fn save_with_dialog(
+    app: &tauri::AppHandle,
+    window: &tauri::Window,
+    options: SaveDialogOptions,
+    content: &[u8],
+    open_file: bool,
+) -> AppResult<bool> {
+    // 1. Mostrar diálogo nativo "Guardar Como"
+    let Some(path_buf) = pick_save_path(app, window, options).await? else {
+        return Ok(false); // Usuario canceló
+    };
+
+    // 2. Escribir el contenido
+    // Al estar en Rust, esto ignora el sandbox de Tauri (que solo afecta a JS)
+    // PERO es seguro porque el path vino del diálogo del usuario
+    fs::write(&path_buf, content).map_err(|e| AppError::io(e, &path_buf))?;
+
+    // 3. Abrir el archivo si se solicitó
+    if open_file {
+        open_saved_file(app, &path_buf)?;
+    }
+
+    Ok(true) // Guardado exitoso
+}
+
+/// Variante original: el contenido llega como arreglo JSON de números.
+/// Se mantiene como fallback de `save_file_binary`.
+/// `filters` limita los tipos del diálogo y fuerza la extensión del archivo guardado;
+/// `kind` hace que el diálogo abra en el último directorio usado para ese tipo.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)] // Argumentos planos del IPC (API existente)
+pub async fn save_file(
+    app: tauri::AppHandle,
+    window: tauri::Window,
+    title: String,
+    default_name: String,
+    filters: Option<Vec<FileFilter>>,
+    kind: Option<SaveKind>,
+    content: Vec<u8>,
+    open_file: bool,
+) -> AppResult<bool> {
+    let options = SaveDialogOptions {
+        title,
+        default_name,
+        filters: filters.unwrap_or_default(),
+        kind,
+    };
+    save_with_dialog(&app, &window, options, &content, open_file).await
+}
+
+// =============================================================================
+// VARIANTES BINARIAS
+// =============================================================================
+
+/// Lee un header de texto codificado con encodeURIComponent
+fn header(request: &Request<'_>, name: &str) -> AppResult<String> {
+    let invalid = |message: String| AppError::InvalidRequest(format!("{}: {}", name, message));
+    let value = request
+        .headers()
+        .get(name)
+        .ok_or_else(|| invalid("missing header".to_string()))?
+        .to_str()
+        .map_err(|e| invalid(e.to_string()))?;
+    percent_decode_str(value)
+        .decode_utf8()
+        .map(|v| v.into_owned())
+        .map_err(|e| invalid(e.to_string()))
+}
+
+/// Cuerpo crudo del request (Uint8Array/ArrayBuffer enviado desde JS)
+fn raw_body<'a>(request: &'a Request<'_>) -> AppResult<&'a [u8]> {
+    match request.body() {
+        InvokeBody::Raw(bytes) => Ok(bytes),
+        InvokeBody::Json(_) => Err(AppError::InvalidRequest(
+            "Expected raw binary body".to_string(),
+        )),
+    }
+}
+
+/// Igual que `save_file`, pero el contenido llega como cuerpo binario del IPC
+/// (`invoke("save_file_binary", bytes, { headers })`) en lugar de un `number[]`.
+/// Título, nombre, filtros (JSON), tipo y apertura viajan en los headers `x-save-*`.
+#[tauri::command]
+pub async fn save_file_binary(
+    app: tauri::AppHandle,
+    window: tauri::Window,
+    request: Request<'_>,
+) -> AppResult<bool> {
+    let title = header(&request, HEADER_TITLE)?;
+    let default_name = header(&request, HEADER_DEFAULT_NAME)?;
+    let open_file = header(&request, HEADER_OPEN_FILE).is_ok_and(|v| v == "true");
+    let filters = match header(&request, HEADER_FILTERS) {
+        Ok(json) => {
+            serde_json::from_str(&json).map_err(|e| AppError::InvalidRequest(e.to_string()))?
+        }
+        Err(_) => Vec::new(), // Header opcional
+    };
+    let kind = match header(&request, HEADER_KIND) {
+        Ok(kind) => Some(
+            serde_json::from_value(serde_json::Value::String(kind))
+                .map_err(|e| AppError::InvalidRequest(e.to_string()))?,
+        ),
+        Err(_) => None, // Header opcional
+    };
+    let content = raw_body(&request)?;
+
+    let options = SaveDialogOptions {
+        title,
+        default_name,
+        filters,
+        kind,
+    };
+    save_with_dialog(&app, &window, options, content, open_file).await
+}
+
+/// Tamaño máximo de una subida por partes (100 MB)
+pub const MAX_UPLOAD_SIZE: u64 = 100 * 1024 * 1024;
+
+/// Tiempo sin recibir partes tras el cual una subida se considera abandonada
+pub const UPLOAD_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// Subida en curso
+struct Upload {
+    /// Nombre del archivo, solo para los mensajes de error
+    name: String,
+    content: Vec<u8>,
+    /// Última parte recibida
+    updated_at: Instant,
+}
+
+/// Subidas por partes en curso, para archivos demasiado grandes para un solo request
+#[derive(Default)]
+pub struct SaveUploads {
+    next_id: AtomicU32,
+    buffers: Mutex<HashMap<u32, Upload>>,
+}
+
+impl SaveUploads {
+    /// Bloquea las subidas y descarta las que no tuvieron actividad por más de
+    /// [`UPLOAD_TIMEOUT`] (el frontend las empezó y nunca las terminó ni canceló).
+    /// Todas las operaciones pasan por aquí, así una subida abandonada no retiene
+    /// su memoria hasta que empiece otra.
+    fn pruned(&self, now: Instant) -> MutexGuard<'_, HashMap<u32, Upload>> {
+        let mut buffers = self.buffers.lock().unwrap();
+        buffers.retain(|_, upload| now.duration_since(upload.updated_at) < UPLOAD_TIMEOUT);
+        buffers
+    }
+
+    /// Registra una subida nueva y retorna su identificador
+    pub fn start(&self, name: String, now: Instant) -> u32 {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let mut buffers = self.pruned(now);
+        buffers.insert(
+            id,
+            Upload {
+                name,
+                content: Vec::new(),
+                updated_at: now,
+            },
+        );
+        id
+    }
+
+    /// Agrega una parte. Si el total supera [`MAX_UPLOAD_SIZE`] la subida se descarta.
+    pub fn append(&self, id: u32, chunk: &[u8], now: Instant) -> AppResult<()> {
+        let mut buffers = self.pruned(now);
+        let upload = buffers.get_mut(&id).ok_or_else(|| unknown_upload(id))?;
+        if (upload.content.len() + chunk.len()) as u64 > MAX_UPLOAD_SIZE {
+            let upload = buffers.remove(&id).unwrap();
+            return Err(AppError::FileTooLarge {
+                name: upload.name,
+                max_size: MAX_UPLOAD_SIZE,
+            });
+        }
+        upload.content.extend_from_slice(chunk);
+        upload.updated_at = now;
+        Ok(())
+    }
+
+    /// Retira la subida con el contenido acumulado
+    pub fn finish(&self, id: u32, now: Instant) -> AppResult<Vec<u8>> {
+        self.pruned(now)
+            .remove(&id)
+            .map(|upload| upload.content)
+            .ok_or_else(|| unknown_upload(id))
+    }
+
+    /// Descarta la subida (si no existe, ya se había descartado)
+    pub fn cancel(&self, id: u32) {
+        self.buffers.lock().unwrap().remove(&id);
+    }
+}
+
+fn unknown_upload(id: u32) -> AppError {
+    AppError::InvalidRequest(format!("Unknown upload {}", id))
+}
+
+/// Inicia una subida por partes y retorna su identificador.
+/// `name` (opcional) es el nombre del archivo para los mensajes de error.
+#[tauri::command]
+pub fn save_upload_start(uploads: State<'_, SaveUploads>, name: Option<String>) -> u32 {
+    uploads.start(name.unwrap_or_default(), Instant::now())
+}
+
+/// Agrega una parte binaria a la subida indicada en el header `x-upload-id`
+#[tauri::command]
+pub fn save_upload_chunk(uploads: State<'_, SaveUploads>, request: Request<'_>) -> AppResult<()> {
+    let id: u32 = header(&request, HEADER_UPLOAD_ID)?
+        .parse()
+        .map_err(|_| AppError::InvalidRequest("Invalid upload id".to_string()))?;
+    uploads.append(id, raw_body(&request)?, Instant::now())
+}
+
+/// Completa la subida: muestra el diálogo y guarda el contenido acumulado.
+/// La subida se descarta tanto si se guarda como si el usuario cancela.
+#[tauri::command]
+pub async fn save_upload_finish(
+    app: tauri::AppHandle,
+    window: tauri::Window,
+    uploads: State<'_, SaveUploads>,
+    id: u32,
+    options: SaveDialogOptions,
+    open_file: bool,
+) -> AppResult<bool> {
+    let content = uploads.finish(id, Instant::now())?;
+    save_with_dialog(&app, &window, options, &content, open_file).await
+}
+
+/// Descarta una subida por partes (ej: error en el frontend a mitad de la subida)
+#[tauri::command]
+pub fn save_upload_cancel(uploads: State<'_, SaveUploads>, id: u32) {
+    uploads.cancel(id);
+}
+
+// =============================================================================
+// APERTURA
+// =============================================================================
+
+/// Tamaño máximo por defecto de un archivo abierto con `open_file` (20 MB)
+const DEFAULT_MAX_OPEN_SIZE: u64 = 20 * 1024 * 1024;
+
+/// Qué retornar de cada archivo abierto
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpenMode {
+    /// Bytes crudos del archivo
+    Bytes,
+    /// Horarios parseados del libro Excel (ver `excel::parser`)
+    #[default]
+    Schedules,
+}
+
+/// Opciones del diálogo "Abrir"
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenFileOptions {
+    pub title: String,
+    /// Sin filtros, el modo `schedules` solo acepta libros Excel
+    #[serde(default)]
+    pub filters: Vec<FileFilter>,
+    #[serde(default)]
+    pub multiple: bool,
+    /// Tamaño máximo por archivo en bytes (por defecto 20 MB)
+    #[serde(default)]
+    pub max_size: Option<u64>,
+    #[serde(default)]
+    pub mode: OpenMode,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum OpenedContent {
+    Bytes {
+        content: Vec<u8>,
+    },
+    Schedules {
+        schedules: Vec<Schedule>,
+        errors: Vec<SheetError>,
+    },
+}
+
+/// Archivo elegido en el diálogo "Abrir"
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenedFile {
+    /// Solo el nombre, sin el path completo
+    pub name: String,
+    pub size: u64,
+    #[serde(flatten)]
+    pub content: OpenedContent,
+}
+
+/// Archivo elegido que no se pudo abrir
+#[derive(Debug, Serialize)]
+pub struct FailedFile {
+    pub name: String,
+    pub error: AppError,
+}
+
+/// Resultado de `open_file`: un archivo inválido no impide abrir los demás
+#[derive(Debug, Default, Serialize)]
+pub struct OpenedFiles {
+    pub files: Vec<OpenedFile>,
+    pub failed: Vec<FailedFile>,
+}
+
+/// Muestra el diálogo nativo "Abrir" y retorna los paths elegidos (vacío si se canceló)
+async fn pick_open_paths(
+    app: &tauri::AppHandle,
+    window: &tauri::Window,
+    title: String,
+    filters: &[FileFilter],
+    multiple: bool,
+) -> AppResult<Vec<PathBuf>> {
+    let (tx, mut rx) = channel::<DialogOutcome<Vec<FilePath>>>(2);
+    let _pending = cancel_on_close(window, tx.clone());
+
+    let mut dialog = app.dialog().file().set_parent(window).set_title(title);
+    for filter in filters {
+        let extensions: Vec<&str> = filter.extensions.iter().map(|e| e.as_str()).collect();
+        dialog = dialog.add_filter(&filter.name, &extensions);
+    }
+
+    if multiple {
+        dialog.pick_files(move |paths| {
+            let _ = tx.try_send(DialogOutcome::Picked(paths));
+        });
+    } else {
+        dialog.pick_file(move |path| {
+            let _ = tx.try_send(DialogOutcome::Picked(path.map(|p| vec![p])));
+        });
+    }
+
+    match rx.recv().await {
+        Some(DialogOutcome::Picked(Some(paths))) => paths
+            .into_iter()
+            .map(|p| p.into_path().map_err(|e| AppError::Dialog(e.to_string())))
+            .collect(),
+        Some(DialogOutcome::Picked(None) | DialogOutcome::WindowClosed) | None => Ok(Vec::new()),
+    }
+}
+
+/// Valida y lee los archivos elegidos, cada uno por separado (bloqueante: llamar
+/// desde `spawn_blocking`). Los que fallan quedan en `failed` con su error.
+pub fn read_opened_files(
+    paths: Vec<PathBuf>,
+    filters: &[FileFilter],
+    max_size: u64,
+    mode: OpenMode,
+) -> OpenedFiles {
+    let allowed = allowed_extensions(filters);
+    let mut opened = OpenedFiles::default();
+    for path in paths {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        match read_opened_file(&path, name.clone(), allowed.as_deref(), max_size, mode) {
+            Ok(file) => opened.files.push(file),
+            Err(error) => opened.failed.push(FailedFile { name, error }),
+        }
+    }
+    opened
+}
+
+/// Valida y lee un archivo elegido por el usuario
+fn read_opened_file(
+    path: &Path,
+    name: String,
+    allowed: Option<&[String]>,
+    max_size: u64,
+    mode: OpenMode,
+) -> AppResult<OpenedFile> {
+    // 1. El tipo debe coincidir con los filtros (el usuario puede escribir cualquier path)
+    if allowed.is_some_and(|allowed| !has_extension(path, allowed)) {
+        return Err(AppError::FileTypeNotAllowed { name });
+    }
+
+    // 2. Verificar el tamaño ANTES de leer el contenido
+    let size = fs::metadata(path).map_err(|e| AppError::io(e, path))?.len();
+    if size > max_size {
+        return Err(AppError::FileTooLarge { name, max_size });
+    }
+
+    let bytes = fs::read(path).map_err(|e| AppError::io(e, path))?;
+
+    // 3. Parsear si se pidieron horarios
+    let content = match mode {
+        OpenMode::Bytes => OpenedContent::Bytes { content: bytes },
+        OpenMode::Schedules => {
+            let parsed = parse_workbook(&bytes).map_err(|e| AppError::InvalidWorkbook {
+                name: name.clone(),
+                message: e.to_string(),
+            })?;
+            OpenedContent::Schedules {
+                schedules: parsed.schedules,
+                errors: parsed.errors,
+            }
+        }
+    };
+
+    Ok(OpenedFile {
+        name,
+        size,
+        content,
+    })
+}
+
+/// Contraparte de `save_file` para importar: el usuario DEBE elegir el archivo en el
+/// diálogo nativo para leer fuera del sandbox. Rust valida tipo y tamaño antes de leerlo
+/// y, en modo `schedules`, retorna los horarios ya parseados.
+/// Los archivos que no se pueden abrir se informan en `failed` sin descartar el resto.
+/// Retorna listas vacías si el usuario canceló.
+#[tauri::command]
+pub async fn open_file(
+    app: tauri::AppHandle,
+    window: tauri::Window,
+    options: OpenFileOptions,
+) -> AppResult<OpenedFiles> {
+    let filters = match (options.mode, options.filters.is_empty()) {
+        (OpenMode::Schedules, true) => vec![FileFilter::new("Excel Workbook", &["xlsx", "xls"])],
+        _ => options.filters,
+    };
+    let max_size = options.max_size.unwrap_or(DEFAULT_MAX_OPEN_SIZE);
+
+    let paths = pick_open_paths(&app, &window, options.title, &filters, options.multiple).await?;
+
+    // Lectura y parseo bloqueantes: fuera del runtime async
+    let mode = options.mode;
+    tauri::async_runtime::spawn_blocking(move || read_opened_files(paths, &filters, max_size, mode))
+        .await
+        .map_err(|e| AppError::Internal(e.to_string()))
+}
@@ -1,56 +1,18 @@
-use std::fs;
-use tauri_plugin_dialog::DialogExt;
-use tauri_plugin_opener::OpenerExt;
+pub mod error;
+pub mod excel;
+pub mod files;
+pub mod matching;
+pub mod schedule;
+pub mod store;
+pub mod time;
+
+use tauri::Manager;
 
 #[tauri::command]
 fn greet(name: &str) -> String {
     format!("Hello, {}! You've been greeted from Rust!", name)
 }
 
-#[tauri::command]
-async fn save_file(
-    app: tauri::AppHandle,
-    window: tauri::Window,
-    title: String,
-    default_name: String,
-    content: Vec<u8>,
-    open_file: bool,
-) -> Result<bool, String> {
-    // 1. Mostrar diálogo nativo "Guardar Como"
-    // Esto es el Core de la seguridad: El usuario DEBE interactuar para guardar fuera del sandbox
-    // Usamos blocking_save_file para simplificar el flujo async en este comando
-    let file_path = app
-        .dialog()
-        .file()
-        .set_parent(&window)
-        .set_title(title)
-        .set_file_name(default_name)
-        .blocking_save_file();
-
-    // 2. Si el usuario eligió un path (no canceló)
-    if let Some(path) = file_path {
-        // Escribir el contenido
-        // Al estar en Rust, esto ignora el sandbox de Tauri (que solo afecta a JS)
-        // PERO es seguro porque el path vino del diálogo del usuario
-        // Convertimos path a PathBuf para fs::write
-        let path_buf = path.into_path().map_err(|e| e.to_string())?;
-        fs::write(&path_buf, content).map_err(|e| e.to_string())?;
-
-        // 3. Abrir el archivo si se solicitó (Feedback visual inmediato)
-        if open_file {
-            // Convertir path a string para el plugin opener
-            let path_str = path_buf.to_string_lossy().to_string();
-            app.opener()
-                .open_path(path_str, None::<&str>)
-                .map_err(|e| e.to_string())?;
-        }
-
-        Ok(true) // Guardado exitoso
-    } else {
-        Ok(false) // Usuario canceló
-    }
-}
-
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -58,13 +20,95 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_process::init())
+        .manage(files::SaveUploads::default())
+        .manage(files::PendingDialogs::default())
+        .manage(matching::MatcherState::default())
         .setup(|app| {
             #[cfg(desktop)]
             app.handle()
                 .plugin(tauri_plugin_updater::Builder::new().build())?;
+
+            // Base de datos local: se migra antes de que el frontend pueda usarla
+            let data_dir = app.path().app_local_data_dir()?;
+            // Una base dañada se aparta y se empieza con una nueva
+            let (db, recovered) =
+                store::Store::open_or_recover(&data_dir.join(store::DB_FILE), time::now_ms())?;
+            if let Some(recovered) = &recovered {
+                eprintln!(
+                    "Could not open local database ({}); moved it to {}",
+                    recovered.error,
+                    recovered.backup.display()
+                );
+            }
+            app.manage(store::StorageStatus { recovered });
+            // Los JSON anteriores a la base de datos (actuales y legacy) se importan una sola vez
+            let imports = store::import::import_json_files(&db, &data_dir)
+                .into_iter()
+                .chain(store::import::import_legacy_files(&db, &data_dir));
+            for (file, outcome) in imports {
+                match outcome {
+                    Ok(store::import::ImportOutcome::Missing) => {}
+                    Ok(outcome) => eprintln!("Storage migration: {} {:?}", file, outcome),
+                    Err(e) => eprintln!("Storage migration: {} failed: {}", file, e),
+                }
+            }
+            app.manage(db);
+
+            // Un override de configuración inválido no impide iniciar: se usan los valores incluidos
+            let matcher = app.state::<matching::MatcherState>();
+            if let Err(e) = matcher.reload_config(app.handle()) {
+                eprintln!("{}", e);
+            }
+            // Sin alias legibles se empieza con la tabla vacía
+            if let Err(e) = matcher.load_aliases(app.handle()) {
+                eprintln!("{}", e);
+            }
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![greet, save_file])
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            files::save_file,
+            files::save_file_binary,
+            files::save_upload_start,
+            files::save_upload_chunk,
+            files::save_upload_finish,
+            files::save_upload_cancel,
+            files::open_file,
+            excel::parse_excel_file,
+            excel::export_excel,
+            schedule::validate_schedules,
+            schedule::overlap::detect_schedule_overlaps,
+            matching::init_matching,
+            matching::run_matching,
+            matching::cancel_matching,
+            matching::match_topic,
+            matching::reload_matching_config,
+            matching::validate_matching_config,
+            matching::aliases::list_aliases,
+            matching::aliases::learn_alias,
+            matching::aliases::update_alias,
+            matching::aliases::expire_alias,
+            matching::aliases::delete_alias,
+            matching::dictionary::get_matching_dictionary,
+            matching::dictionary::save_matching_dictionary,
+            matching::hosts::check_host_conflicts,
+            matching::allocation::plan_host_allocation,
+            store::get_storage_recovery,
+            store::settings::get_settings,
+            store::settings::update_settings,
+            store::settings::clear_app_settings,
+            store::drafts::load_draft,
+            store::drafts::save_draft,
+            store::drafts::clear_draft,
+            store::history::list_draft_history,
+            store::history::preview_draft_snapshot,
+            store::history::restore_draft_snapshot,
+            store::incidences::load_incidences,
+            store::incidences::save_incidences,
+            store::mirror::load_excel_mirror,
+            store::mirror::save_excel_mirror,
+            store::mirror::clear_excel_mirror
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
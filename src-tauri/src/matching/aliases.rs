@@ -0,0 +1,331 @@
+//! Alias aprendidos de las resoluciones manuales de conflictos.
+//!
+//! Cuando un operador elige el meeting de una fila `ambiguous` (`resolveConflict`),
+//! la elección se guarda en `AppLocalData/matching.aliases.json`, con el programa e
+//! instructor normalizados como clave. En las siguientes ejecuciones el matcher consulta
+//! el alias antes de la búsqueda difusa y asigna ese meeting directamente.
+//!
+//! También se guardan el programa e instructor originales: si cambian las reglas (ej: un
+//! sinónimo nuevo en el diccionario) las claves se vuelven a calcular ([`AliasTable::rekey`])
+//! y los alias siguen aplicando a las mismas filas.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State};
+
+use super::config::MatchingRules;
+use super::MatcherState;
+use crate::error::{AppError, AppResult};
+use crate::files::write_json_atomic;
+use crate::time::now_ms;
+
+/// Nombre del archivo de alias en AppLocalData
+pub const ALIASES_FILE: &str = "matching.aliases.json";
+
+/// Meeting al que apunta un alias: por ID o, si el meeting se recrea, por topic
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AliasTarget {
+    MeetingId(String),
+    Topic(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Alias {
+    pub id: u64,
+    /// Programa normalizado del horario
+    pub program: String,
+    /// Instructor normalizado; vacío aplica a cualquier instructor
+    pub instructor: String,
+    /// Programa e instructor sin normalizar, como llegaron al aprenderlo (vacíos en los
+    /// alias guardados antes de conservarlos: se vuelve a normalizar la clave)
+    #[serde(default)]
+    pub source_program: String,
+    #[serde(default)]
+    pub source_instructor: String,
+    pub target: AliasTarget,
+    /// Fechas en milisegundos desde epoch (como `Date.now()`)
+    pub created_at: i64,
+    pub updated_at: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
+}
+
+/// Resultado de aprender un alias: el alias y el que reemplazó, si había (para deshacer
+/// la selección sin perder un alias de una sesión anterior)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LearnedAlias {
+    pub alias: Alias,
+    pub previous: Option<Alias>,
+}
+
+impl Alias {
+    pub fn is_active(&self, now: i64) -> bool {
+        self.expires_at.is_none_or(|at| at > now)
+    }
+}
+
+/// Contenido de `matching.aliases.json`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AliasTable {
+    next_id: u64,
+    aliases: Vec<Alias>,
+}
+
+impl AliasTable {
+    pub fn aliases(&self) -> &[Alias] {
+        &self.aliases
+    }
+
+    /// Alias vigente para la fila: primero el del instructor, luego el genérico del programa
+    pub fn lookup(&self, program: &str, instructor: &str, now: i64) -> Option<&Alias> {
+        let active = |instructor: &str| {
+            self.aliases
+                .iter()
+                .find(|a| a.program == program && a.instructor == instructor && a.is_active(now))
+        };
+        active(instructor).or_else(|| active(""))
+    }
+
+    /// Guarda (o reemplaza) el alias de programa + instructor (sin normalizar), vigente
+    /// sin vencimiento
+    pub fn learn(
+        &mut self,
+        rules: &MatchingRules,
+        source_program: &str,
+        source_instructor: &str,
+        target: AliasTarget,
+        now: i64,
+    ) -> LearnedAlias {
+        let (program, instructor) = (
+            rules.normalize(source_program),
+            rules.normalize(source_instructor),
+        );
+        if let Some(alias) = self
+            .aliases
+            .iter_mut()
+            .find(|a| a.program == program && a.instructor == instructor)
+        {
+            let previous = alias.clone();
+            alias.source_program = source_program.to_string();
+            alias.source_instructor = source_instructor.to_string();
+            alias.target = target;
+            alias.updated_at = now;
+            alias.expires_at = None;
+            return LearnedAlias {
+                alias: alias.clone(),
+                previous: Some(previous),
+            };
+        }
+
+        self.next_id += 1;
+        let alias = Alias {
+            id: self.next_id,
+            program,
+            instructor,
+            source_program: source_program.to_string(),
+            source_instructor: source_instructor.to_string(),
+            target,
+            created_at: now,
+            updated_at: now,
+            expires_at: None,
+        };
+        self.aliases.push(alias.clone());
+        LearnedAlias {
+            alias,
+            previous: None,
+        }
+    }
+
+    /// Reemplaza destino y vencimiento del alias
+    pub fn update(
+        &mut self,
+        id: u64,
+        target: AliasTarget,
+        expires_at: Option<i64>,
+        now: i64,
+    ) -> Option<Alias> {
+        let alias = self.aliases.iter_mut().find(|a| a.id == id)?;
+        alias.target = target;
+        alias.expires_at = expires_at;
+        alias.updated_at = now;
+        Some(alias.clone())
+    }
+
+    /// Vence el alias en `at` (ahora si es None); se conserva en la lista
+    pub fn expire(&mut self, id: u64, at: Option<i64>, now: i64) -> Option<Alias> {
+        let alias = self.aliases.iter_mut().find(|a| a.id == id)?;
+        alias.expires_at = Some(at.unwrap_or(now));
+        alias.updated_at = now;
+        Some(alias.clone())
+    }
+
+    pub fn remove(&mut self, id: u64) -> bool {
+        let before = self.aliases.len();
+        self.aliases.retain(|a| a.id != id);
+        self.aliases.len() != before
+    }
+
+    /// Vuelve a calcular las claves con otras reglas. Si dos alias quedan con la misma
+    /// clave se conserva el modificado más recientemente. Retorna si algo cambió.
+    pub fn rekey(&mut self, rules: &MatchingRules) -> bool {
+        let before = self.aliases.clone();
+        for alias in &mut self.aliases {
+            let source = |source: &str, key: &str| {
+                rules.normalize(if source.is_empty() { key } else { source })
+            };
+            alias.program = source(&alias.source_program, &alias.program);
+            alias.instructor = source(&alias.source_instructor, &alias.instructor);
+        }
+
+        let mut by_recency: Vec<usize> = (0..self.aliases.len()).collect();
+        by_recency.sort_by_key(|&i| std::cmp::Reverse(self.aliases[i].updated_at));
+        let mut seen = std::collections::HashSet::new();
+        let mut keep = vec![false; self.aliases.len()];
+        for i in by_recency {
+            let alias = &self.aliases[i];
+            keep[i] = seen.insert((alias.program.clone(), alias.instructor.clone()));
+        }
+        let mut keep = keep.into_iter();
+        self.aliases.retain(|_| keep.next().unwrap_or(false));
+
+        self.aliases != before
+    }
+
+    /// Elimina el alias de programa + instructor (ej: el operador deshace la selección)
+    pub fn forget(&mut self, program: &str, instructor: &str) -> bool {
+        let before = self.aliases.len();
+        self.aliases
+            .retain(|a| a.program != program || a.instructor != instructor);
+        self.aliases.len() != before
+    }
+}
+
+// =============================================================================
+// PERSISTENCIA
+// =============================================================================
+
+/// Lee la tabla de alias; si el archivo no existe la tabla está vacía
+pub fn load(path: &Path) -> AppResult<AliasTable> {
+    match fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content).map_err(|e| AppError::Io {
+            path: path.to_path_buf(),
+            message: format!("Invalid aliases file: {}", e),
+        }),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(AliasTable::default()),
+        Err(e) => Err(AppError::io(e, path)),
+    }
+}
+
+pub fn save(table: &AliasTable, path: &Path) -> AppResult<()> {
+    write_json_atomic(path, table)
+}
+
+fn aliases_path(app: &AppHandle) -> AppResult<PathBuf> {
+    Ok(app.path().app_local_data_dir()?.join(ALIASES_FILE))
+}
+
+impl MatcherState {
+    /// Carga los alias guardados (al iniciar la app), con las claves de las reglas vigentes
+    pub fn load_aliases(&self, app: &AppHandle) -> AppResult<()> {
+        let table = load(&aliases_path(app)?)?;
+        *self.aliases.write().unwrap() = Arc::new(table);
+        self.rekey_aliases(app)
+    }
+
+    /// Recalcula las claves con las reglas vigentes (tras cambiarlas). La tabla nueva se
+    /// aplica aunque no se pueda guardar: el archivo se corrige en el próximo recálculo.
+    pub(super) fn rekey_aliases(&self, app: &AppHandle) -> AppResult<()> {
+        let mut current = self.aliases.write().unwrap();
+        let mut table = AliasTable::clone(&current);
+        if !table.rekey(&self.rules()) {
+            return Ok(());
+        }
+        *current = Arc::new(table);
+        save(&current, &aliases_path(app)?)
+    }
+
+    /// Aplica un cambio a la tabla y la guarda; si no se pudo guardar no se aplica
+    fn update_aliases<T>(
+        &self,
+        app: &AppHandle,
+        change: impl FnOnce(&mut AliasTable) -> T,
+    ) -> AppResult<T> {
+        let mut current = self.aliases.write().unwrap();
+        let mut table = AliasTable::clone(&current);
+        let result = change(&mut table);
+        save(&table, &aliases_path(app)?)?;
+        *current = Arc::new(table);
+        Ok(result)
+    }
+}
+
+fn alias_not_found(id: u64) -> AppError {
+    AppError::InvalidRequest(format!("Alias {} not found", id))
+}
+
+// =============================================================================
+// COMANDOS
+// =============================================================================
+
+#[tauri::command]
+pub fn list_aliases(state: State<'_, MatcherState>) -> Vec<Alias> {
+    state.aliases().aliases().to_vec()
+}
+
+/// Guarda la resolución manual de una fila (programa e instructor sin normalizar).
+/// Retorna también el alias que reemplazó, para deshacer la selección.
+#[tauri::command]
+pub fn learn_alias(
+    app: AppHandle,
+    state: State<'_, MatcherState>,
+    program: String,
+    instructor: String,
+    target: AliasTarget,
+) -> AppResult<LearnedAlias> {
+    // Las reglas se leen con la tabla bloqueada: un cambio de reglas la recalcula después
+    state.update_aliases(&app, |table| {
+        table.learn(&state.rules(), &program, &instructor, target, now_ms())
+    })
+}
+
+#[tauri::command]
+pub fn update_alias(
+    app: AppHandle,
+    state: State<'_, MatcherState>,
+    id: u64,
+    target: AliasTarget,
+    expires_at: Option<i64>,
+) -> AppResult<Alias> {
+    state
+        .update_aliases(&app, |table| table.update(id, target, expires_at, now_ms()))?
+        .ok_or_else(|| alias_not_found(id))
+}
+
+/// Vence el alias en `at` (ms desde epoch) o inmediatamente
+#[tauri::command]
+pub fn expire_alias(
+    app: AppHandle,
+    state: State<'_, MatcherState>,
+    id: u64,
+    at: Option<i64>,
+) -> AppResult<Alias> {
+    state
+        .update_aliases(&app, |table| table.expire(id, at, now_ms()))?
+        .ok_or_else(|| alias_not_found(id))
+}
+
+#[tauri::command]
+pub fn delete_alias(app: AppHandle, state: State<'_, MatcherState>, id: u64) -> AppResult<()> {
+    state
+        .update_aliases(&app, |table| table.remove(id))?
+        .then_some(())
+        .ok_or_else(|| alias_not_found(id))
+}
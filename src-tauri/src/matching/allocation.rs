@@ -0,0 +1,251 @@
+//! Asignación automática de anfitriones.
+//!
+//! Reparte los meetings de una ejecución entre el pool de usuarios de Zoom sin que un
+//! anfitrión quede con dos meetings a la vez (ver [`super::hosts`]). El anfitrión es una
+//! propiedad del meeting, así que todas las filas de un mismo meeting van al mismo
+//! anfitrión. El resultado es un plan para revisar antes de `executeAssignments`.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::hosts::HostAssignment;
+use crate::error::AppResult;
+use crate::schedule::{self, ScheduleDate};
+
+// =============================================================================
+// TIPOS DE DATOS
+// =============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanOutcome {
+    /// Queda con el anfitrión habitual del instructor
+    Preferred,
+    /// El habitual estaba ocupado (o no hay habitual): se eligió otro anfitrión libre
+    Reassigned,
+    /// Ningún anfitrión del pool está libre en todos sus horarios
+    Unassigned,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlannedMeeting {
+    pub meeting_id: String,
+    /// Índices de las filas del meeting en el arreglo recibido
+    pub rows: Vec<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_host_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host_name: Option<String>,
+    pub outcome: PlanOutcome,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HostPlan {
+    /// Un elemento por meeting, en el orden de su primera fila
+    pub meetings: Vec<PlannedMeeting>,
+    pub preferred: usize,
+    pub reassigned: usize,
+    pub unassigned: usize,
+}
+
+/// Meeting a ubicar, con todos los tramos que ocupa
+struct Unit<'a> {
+    meeting_id: &'a str,
+    rows: Vec<usize>,
+    spans: Vec<(ScheduleDate, u32, u32)>,
+    preferred: Option<&'a str>,
+}
+
+/// Tramos ocupados de cada anfitrión
+#[derive(Default)]
+struct Calendar<'a> {
+    busy: HashMap<&'a str, Vec<(ScheduleDate, u32, u32)>>,
+}
+
+impl<'a> Calendar<'a> {
+    fn is_free(&self, host: &str, unit: &Unit) -> bool {
+        let Some(busy) = self.busy.get(host) else {
+            return true;
+        };
+        !unit.spans.iter().any(|&(date, start, end)| {
+            busy.iter()
+                .any(|&(d, s, e)| d == date && s < end && start < e)
+        })
+    }
+
+    fn book(&mut self, host: &'a str, unit: &Unit) {
+        self.busy
+            .entry(host)
+            .or_default()
+            .extend(unit.spans.iter().copied());
+    }
+
+    fn load(&self, host: &str) -> usize {
+        self.busy.get(host).map_or(0, Vec::len)
+    }
+}
+
+// =============================================================================
+// PLANIFICACIÓN
+// =============================================================================
+
+/// Asigna un anfitrión del pool (`hosts`, id -> nombre) a cada meeting de `rows`.
+/// `host_id` de cada fila es el anfitrión habitual de su instructor (puede estar vacío).
+/// `fixed` son filas que conservan su anfitrión (ej: no se van a ejecutar): solo lo ocupan.
+/// 1. Agrupar las filas por meeting; su habitual es el `host_id` más frecuente del pool
+/// 2. Primera pasada: cada meeting con su habitual, por orden de inicio, si está libre
+/// 3. Segunda pasada: los restantes (primero los de más tramos, que son los más difíciles
+///    de ubicar) van al anfitrión libre con menos tramos asignados, luego por nombre
+/// 4. Los que no caben en ningún anfitrión quedan sin asignar para resolver a mano
+pub fn plan_hosts(
+    rows: &[HostAssignment],
+    fixed: &[HostAssignment],
+    hosts: &BTreeMap<String, String>,
+) -> HostPlan {
+    // 1. Agrupar por meeting
+    let mut order: Vec<&str> = Vec::new();
+    let mut by_meeting: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (index, row) in rows.iter().enumerate() {
+        let members = by_meeting.entry(row.meeting_id.as_str()).or_default();
+        if members.is_empty() {
+            order.push(row.meeting_id.as_str());
+        }
+        members.push(index);
+    }
+
+    let units: Vec<Unit> = order
+        .iter()
+        .map(|&meeting_id| {
+            let members = by_meeting.remove(meeting_id).unwrap_or_default();
+            Unit {
+                meeting_id,
+                preferred: most_frequent_host(rows, &members, hosts),
+                spans: spans(members.iter().map(|&i| &rows[i])),
+                rows: members,
+            }
+        })
+        .collect();
+
+    let first_span = |unit: &Unit| unit.spans.iter().min().copied();
+    let mut calendar = Calendar::default();
+    for row in fixed.iter().filter(|r| !r.host_id.is_empty()) {
+        calendar
+            .busy
+            .entry(row.host_id.as_str())
+            .or_default()
+            .extend(spans([row]));
+    }
+    let mut placed: Vec<Option<(&str, PlanOutcome)>> = vec![None; units.len()];
+
+    // 2. Anfitrión habitual
+    let mut by_start: Vec<usize> = (0..units.len()).collect();
+    by_start.sort_by_key(|&u| first_span(&units[u]));
+    for &u in &by_start {
+        let unit = &units[u];
+        if let Some(host) = unit.preferred {
+            if calendar.is_free(host, unit) {
+                calendar.book(host, unit);
+                placed[u] = Some((host, PlanOutcome::Preferred));
+            }
+        }
+    }
+
+    // 3. Reasignación
+    let mut pending: Vec<usize> = by_start
+        .into_iter()
+        .filter(|&u| placed[u].is_none())
+        .collect();
+    pending.sort_by_key(|&u| std::cmp::Reverse(units[u].spans.len()));
+    for u in pending {
+        let unit = &units[u];
+        let best = hosts
+            .iter()
+            .filter(|(id, _)| calendar.is_free(id, unit))
+            .min_by(|(a_id, a_name), (b_id, b_name)| {
+                (calendar.load(a_id), *a_name).cmp(&(calendar.load(b_id), *b_name))
+            });
+        if let Some((id, _)) = best {
+            calendar.book(id, unit);
+            placed[u] = Some((id.as_str(), PlanOutcome::Reassigned));
+        }
+    }
+
+    // 4. Plan
+    let mut plan = HostPlan::default();
+    for (unit, placement) in units.into_iter().zip(placed) {
+        let outcome = placement.map_or(PlanOutcome::Unassigned, |(_, outcome)| outcome);
+        match outcome {
+            PlanOutcome::Preferred => plan.preferred += 1,
+            PlanOutcome::Reassigned => plan.reassigned += 1,
+            PlanOutcome::Unassigned => plan.unassigned += 1,
+        }
+        plan.meetings.push(PlannedMeeting {
+            meeting_id: unit.meeting_id.to_string(),
+            rows: unit.rows,
+            preferred_host_id: unit.preferred.map(str::to_string),
+            host_id: placement.map(|(host, _)| host.to_string()),
+            host_name: placement.and_then(|(host, _)| hosts.get(host).cloned()),
+            outcome,
+        });
+    }
+    plan
+}
+
+/// Tramos (fecha, inicio, fin en minutos) de las filas; las de fin <= inicio no ocupan
+fn spans<'a>(rows: impl IntoIterator<Item = &'a HostAssignment>) -> Vec<(ScheduleDate, u32, u32)> {
+    rows.into_iter()
+        .map(|row| {
+            let s = &row.schedule;
+            (
+                s.date,
+                s.start_time.minutes_since_midnight(),
+                s.end_time.minutes_since_midnight(),
+            )
+        })
+        .filter(|&(_, start, end)| end > start)
+        .collect()
+}
+
+/// `host_id` más frecuente (y del pool) entre las filas; en empate, el menor id
+fn most_frequent_host<'a>(
+    rows: &'a [HostAssignment],
+    members: &[usize],
+    hosts: &BTreeMap<String, String>,
+) -> Option<&'a str> {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for &i in members {
+        let host = rows[i].host_id.as_str();
+        if hosts.contains_key(host) {
+            *counts.entry(host).or_default() += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .max_by(|(a_id, a), (b_id, b)| a.cmp(b).then_with(|| b_id.cmp(a_id)))
+        .map(|(host, _)| host)
+}
+
+// =============================================================================
+// COMANDOS
+// =============================================================================
+
+/// Propone un anfitrión para cada meeting sin cruces entre meetings del mismo anfitrión.
+/// `rows` son horarios con `meeting_id` y el `host_id` habitual del instructor;
+/// `fixed` las filas que conservan su anfitrión actual;
+/// `hosts` el pool de usuarios de Zoom (id -> nombre). No modifica nada en Zoom.
+#[tauri::command]
+pub async fn plan_host_allocation(
+    rows: Vec<Value>,
+    fixed: Option<Vec<Value>>,
+    hosts: BTreeMap<String, String>,
+) -> AppResult<HostPlan> {
+    let rows: Vec<HostAssignment> = schedule::from_json_rows(rows)?;
+    let fixed: Vec<HostAssignment> = schedule::from_json_rows(fixed.unwrap_or_default())?;
+    Ok(plan_hosts(&rows, &fixed, &hosts))
+}
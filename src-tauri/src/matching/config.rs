@@ -0,0 +1,294 @@
+//! Configuración del matching (`matching.config.json`).
+//!
+//! El JSON del frontend es la fuente única de verdad: se incluye en el binario
+//! y se compila una sola vez (regex de palabras irrelevantes, patrones de persona, sets).
+
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::{Arc, LazyLock};
+
+use indexmap::IndexMap;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Configuración incluida con la app (misma que usa el frontend)
+pub const BUNDLED_CONFIG_JSON: &str =
+    include_str!("../../../src/features/matching/config/matching.config.json");
+
+// =============================================================================
+// TIPOS DEL JSON
+// =============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchingConfig {
+    pub scoring: ScoringConfig,
+    pub tokens: TokensConfig,
+    pub irrelevant_words: IrrelevantWords,
+    pub person_detection: PersonDetection,
+    /// Opciones de Fuse.js del frontend; el matcher nativo no las usa
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fuzzy_matching: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoringConfig {
+    pub base_score: i32,
+    pub penalties: Penalties,
+    pub thresholds: Thresholds,
+}
+
+/// Puntos (negativos) de cada penalización
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct Penalties {
+    pub critical_token_mismatch: i32,
+    pub level_conflict: i32,
+    pub company_conflict: i32,
+    pub program_vs_person: i32,
+    pub weak_match: i32,
+    pub group_number_conflict: i32,
+    pub missing_token: i32,
+    pub orphan_number_with_siblings: i32,
+    pub orphan_level_with_siblings: i32,
+    pub structural_token_missing: i32,
+    pub numeric_conflict: i32,
+    pub missing_numeric_token: i32,
+    pub missing_token_extra_info: i32,
+    pub level_mismatch_ignored: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct Thresholds {
+    pub high_confidence: i32,
+    pub medium_confidence: i32,
+    pub minimum: i32,
+    pub ambiguity_diff: i32,
+    pub fuse_max_score: f64,
+    pub token_overlap_min: f64,
+    pub min_matching_tokens: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokensConfig {
+    pub structural: Vec<String>,
+    pub program_types: Vec<String>,
+    pub synonyms: Vec<Vec<String>>,
+    /// Tipos de programa mutuamente excluyentes; el orden define la prioridad
+    pub program_type_groups: Vec<ProgramTypeGroup>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProgramTypeGroup {
+    pub id: String,
+    pub tokens: Vec<String>,
+}
+
+/// Palabras eliminadas al normalizar, agrupadas por categoría (en el orden del JSON),
+/// más patrones regex sueltos en `patterns`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IrrelevantWords {
+    #[serde(default)]
+    pub patterns: Vec<String>,
+    #[serde(flatten)]
+    pub categories: IndexMap<String, Vec<String>>,
+}
+
+impl IrrelevantWords {
+    /// Todas las palabras de todas las categorías (sin los patrones)
+    pub fn words(&self) -> impl Iterator<Item = &String> {
+        self.categories.values().flatten()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonDetection {
+    pub patterns: Vec<String>,
+    pub title_indicators: Vec<String>,
+}
+
+// =============================================================================
+// CONFIGURACIÓN COMPILADA
+// =============================================================================
+
+/// Configuración lista para usar: regex compiladas y sets de tokens
+#[derive(Debug)]
+pub struct MatchingRules {
+    pub config: MatchingConfig,
+    pub(crate) irrelevant_pattern: Option<Regex>,
+    pub(crate) person_patterns: Vec<Regex>,
+    pub(crate) title_pattern: Option<Regex>,
+    /// Palabras irrelevantes + "group"/"grupo"
+    pub(crate) irrelevant_tokens: HashSet<String>,
+    /// Tokens que nunca son una compañía (estructurales, tipos de programa, irrelevantes)
+    pub(crate) ignored_company_tokens: HashSet<String>,
+    pub(crate) structural: HashSet<String>,
+    pub(crate) program_types: HashSet<String>,
+    /// Versión del diccionario del usuario aplicado (0 = ninguno, ver `dictionary`)
+    pub dictionary_version: u32,
+    /// Variante de sinónimo (ya normalizada) -> forma canónica
+    pub(crate) synonym_rewrites: Vec<(Regex, String)>,
+}
+
+static BUNDLED_RULES: LazyLock<Arc<MatchingRules>> = LazyLock::new(|| {
+    let config: MatchingConfig =
+        serde_json::from_str(BUNDLED_CONFIG_JSON).expect("bundled matching.config.json is valid");
+    Arc::new(MatchingRules::compile(config).expect("bundled matching.config.json compiles"))
+});
+
+/// Error de configuración: JSON pointer del valor inválido (ej: "/scoring/penalties/WEAK_MATCH")
+/// y descripción. El pointer vacío es el documento completo.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigIssue {
+    pub pointer: String,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    pub fn new(pointer: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            pointer: pointer.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.pointer.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.pointer, self.message)
+        }
+    }
+}
+
+impl MatchingRules {
+    /// Reglas de la configuración incluida con la app
+    pub fn bundled() -> Arc<MatchingRules> {
+        BUNDLED_RULES.clone()
+    }
+
+    /// Compila las regex de la configuración; un patrón inválido se reporta con su pointer
+    pub fn compile(config: MatchingConfig) -> Result<Self, ConfigIssue> {
+        // Mismo patrón que buildIrrelevantWordsPattern: \b(palabras|patrones)\b, sin distinguir mayúsculas.
+        // Los límites de palabra son ASCII como en JavaScript ("DEÑA" -> "DE" + "ÑA").
+        let alternatives: Vec<&str> = config
+            .irrelevant_words
+            .words()
+            .chain(&config.irrelevant_words.patterns)
+            .map(String::as_str)
+            .collect();
+        let irrelevant_pattern = if alternatives.is_empty() {
+            None
+        } else {
+            check_alternatives(&config.irrelevant_words)?;
+            let pattern = format!(r"(?i)(?-u:\b)({})(?-u:\b)", alternatives.join("|"));
+            Some(
+                Regex::new(&pattern)
+                    .map_err(|e| ConfigIssue::new("/irrelevantWords", e.to_string()))?,
+            )
+        };
+
+        let person_patterns = config
+            .person_detection
+            .patterns
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                Regex::new(&format!("(?i){}", p)).map_err(|e| {
+                    ConfigIssue::new(format!("/personDetection/patterns/{}", i), e.to_string())
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let title_pattern =
+            if config.person_detection.title_indicators.is_empty() {
+                None
+            } else {
+                let titles: Vec<String> = config
+                    .person_detection
+                    .title_indicators
+                    .iter()
+                    .map(|t| regex::escape(t))
+                    .collect();
+                let pattern = format!(r"(?-u:\b)({})(?-u:\b)", titles.join("|"));
+                Some(Regex::new(&pattern).map_err(|e| {
+                    ConfigIssue::new("/personDetection/titleIndicators", e.to_string())
+                })?)
+            };
+
+        let mut irrelevant_tokens: HashSet<String> =
+            config.irrelevant_words.words().cloned().collect();
+        // También "group", "grupo" que son estructurales comunes
+        irrelevant_tokens.insert("group".to_string());
+        irrelevant_tokens.insert("grupo".to_string());
+
+        let structural: HashSet<String> = config.tokens.structural.iter().cloned().collect();
+        let program_types: HashSet<String> = config.tokens.program_types.iter().cloned().collect();
+        let ignored_company_tokens = structural
+            .iter()
+            .chain(&program_types)
+            .chain(&irrelevant_tokens)
+            .cloned()
+            .collect();
+
+        Ok(Self {
+            config,
+            irrelevant_pattern,
+            person_patterns,
+            title_pattern,
+            irrelevant_tokens,
+            ignored_company_tokens,
+            structural,
+            program_types,
+            dictionary_version: 0,
+            synonym_rewrites: Vec::new(),
+        })
+    }
+
+    pub fn penalties(&self) -> &Penalties {
+        &self.config.scoring.penalties
+    }
+
+    pub fn thresholds(&self) -> &Thresholds {
+        &self.config.scoring.thresholds
+    }
+
+    /// Formato de persona ("Garcia Lopez (ACME), Juan" o "JUAN GARCIA LOPEZ - ...")
+    pub fn is_person_format(&self, raw: &str) -> bool {
+        self.person_patterns.iter().any(|p| p.is_match(raw))
+    }
+}
+
+/// Las palabras irrelevantes se insertan sin escapar en la regex (igual que en el frontend):
+/// se compilan una por una para señalar exactamente cuál es inválida
+fn check_alternatives(words: &IrrelevantWords) -> Result<(), ConfigIssue> {
+    let groups = words
+        .categories
+        .iter()
+        .map(|(category, list)| (category.as_str(), list))
+        .chain(std::iter::once(("patterns", &words.patterns)));
+
+    for (category, list) in groups {
+        for (i, word) in list.iter().enumerate() {
+            if let Err(e) = Regex::new(word) {
+                return Err(ConfigIssue::new(
+                    format!("/irrelevantWords/{}/{}", escape_pointer(category), i),
+                    e.to_string(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Escapa un segmento de JSON pointer (RFC 6901: "~" -> "~0", "/" -> "~1")
+pub(crate) fn escape_pointer(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
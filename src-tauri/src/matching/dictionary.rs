@@ -0,0 +1,232 @@
+//! Diccionario de normalización del usuario (`AppLocalData/matching.dictionary.json`).
+//!
+//! Complementa las listas de `matching.config.json` sin publicar una versión nueva:
+//! palabras irrelevantes, sinónimos de tokens ("trio" / "3 pax") y tokens estructurales
+//! (CH/DUO/TRIO). Cada guardado incrementa `version`, que queda registrada en cada
+//! resultado del matching (`dictionaryVersion`).
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+use super::config::{ConfigIssue, MatchingRules};
+use super::normalizer::{fold, pre_clean};
+use super::{config_override_path, loader, MatcherState};
+use crate::error::{AppError, AppResult};
+use crate::files::write_json_atomic;
+use crate::time::now_ms;
+
+/// Nombre del diccionario en AppLocalData
+pub const DICTIONARY_FILE: &str = "matching.dictionary.json";
+
+/// Categoría de `irrelevantWords` con las palabras del usuario
+const USER_CATEGORY: &str = "userDictionary";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDictionary {
+    /// Se incrementa con cada guardado (0 = nunca guardado)
+    #[serde(default)]
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<i64>,
+    /// Palabras (literales, no regex) que se eliminan al normalizar
+    #[serde(default)]
+    pub irrelevant_words: Vec<String>,
+    /// Grupos de sinónimos; el primer término es la forma canónica
+    #[serde(default)]
+    pub synonyms: Vec<Vec<String>>,
+    /// Tokens estructurales adicionales (tipos de programa como CH, DUO, TRIO)
+    #[serde(default)]
+    pub structural: Vec<String>,
+}
+
+impl UserDictionary {
+    /// Término tal como queda tras normalizar (sin eliminar palabras irrelevantes)
+    fn normalized(term: &str) -> String {
+        fold(&pre_clean(term))
+    }
+
+    /// Valida todas las entradas; los pointers son relativos al diccionario
+    pub fn validate(&self) -> Result<(), Vec<ConfigIssue>> {
+        let mut issues = Vec::new();
+
+        for (i, word) in self.irrelevant_words.iter().enumerate() {
+            if word.trim().is_empty() {
+                issues.push(ConfigIssue::new(
+                    format!("/irrelevantWords/{}", i),
+                    "Word is empty",
+                ));
+            }
+        }
+
+        for (i, group) in self.synonyms.iter().enumerate() {
+            if group.len() < 2 {
+                issues.push(ConfigIssue::new(
+                    format!("/synonyms/{}", i),
+                    "A synonym group needs at least two terms",
+                ));
+            }
+            for (j, term) in group.iter().enumerate() {
+                if Self::normalized(term).is_empty() {
+                    issues.push(ConfigIssue::new(
+                        format!("/synonyms/{}/{}", i, j),
+                        "Term is empty after normalization",
+                    ));
+                }
+            }
+        }
+
+        for (i, token) in self.structural.iter().enumerate() {
+            let normalized = Self::normalized(token);
+            if normalized.is_empty() || normalized.contains(' ') {
+                issues.push(ConfigIssue::new(
+                    format!("/structural/{}", i),
+                    "Structural tokens must be a single word",
+                ));
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+}
+
+impl MatchingRules {
+    /// Reglas con el diccionario del usuario combinado con las listas incluidas.
+    /// 1. Palabras irrelevantes: categoría adicional, como texto literal
+    /// 2. Tokens estructurales: también como grupo propio para STRUCTURAL_TOKEN_MISSING
+    /// 3. Sinónimos: cada variante se reemplaza por el primer término al normalizar
+    pub fn with_dictionary(
+        &self,
+        dictionary: &UserDictionary,
+    ) -> Result<MatchingRules, Vec<ConfigIssue>> {
+        dictionary.validate()?;
+        let mut config = self.config.clone();
+
+        // 1. Palabras irrelevantes
+        if !dictionary.irrelevant_words.is_empty() {
+            config.irrelevant_words.categories.insert(
+                USER_CATEGORY.to_string(),
+                dictionary
+                    .irrelevant_words
+                    .iter()
+                    .map(|w| regex::escape(w.trim()))
+                    .collect(),
+            );
+        }
+
+        // 2. Tokens estructurales
+        let tokens = &mut config.tokens;
+        for token in dictionary
+            .structural
+            .iter()
+            .map(|t| UserDictionary::normalized(t))
+        {
+            if !tokens.structural.contains(&token) {
+                tokens.structural.push(token.clone());
+            }
+            if !tokens.synonyms.iter().flatten().any(|t| *t == token) {
+                tokens.synonyms.push(vec![token]);
+            }
+        }
+
+        let mut rules = MatchingRules::compile(config).map_err(|issue| vec![issue])?;
+
+        // 3. Sinónimos
+        for group in &dictionary.synonyms {
+            let canonical = UserDictionary::normalized(&group[0]);
+            for variant in group[1..].iter().map(|t| UserDictionary::normalized(t)) {
+                if variant == canonical {
+                    continue;
+                }
+                let pattern = format!(r"(?-u:\b){}(?-u:\b)", regex::escape(&variant));
+                let pattern = Regex::new(&pattern).expect("escaped synonym is a valid regex");
+                rules.synonym_rewrites.push((pattern, canonical.clone()));
+            }
+        }
+
+        rules.dictionary_version = dictionary.version;
+        Ok(rules)
+    }
+}
+
+// =============================================================================
+// PERSISTENCIA
+// =============================================================================
+
+/// Lee el diccionario; si el archivo no existe está vacío (versión 0)
+pub fn load(path: &Path) -> AppResult<UserDictionary> {
+    match fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content).map_err(|e| {
+            AppError::InvalidConfig(vec![ConfigIssue::new(
+                "",
+                format!("Invalid dictionary JSON: {}", e),
+            )])
+        }),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(UserDictionary::default()),
+        Err(e) => Err(AppError::io(e, path)),
+    }
+}
+
+pub fn save(dictionary: &UserDictionary, path: &Path) -> AppResult<()> {
+    write_json_atomic(path, dictionary)
+}
+
+pub(super) fn dictionary_path(app: &AppHandle) -> AppResult<PathBuf> {
+    Ok(app.path().app_local_data_dir()?.join(DICTIONARY_FILE))
+}
+
+// =============================================================================
+// COMANDOS
+// =============================================================================
+
+#[tauri::command]
+pub fn get_matching_dictionary(app: AppHandle) -> AppResult<UserDictionary> {
+    load(&dictionary_path(&app)?)
+}
+
+/// Guarda el diccionario editado, recompila las reglas y vuelve a indexar el matcher
+/// activo (con las claves de los alias recalculadas).
+/// `dictionary.version` debe ser la versión leída: si otro guardado la cambió se rechaza
+/// en vez de sobrescribirlo. Retorna el diccionario guardado, con la versión nueva.
+#[tauri::command]
+pub async fn save_matching_dictionary(
+    app: AppHandle,
+    dictionary: UserDictionary,
+) -> AppResult<UserDictionary> {
+    tauri::async_runtime::spawn_blocking(move || save_and_apply(&app, dictionary))
+        .await
+        .map_err(|e| AppError::Internal(e.to_string()))?
+}
+
+fn save_and_apply(app: &AppHandle, dictionary: UserDictionary) -> AppResult<UserDictionary> {
+    let path = dictionary_path(app)?;
+    let current = load(&path)?;
+    if dictionary.version != current.version {
+        return Err(AppError::InvalidRequest(format!(
+            "The dictionary was changed since it was read (version {}, now {})",
+            dictionary.version, current.version
+        )));
+    }
+
+    let next = UserDictionary {
+        version: current.version + 1,
+        updated_at: Some(now_ms()),
+        ..dictionary
+    };
+    let rules = loader::load_rules(&config_override_path(app)?)?
+        .with_dictionary(&next)
+        .map_err(AppError::InvalidConfig)?;
+
+    save(&next, &path)?;
+    app.state::<MatcherState>().set_rules(app, rules)?;
+    Ok(next)
+}
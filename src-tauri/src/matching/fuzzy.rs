@@ -0,0 +1,163 @@
+//! Búsqueda difusa equivalente a Fuse.js (`ignoreLocation: true`).
+//!
+//! Fuse puntúa cada campo con Bitap: errores / longitud del patrón, donde los errores
+//! son la distancia de edición mínima entre el patrón y cualquier subcadena del texto.
+//! El score de un ítem combina los campos que coinciden ponderados por peso y norma
+//! del campo; los resultados se ordenan de menor a mayor score.
+
+/// Máximo de caracteres que Bitap procesa de una vez; patrones más largos se dividen
+const MAX_BITS: usize = 32;
+
+/// Índice de ítems con uno o más campos de texto ya normalizados
+#[derive(Debug, Default)]
+pub struct FuzzyIndex {
+    /// Campos no vacíos de cada ítem: (valor, norma)
+    records: Vec<Vec<(Vec<char>, f64)>>,
+    /// Peso de cada clave (todas pesan igual, normalizado a 1)
+    weight: f64,
+    ignore_field_norm: bool,
+}
+
+impl FuzzyIndex {
+    /// `items[i][k]` es el valor de la clave `k` del ítem `i`. Los valores vacíos no se indexan.
+    pub fn new(items: Vec<Vec<String>>, ignore_field_norm: bool) -> Self {
+        let keys = items.first().map(Vec::len).unwrap_or(1).max(1);
+        let records = items
+            .into_iter()
+            .map(|fields| {
+                fields
+                    .into_iter()
+                    .filter(|value| !value.trim().is_empty())
+                    .map(|value| {
+                        let norm = field_norm(&value);
+                        (value.chars().collect(), norm)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            records,
+            weight: 1.0 / keys as f64,
+            ignore_field_norm,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Busca el patrón y retorna (índice del ítem, score) ordenado por score ascendente
+    pub fn search(&self, pattern: &str, threshold: f64) -> Vec<(usize, f64)> {
+        self.search_in(pattern, threshold, 0..self.records.len())
+    }
+
+    /// Igual que `search`, pero solo evalúa los ítems indicados (en orden ascendente,
+    /// para que los empates conserven el mismo orden que una búsqueda completa)
+    pub fn search_in(
+        &self,
+        pattern: &str,
+        threshold: f64,
+        items: impl IntoIterator<Item = usize>,
+    ) -> Vec<(usize, f64)> {
+        if pattern.is_empty() {
+            return Vec::new();
+        }
+        let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+
+        let mut results: Vec<(usize, f64)> = items
+            .into_iter()
+            .filter_map(|idx| {
+                let fields = &self.records[idx];
+                let mut total = 1.0;
+                let mut matched = false;
+                for (value, norm) in fields {
+                    if let Some(score) = bitap_score(&pattern, value, threshold) {
+                        matched = true;
+                        let norm = if self.ignore_field_norm { 1.0 } else { *norm };
+                        let base = if score == 0.0 { f64::EPSILON } else { score };
+                        total *= base.powf(self.weight * norm);
+                    }
+                }
+                matched.then_some((idx, total))
+            })
+            .collect();
+
+        // Orden estable: en empate conserva el orden original
+        results.sort_by(|a, b| a.1.total_cmp(&b.1));
+        results
+    }
+}
+
+/// Norma del campo: 1 / sqrt(número de tokens), redondeada a 3 decimales
+fn field_norm(value: &str) -> f64 {
+    let tokens = value.split(' ').filter(|t| !t.is_empty()).count().max(1);
+    ((1.0 / (tokens as f64).sqrt()) * 1000.0).round() / 1000.0
+}
+
+/// Score Bitap de un campo, o None si no coincide dentro del umbral
+fn bitap_score(pattern: &[char], text: &[char], threshold: f64) -> Option<f64> {
+    if pattern == text {
+        return Some(0.0);
+    }
+
+    let chunks = pattern_chunks(pattern);
+    let mut total = 0.0;
+    let mut has_matches = false;
+    for chunk in &chunks {
+        let score = substring_distance(chunk, text) as f64 / chunk.len() as f64;
+        if score <= threshold {
+            has_matches = true;
+            total += score.max(0.001);
+        } else {
+            total += 1.0;
+        }
+    }
+
+    has_matches.then(|| total / chunks.len() as f64)
+}
+
+/// Bloques que Bitap evalúa por separado: bloques de 32 y un último bloque
+/// con los 32 caracteres finales
+pub(crate) fn pattern_chunks(pattern: &[char]) -> Vec<&[char]> {
+    if pattern.len() <= MAX_BITS {
+        return vec![pattern];
+    }
+    let remainder = pattern.len() % MAX_BITS;
+    let end = pattern.len() - remainder;
+    let mut chunks: Vec<&[char]> = pattern[..end].chunks(MAX_BITS).collect();
+    if remainder > 0 {
+        chunks.push(&pattern[pattern.len() - MAX_BITS..]);
+    }
+    chunks
+}
+
+/// Máximo de errores (distancia de edición) con los que un bloque coincide dentro del umbral
+pub(crate) fn max_errors(chunk_len: usize, threshold: f64) -> usize {
+    (0..=chunk_len)
+        .take_while(|&errors| errors as f64 / chunk_len as f64 <= threshold)
+        .last()
+        .unwrap_or(0)
+}
+
+/// Distancia de edición mínima entre el patrón y cualquier subcadena del texto
+fn substring_distance(pattern: &[char], text: &[char]) -> usize {
+    // Fila inicial en cero: la coincidencia puede empezar en cualquier posición
+    let mut prev = vec![0usize; text.len() + 1];
+    let mut curr = vec![0usize; text.len() + 1];
+
+    for (i, p) in pattern.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, t) in text.iter().enumerate() {
+            let substitution = prev[j] + usize::from(p != t);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev.into_iter().min().unwrap_or(pattern.len())
+}
@@ -0,0 +1,230 @@
+//! Concurrencia de anfitriones de Zoom.
+//!
+//! Una licencia de Zoom solo puede hospedar una reunión a la vez. Con las asignaciones
+//! de una ejecución (horario + meeting + anfitrión) se buscan los anfitriones con
+//! meetings distintos superpuestos el mismo día, y para cada cruce se sugieren
+//! anfitriones del pool que estén libres durante todo el tramo.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::AppResult;
+use crate::schedule::{self, Schedule, ScheduleDate, ScheduleKey, ScheduleTime};
+
+// =============================================================================
+// TIPOS DE DATOS
+// =============================================================================
+
+/// Fila a verificar: horario con el meeting asignado y el anfitrión que lo hospedará
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostAssignment {
+    #[serde(flatten)]
+    pub schedule: Schedule,
+    pub meeting_id: String,
+    /// ID del usuario de Zoom; vacío si la fila aún no tiene anfitrión
+    #[serde(default)]
+    pub host_id: String,
+}
+
+/// Rango de fechas a verificar (inclusivo); sin límites se revisan todas las filas
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HostWindow {
+    pub from: Option<ScheduleDate>,
+    pub to: Option<ScheduleDate>,
+}
+
+impl HostWindow {
+    pub fn contains(&self, date: ScheduleDate) -> bool {
+        self.from.is_none_or(|from| date >= from) && self.to.is_none_or(|to| date <= to)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConflictRow {
+    /// Índice de la fila en el arreglo recibido
+    pub row: usize,
+    pub key: ScheduleKey,
+    pub meeting_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FreeHost {
+    pub id: String,
+    pub name: String,
+}
+
+/// Grupo de filas superpuestas de un mismo anfitrión
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HostConflict {
+    pub host_id: String,
+    pub host_name: String,
+    pub date: ScheduleDate,
+    /// Tramo que cubre todas las filas del grupo
+    pub start_time: ScheduleTime,
+    pub end_time: ScheduleTime,
+    pub rows: Vec<ConflictRow>,
+    /// Anfitriones sin meetings en el tramo, ordenados por nombre
+    pub free_hosts: Vec<FreeHost>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HostConcurrencyReport {
+    /// Ordenados por fecha, hora de inicio y anfitrión
+    pub conflicts: Vec<HostConflict>,
+    /// Filas involucradas en algún cruce
+    pub conflict_count: usize,
+}
+
+// =============================================================================
+// VERIFICACIÓN
+// =============================================================================
+
+/// Busca anfitriones con meetings superpuestos.
+/// `hosts` es el pool de usuarios de Zoom (id -> nombre, como `useHostMap`).
+/// 1. Agrupar las filas con anfitrión por anfitrión + fecha, dentro de la ventana
+/// 2. Barrido ordenado por inicio: las filas encadenadas por cruces forman un grupo
+/// 3. El grupo es un conflicto si tiene al menos dos meetings distintos
+///    (un mismo meeting en dos filas no es concurrencia)
+/// 4. Sugerir anfitriones del pool sin filas en el tramo del grupo
+pub fn check_host_concurrency(
+    rows: &[HostAssignment],
+    hosts: &BTreeMap<String, String>,
+    window: HostWindow,
+) -> HostConcurrencyReport {
+    let span = |i: usize| {
+        let s = &rows[i].schedule;
+        (
+            s.start_time.minutes_since_midnight(),
+            s.end_time.minutes_since_midnight(),
+        )
+    };
+
+    // 1. Agrupar (las filas con fin <= inicio no ocupan al anfitrión)
+    let mut by_host_date: HashMap<(&str, ScheduleDate), Vec<usize>> = HashMap::new();
+    for (index, row) in rows.iter().enumerate() {
+        let (start, end) = span(index);
+        if row.host_id.is_empty() || end <= start || !window.contains(row.schedule.date) {
+            continue;
+        }
+        by_host_date
+            .entry((row.host_id.as_str(), row.schedule.date))
+            .or_default()
+            .push(index);
+    }
+
+    let mut report = HostConcurrencyReport::default();
+    for (&(host_id, date), group) in &by_host_date {
+        let mut group = group.clone();
+        group.sort_by_key(|&i| span(i));
+
+        // 2. Barrido
+        let mut clusters: Vec<(Vec<usize>, u32)> = Vec::new();
+        for i in group {
+            let (start, end) = span(i);
+            match clusters.last_mut() {
+                Some((members, cluster_end)) if start < *cluster_end => {
+                    members.push(i);
+                    *cluster_end = (*cluster_end).max(end);
+                }
+                _ => clusters.push((vec![i], end)),
+            }
+        }
+
+        for (members, _) in clusters {
+            // 3. Meetings distintos
+            let meetings: HashSet<&str> = members
+                .iter()
+                .map(|&i| rows[i].meeting_id.as_str())
+                .collect();
+            if meetings.len() < 2 {
+                continue;
+            }
+
+            let start_time = members
+                .iter()
+                .map(|&i| rows[i].schedule.start_time)
+                .min()
+                .expect("cluster has members");
+            let end_time = members
+                .iter()
+                .map(|&i| rows[i].schedule.end_time)
+                .max()
+                .expect("cluster has members");
+
+            // 4. Anfitriones libres
+            let (from, to) = (
+                start_time.minutes_since_midnight(),
+                end_time.minutes_since_midnight(),
+            );
+            let busy = |candidate: &str| {
+                by_host_date.get(&(candidate, date)).is_some_and(|others| {
+                    others.iter().any(|&i| {
+                        let (start, end) = span(i);
+                        start < to && from < end
+                    })
+                })
+            };
+            let mut free_hosts: Vec<FreeHost> = hosts
+                .iter()
+                .filter(|(id, _)| id.as_str() != host_id && !busy(id))
+                .map(|(id, name)| FreeHost {
+                    id: id.clone(),
+                    name: name.clone(),
+                })
+                .collect();
+            free_hosts.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+
+            report.conflict_count += members.len();
+            report.conflicts.push(HostConflict {
+                host_id: host_id.to_string(),
+                host_name: hosts
+                    .get(host_id)
+                    .cloned()
+                    .unwrap_or_else(|| host_id.to_string()),
+                date,
+                start_time,
+                end_time,
+                rows: members
+                    .into_iter()
+                    .map(|i| ConflictRow {
+                        row: i,
+                        key: rows[i].schedule.key(),
+                        meeting_id: rows[i].meeting_id.clone(),
+                    })
+                    .collect(),
+                free_hosts,
+            });
+        }
+    }
+
+    report.conflicts.sort_by(|a, b| {
+        (a.date, a.start_time, &a.host_name).cmp(&(b.date, b.start_time, &b.host_name))
+    });
+    report
+}
+
+// =============================================================================
+// COMANDOS
+// =============================================================================
+
+/// Verifica que ningún anfitrión quede con dos meetings a la vez.
+/// `rows` son horarios con `meeting_id` y `host_id`; `hosts` el mapa id -> nombre.
+#[tauri::command]
+pub async fn check_host_conflicts(
+    rows: Vec<Value>,
+    hosts: BTreeMap<String, String>,
+    window: Option<HostWindow>,
+) -> AppResult<HostConcurrencyReport> {
+    let rows: Vec<HostAssignment> = schedule::from_json_rows(rows)?;
+    Ok(check_host_concurrency(
+        &rows,
+        &hosts,
+        window.unwrap_or_default(),
+    ))
+}
@@ -0,0 +1,240 @@
+//! Índice de recuperación de candidatos (paso 2 de `docs/matching_logic.md`).
+//!
+//! Se construye una vez por carga de datos de Zoom (`init_matching`) y se reutiliza
+//! en cada `run_matching`. Estrategias, en orden:
+//! 1. Diccionario exacto de topics normalizados
+//! 2. Búsqueda difusa: un índice de trigramas descarta los topics que no pueden estar
+//!    dentro del umbral de errores y solo se calcula la distancia de edición del resto
+//! 3. Conjunto de tokens compartidos, con un índice invertido de tokens
+//!
+//! El filtro de trigramas es exacto (lema de q-gramas): retorna los mismos candidatos,
+//! en el mismo orden, que evaluar todos los topics.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::config::Thresholds;
+use super::fuzzy::{max_errors, pattern_chunks, FuzzyIndex};
+
+type Trigram = [char; 3];
+
+/// Estrategia con la que se encontraron los candidatos
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Retrieval {
+    /// Alias aprendido de una resolución manual (ver `aliases`)
+    Alias,
+    Exact,
+    Fuzzy,
+    TokenSet,
+}
+
+/// Candidatos encontrados (índices de topic en orden de relevancia)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidates {
+    pub strategy: Retrieval,
+    pub indices: Vec<usize>,
+}
+
+#[derive(Debug, Default)]
+pub struct CandidateIndex {
+    topics: Vec<String>,
+    /// Topic normalizado -> topics (varios meetings pueden normalizar al mismo key)
+    exact: HashMap<String, Vec<usize>>,
+    fuzzy: FuzzyIndex,
+    /// Trigrama -> (topic, apariciones en el topic), en orden ascendente de topic
+    trigrams: HashMap<Trigram, Vec<(usize, u32)>>,
+    /// Token -> topics que lo contienen, en orden ascendente
+    tokens: HashMap<String, Vec<usize>>,
+}
+
+impl CandidateIndex {
+    /// Indexa los topics ya normalizados
+    pub fn new(topics: Vec<String>) -> Self {
+        let mut exact: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut trigrams: HashMap<Trigram, Vec<(usize, u32)>> = HashMap::new();
+        let mut tokens: HashMap<String, Vec<usize>> = HashMap::new();
+
+        for (idx, topic) in topics.iter().enumerate() {
+            if topic.is_empty() {
+                continue;
+            }
+            exact.entry(topic.clone()).or_default().push(idx);
+
+            let chars: Vec<char> = topic.chars().collect();
+            for (trigram, count) in trigram_counts(&chars) {
+                trigrams.entry(trigram).or_default().push((idx, count));
+            }
+
+            let unique: HashSet<&str> = topic.split(' ').filter(|t| !t.is_empty()).collect();
+            for token in unique {
+                tokens.entry(token.to_string()).or_default().push(idx);
+            }
+        }
+
+        let fuzzy = FuzzyIndex::new(topics.iter().map(|t| vec![t.clone()]).collect(), false);
+
+        Self {
+            topics,
+            exact,
+            fuzzy,
+            trigrams,
+            tokens,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.topics.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.topics.is_empty()
+    }
+
+    /// Topics iguales a la query normalizada
+    pub fn exact(&self, query: &str) -> &[usize] {
+        self.exact.get(query).map_or(&[], Vec::as_slice)
+    }
+
+    /// Candidatos para la query normalizada: la primera estrategia con resultados.
+    /// Sin resultados retorna la última estrategia intentada con la lista vacía.
+    pub fn retrieve(&self, query: &str, thresholds: &Thresholds) -> Candidates {
+        // 1. Búsqueda exacta
+        if let Some(exact) = self.exact.get(query) {
+            return Candidates {
+                strategy: Retrieval::Exact,
+                indices: exact.clone(),
+            };
+        }
+
+        // 2. Búsqueda difusa
+        let fuzzy = self.fuzzy_candidates(query, thresholds.fuse_max_score);
+        if !fuzzy.is_empty() {
+            return Candidates {
+                strategy: Retrieval::Fuzzy,
+                indices: fuzzy,
+            };
+        }
+
+        // 3. Conjunto de tokens
+        Candidates {
+            strategy: Retrieval::TokenSet,
+            indices: self.token_set_candidates(query, thresholds),
+        }
+    }
+
+    /// Topics a distancia de edición dentro del umbral, ordenados por score (como Fuse)
+    fn fuzzy_candidates(&self, query: &str, max_score: f64) -> Vec<usize> {
+        let results = match self.trigram_filter(query, max_score) {
+            Some(items) => self.fuzzy.search_in(query, max_score, items),
+            None => self.fuzzy.search(query, max_score),
+        };
+        results
+            .into_iter()
+            .filter(|(_, score)| *score <= max_score)
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// Topics que comparten suficientes trigramas con algún bloque de la query.
+    /// Un texto a `k` errores de un bloque de largo `m` conserva al menos `m - 2 - 3k`
+    /// de sus trigramas; si ese mínimo no es positivo el filtro no aplica (None).
+    fn trigram_filter(&self, query: &str, max_score: f64) -> Option<Vec<usize>> {
+        let pattern: Vec<char> = query.to_lowercase().chars().collect();
+        let mut passes = vec![false; self.topics.len()];
+        let mut shared = vec![0u32; self.topics.len()];
+
+        for chunk in pattern_chunks(&pattern) {
+            let grams = chunk.len().saturating_sub(2);
+            let required = grams.checked_sub(3 * max_errors(chunk.len(), max_score))?;
+            if required == 0 {
+                return None;
+            }
+
+            shared.fill(0);
+            for (trigram, count) in trigram_counts(chunk) {
+                for &(idx, topic_count) in self.trigrams.get(&trigram).into_iter().flatten() {
+                    shared[idx] += count.min(topic_count);
+                }
+            }
+            for (pass, &n) in passes.iter_mut().zip(&shared) {
+                *pass |= n as usize >= required;
+            }
+        }
+
+        Some(
+            passes
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, &pass)| pass.then_some(idx))
+                .collect(),
+        )
+    }
+
+    /// Topics que comparten tokens significativos con la query
+    fn token_set_candidates(&self, query: &str, thresholds: &Thresholds) -> Vec<usize> {
+        let query_tokens: HashSet<&str> = query
+            .split(' ')
+            .filter(|t| t.chars().count() >= 2)
+            .collect();
+
+        // Solo se evalúan los topics con al menos un token en común
+        let mut candidates: Vec<usize> = query_tokens
+            .iter()
+            .filter_map(|t| self.tokens.get(*t))
+            .flatten()
+            .copied()
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        candidates
+            .into_iter()
+            .filter(|&idx| {
+                let intersection: Vec<&str> = self.topics[idx]
+                    .split(' ')
+                    .filter(|t| query_tokens.contains(t))
+                    .collect();
+                let has_meaningful_match = intersection
+                    .iter()
+                    .any(|t| !is_js_number(t) && t.chars().count() > 2);
+                let overlap_ratio = intersection.len() as f64 / query_tokens.len() as f64;
+
+                has_meaningful_match
+                    && intersection.len() >= thresholds.min_matching_tokens
+                    && overlap_ratio >= thresholds.token_overlap_min
+            })
+            .collect()
+    }
+}
+
+/// Trigramas del texto con su cantidad de apariciones
+fn trigram_counts(chars: &[char]) -> HashMap<Trigram, u32> {
+    let mut counts = HashMap::new();
+    for window in chars.windows(3) {
+        *counts.entry([window[0], window[1], window[2]]).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Equivalente a `!isNaN(Number(t))` para tokens normalizados ([a-z0-9_']):
+/// enteros, exponentes ("1e5") y literales hex/binarios/octales ("0x1f")
+fn is_js_number(t: &str) -> bool {
+    let all = |s: &str, f: fn(char) -> bool| !s.is_empty() && s.chars().all(f);
+    if let Some(hex) = t.strip_prefix("0x") {
+        return all(hex, |c| c.is_ascii_hexdigit());
+    }
+    if let Some(bin) = t.strip_prefix("0b") {
+        return all(bin, |c| c == '0' || c == '1');
+    }
+    if let Some(oct) = t.strip_prefix("0o") {
+        return all(oct, |c| ('0'..='7').contains(&c));
+    }
+    match t.split_once('e') {
+        Some((mantissa, exponent)) => {
+            all(mantissa, |c| c.is_ascii_digit()) && all(exponent, |c| c.is_ascii_digit())
+        }
+        None => t.is_empty() || all(t, |c| c.is_ascii_digit()),
+    }
+}
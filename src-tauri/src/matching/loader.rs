@@ -0,0 +1,117 @@
+//! Carga y validación de `matching.config.json`.
+//!
+//! La configuración efectiva es la incluida con la app con el override del usuario
+//! (`AppLocalData/matching.config.json`) aplicado encima. Antes de usarla se valida
+//! contra `matching.schema.json`, luego los tipos y finalmente las regex.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::LazyLock;
+
+use jsonschema::Validator;
+use serde_json::Value;
+use serde_path_to_error::Segment;
+
+use super::config::{
+    escape_pointer, ConfigIssue, MatchingConfig, MatchingRules, BUNDLED_CONFIG_JSON,
+};
+use crate::error::{AppError, AppResult};
+
+/// Schema incluido con la app (el mismo que referencia el JSON del frontend)
+pub const BUNDLED_SCHEMA_JSON: &str =
+    include_str!("../../../src/features/matching/config/matching.schema.json");
+
+/// Nombre del override en AppLocalData
+pub const OVERRIDE_FILE: &str = "matching.config.json";
+
+static SCHEMA: LazyLock<Validator> = LazyLock::new(|| {
+    let schema: Value =
+        serde_json::from_str(BUNDLED_SCHEMA_JSON).expect("bundled matching.schema.json is JSON");
+    jsonschema::validator_for(&schema).expect("bundled matching.schema.json is a valid schema")
+});
+
+/// Configuración incluida con la app, como JSON
+pub fn bundled_config() -> Value {
+    serde_json::from_str(BUNDLED_CONFIG_JSON).expect("bundled matching.config.json is JSON")
+}
+
+/// Aplica `overlay` sobre `base`: los objetos se combinan recursivamente y cualquier
+/// otro valor (incluidas las listas) reemplaza al de `base`
+pub fn merge(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// Valida una configuración completa y la compila.
+/// 1. Schema (se reportan todos los errores, no solo el primero)
+/// 2. Tipos: el schema es más permisivo (ej: penalizaciones con decimales)
+/// 3. Regex de palabras irrelevantes y detección de personas
+pub fn validate(config: &Value) -> Result<MatchingRules, Vec<ConfigIssue>> {
+    let issues: Vec<ConfigIssue> = SCHEMA
+        .iter_errors(config)
+        .map(|e| ConfigIssue::new(e.instance_path.to_string(), e.to_string()))
+        .collect();
+    if !issues.is_empty() {
+        return Err(issues);
+    }
+
+    let parsed: MatchingConfig = serde_path_to_error::deserialize(config).map_err(|e| {
+        vec![ConfigIssue::new(
+            path_to_pointer(e.path()),
+            e.into_inner().to_string(),
+        )]
+    })?;
+
+    MatchingRules::compile(parsed).map_err(|issue| vec![issue])
+}
+
+/// Valida un override aplicado sobre la configuración incluida (sin guardarlo)
+pub fn validate_override(overlay: Value) -> Result<MatchingRules, Vec<ConfigIssue>> {
+    if !overlay.is_object() {
+        return Err(vec![ConfigIssue::new(
+            "",
+            "The override must be a JSON object",
+        )]);
+    }
+    let mut config = bundled_config();
+    merge(&mut config, overlay);
+    validate(&config)
+}
+
+/// Reglas efectivas: la configuración incluida con el override de `override_path`
+/// aplicado encima (si existe)
+pub fn load_rules(override_path: &Path) -> AppResult<MatchingRules> {
+    let overlay = match fs::read_to_string(override_path) {
+        Ok(content) => serde_json::from_str(&content).map_err(|e| {
+            AppError::InvalidConfig(vec![ConfigIssue::new("", format!("Invalid JSON: {}", e))])
+        })?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Value::Object(Default::default()),
+        Err(e) => return Err(AppError::io(e, override_path)),
+    };
+    validate_override(overlay).map_err(AppError::InvalidConfig)
+}
+
+/// Ruta de serde ("scoring.penalties.WEAK_MATCH") como JSON pointer
+fn path_to_pointer(path: &serde_path_to_error::Path) -> String {
+    path.iter()
+        .filter_map(|segment| match segment {
+            Segment::Seq { index } => Some(format!("/{}", index)),
+            Segment::Map { key } | Segment::Enum { variant: key } => {
+                Some(format!("/{}", escape_pointer(key)))
+            }
+            Segment::Unknown => None,
+        })
+        .collect()
+}
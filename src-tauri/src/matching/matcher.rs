@@ -0,0 +1,494 @@
+//! Servicio para emparejar horarios con reuniones de Zoom y validar anfitriones
+//! (equivalente a `matcher.ts`).
+//!
+//! Flujo:
+//! 1. Obtener candidatos: alias aprendido o índice (búsqueda exacta, difusa o por
+//!    conjunto de tokens)
+//! 2. Calcular score para cada candidato aplicando penalizaciones
+//! 3. Decidir resultado basándose en umbrales de score
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use rayon::prelude::*;
+
+use super::aliases::{Alias, AliasTable, AliasTarget};
+use super::config::MatchingRules;
+use super::fuzzy::FuzzyIndex;
+use super::index::{CandidateIndex, Candidates, Retrieval};
+use super::scorer::{evaluate_match, Confidence, Decision};
+use super::trace::MatchTrace;
+use super::{
+    FoundInstructor, MatchOptions, MatchOutcome, MatchResult, MatchStatus, ZoomMeetingCandidate,
+    ZoomUserCandidate,
+};
+use crate::schedule::Schedule;
+
+/// Umbral de Fuse para instructores (más permisivo que el de meetings)
+const USER_FUZZY_THRESHOLD: f64 = 0.45;
+
+struct IndexedMeeting {
+    meeting: ZoomMeetingCandidate,
+    normalized_topic: String,
+}
+
+struct IndexedUser {
+    user: ZoomUserCandidate,
+    normalized_name: String,
+    normalized_display: String,
+}
+
+pub struct MatchingService {
+    rules: Arc<MatchingRules>,
+    meetings: Vec<IndexedMeeting>,
+    users: Vec<IndexedUser>,
+    /// Recuperación de candidatos por topic (exacto, trigramas, tokens)
+    meeting_index: CandidateIndex,
+    meeting_ids: HashMap<String, usize>,
+    users_dict: HashMap<String, usize>,
+    users_dict_display: HashMap<String, usize>,
+    fuzzy_users: FuzzyIndex,
+}
+
+impl MatchingService {
+    pub fn new(
+        meetings: Vec<ZoomMeetingCandidate>,
+        users: Vec<ZoomUserCandidate>,
+        rules: Arc<MatchingRules>,
+    ) -> Self {
+        // 1. Índice de candidatos sobre los topics normalizados
+        let meetings: Vec<IndexedMeeting> = meetings
+            .into_iter()
+            .map(|meeting| IndexedMeeting {
+                normalized_topic: rules.normalize(&meeting.topic),
+                meeting,
+            })
+            .collect();
+        let meeting_index = CandidateIndex::new(
+            meetings
+                .iter()
+                .map(|m| m.normalized_topic.clone())
+                .collect(),
+        );
+        let meeting_ids = meetings
+            .iter()
+            .enumerate()
+            .map(|(idx, m)| (m.meeting.meeting_id.clone(), idx))
+            .collect();
+
+        // 2. Diccionarios de usuarios para búsqueda exacta (normalizada)
+
+        let users: Vec<IndexedUser> = users
+            .into_iter()
+            .map(|user| IndexedUser {
+                normalized_name: rules
+                    .normalize(&format!("{} {}", user.first_name, user.last_name)),
+                normalized_display: rules.normalize(&user.display_name),
+                user,
+            })
+            .collect();
+
+        let mut users_dict = HashMap::new();
+        let mut users_dict_display = HashMap::new();
+        for (idx, u) in users.iter().enumerate() {
+            let full_name = format!("{} {}", u.user.first_name, u.user.last_name);
+            if !full_name.trim().is_empty() {
+                users_dict.insert(rules.normalize(full_name.trim()), idx);
+            }
+            if !u.user.display_name.is_empty() {
+                users_dict_display.insert(u.normalized_display.clone(), idx);
+            }
+        }
+
+        // 3. Índice difuso de usuarios por nombre y display name
+        let fuzzy_users = FuzzyIndex::new(
+            users
+                .iter()
+                .map(|u| vec![u.normalized_name.clone(), u.normalized_display.clone()])
+                .collect(),
+            true,
+        );
+
+        Self {
+            rules,
+            meetings,
+            users,
+            meeting_index,
+            meeting_ids,
+            users_dict,
+            users_dict_display,
+            fuzzy_users,
+        }
+    }
+
+    pub fn rules(&self) -> &MatchingRules {
+        &self.rules
+    }
+
+    /// Vuelve a indexar los mismos meetings y usuarios con otras reglas
+    /// (la normalización puede haber cambiado)
+    pub fn with_rules(&self, rules: Arc<MatchingRules>) -> Self {
+        Self::new(
+            self.meetings.iter().map(|m| m.meeting.clone()).collect(),
+            self.users.iter().map(|u| u.user.clone()).collect(),
+            rules,
+        )
+    }
+
+    /// Si el matcher se indexó con estas reglas (la misma instancia)
+    pub fn uses_rules(&self, rules: &Arc<MatchingRules>) -> bool {
+        Arc::ptr_eq(&self.rules, rules)
+    }
+
+    // =========================================================================
+    // BÚSQUEDA DE CANDIDATOS
+    // =========================================================================
+
+    /// Busca candidatos de meeting con estrategia escalonada:
+    /// 1. Búsqueda exacta en diccionario normalizado
+    /// 2. Búsqueda difusa (distancia de edición, filtrada por trigramas)
+    /// 3. Conjunto de tokens como alternativa
+    fn find_meeting_candidates(&self, program_normalized: &str) -> Candidates {
+        self.meeting_index
+            .retrieve(program_normalized, self.rules.thresholds())
+    }
+
+    /// Meeting al que apunta el alias vigente de la fila, si todavía existe
+    fn find_alias<'t>(
+        &self,
+        aliases: &'t AliasTable,
+        program_normalized: &str,
+        instructor_normalized: &str,
+        now: i64,
+    ) -> Option<(&'t Alias, usize)> {
+        let alias = aliases.lookup(program_normalized, instructor_normalized, now)?;
+        let idx = match &alias.target {
+            AliasTarget::MeetingId(id) => self.meeting_ids.get(id).copied(),
+            AliasTarget::Topic(topic) => self
+                .meeting_index
+                .exact(&self.rules.normalize(topic))
+                .first()
+                .copied(),
+        }?;
+        Some((alias, idx))
+    }
+
+    /// Candidatos con su topic normalizado, en el formato que espera el scorer
+    fn candidate_refs(&self, indices: &[usize]) -> Vec<(&ZoomMeetingCandidate, &str)> {
+        indices
+            .iter()
+            .map(|&idx| {
+                let m = &self.meetings[idx];
+                (&m.meeting, m.normalized_topic.as_str())
+            })
+            .collect()
+    }
+
+    /// Busca el instructor: exacto, subconjunto de tokens y, por último, difuso
+    fn find_instructor(&self, instructor_normalized: &str) -> Option<&ZoomUserCandidate> {
+        // 1. Búsqueda exacta por nombre o display name
+        if let Some(&idx) = self
+            .users_dict
+            .get(instructor_normalized)
+            .or_else(|| self.users_dict_display.get(instructor_normalized))
+        {
+            return Some(&self.users[idx].user);
+        }
+
+        // 2. Subconjunto de tokens: todos los tokens del usuario están en la query.
+        // Con varios, gana el de más tokens (el primero en empate)
+        let query_tokens: HashSet<&str> = instructor_normalized.split(' ').collect();
+        let token_count = |u: &IndexedUser| {
+            u.normalized_name
+                .split(' ')
+                .count()
+                .max(u.normalized_display.split(' ').count())
+        };
+        let mut best: Option<&IndexedUser> = None;
+        for u in &self.users {
+            let matches = u
+                .normalized_name
+                .split(' ')
+                .all(|t| query_tokens.contains(t))
+                || u.normalized_display
+                    .split(' ')
+                    .all(|t| query_tokens.contains(t));
+            if matches && best.is_none_or(|b| token_count(u) > token_count(b)) {
+                best = Some(u);
+            }
+        }
+        if let Some(u) = best {
+            return Some(&u.user);
+        }
+
+        // 3. Difuso, con validación contra falsos positivos por apellido común:
+        // al menos 2 tokens coinciden, o todos si la query tiene 2 tokens o menos
+        let (idx, score) = self
+            .fuzzy_users
+            .search(instructor_normalized, USER_FUZZY_THRESHOLD)
+            .into_iter()
+            .next()?;
+        if score > USER_FUZZY_THRESHOLD {
+            return None;
+        }
+        let candidate = &self.users[idx];
+        let candidate_tokens: HashSet<&str> = candidate
+            .normalized_name
+            .split(' ')
+            .chain(candidate.normalized_display.split(' '))
+            .collect();
+        let query_tokens: Vec<&str> = instructor_normalized.split(' ').collect();
+        let matching = query_tokens
+            .iter()
+            .filter(|t| candidate_tokens.contains(*t))
+            .count();
+        let min_required = query_tokens.len().min(2);
+
+        (matching >= min_required).then_some(&candidate.user)
+    }
+
+    // =========================================================================
+    // MATCHING
+    // =========================================================================
+
+    /// Mejor coincidencia para un horario (meeting + validación de anfitrión)
+    pub fn find_match(&self, schedule: &Schedule, options: MatchOptions) -> MatchOutcome {
+        self.find_match_with_aliases(schedule, options, &AliasTable::default(), 0)
+    }
+
+    /// Como `find_match`, pero un alias vigente en `now` (ms) tiene prioridad sobre la
+    /// búsqueda: su meeting se asigna aunque el scoring lo considere ambiguo
+    pub fn find_match_with_aliases(
+        &self,
+        schedule: &Schedule,
+        options: MatchOptions,
+        aliases: &AliasTable,
+        now: i64,
+    ) -> MatchOutcome {
+        let mut result = MatchOutcome {
+            dictionary_version: self.rules.dictionary_version,
+            ..Default::default()
+        };
+
+        let program_normalized = self.rules.normalize(&schedule.program);
+        let instructor_normalized = self.rules.normalize(&schedule.instructor);
+
+        // PASO 1: encontrar instructor antes de evaluar meetings,
+        // para que found_instructor esté disponible en todos los status
+        let instructor = if self.users.is_empty() {
+            None
+        } else {
+            self.find_instructor(&instructor_normalized)
+        };
+        result.found_instructor = instructor.map(FoundInstructor::from);
+
+        // PASO 2: candidatos de meeting (el alias aprendido va primero)
+        let alias = self.find_alias(aliases, &program_normalized, &instructor_normalized, now);
+        let found = match alias {
+            Some((_, idx)) => Candidates {
+                strategy: Retrieval::Alias,
+                indices: vec![idx],
+            },
+            None => self.find_meeting_candidates(&program_normalized),
+        };
+        if found.indices.is_empty() {
+            result.reason = "Meeting not found".to_string();
+            result.trace = options
+                .trace
+                .then(|| MatchTrace::empty(&program_normalized));
+            return result;
+        }
+
+        // PASO 3: evaluar candidatos con el sistema de scoring
+        let candidates = self.candidate_refs(&found.indices);
+        let mut evaluation = evaluate_match(&self.rules, &schedule.program, &candidates, options);
+        if alias.is_some() {
+            // La elección del operador prevalece sobre el scoring
+            evaluation.best_match = evaluation.all_results.first().cloned();
+            evaluation.decision = Decision::Assigned;
+            evaluation.confidence = Confidence::High;
+        }
+        result.trace = options
+            .trace
+            .then(|| MatchTrace::from_evaluation(&program_normalized, found.strategy, &evaluation));
+
+        match evaluation.decision {
+            Decision::NotFound => {
+                result.reason = evaluation.reason;
+                result.detailed_reason = evaluation.detailed_reason;
+                result.best_match = evaluation.best_match.map(|b| b.candidate.clone());
+                return result;
+            }
+            Decision::Ambiguous => {
+                result.status = MatchStatus::Ambiguous;
+                result.reason = evaluation.reason;
+                result.detailed_reason = evaluation.detailed_reason;
+                result.ambiguous_candidates = evaluation
+                    .ambiguous_candidates
+                    .map(|c| c.into_iter().cloned().collect());
+                if let Some(best) = evaluation.best_match {
+                    result.best_match = Some(best.candidate.clone());
+                    result.score = Some(best.final_score);
+                }
+                return result;
+            }
+            Decision::Assigned => {}
+        }
+
+        // Match encontrado
+        let best = evaluation
+            .best_match
+            .expect("assigned evaluation has a best match");
+        let meeting = best.candidate;
+        let score = best.final_score;
+        result.meeting_id = Some(meeting.meeting_id.clone());
+        result.matched_candidate = Some(meeting.clone());
+        result.best_match = Some(meeting.clone());
+        result.score = Some(score);
+
+        // PASO 4: validar anfitrión.
+        // Sin usuarios cargados (tests de meetings) no hay validación
+        if self.users.is_empty() {
+            result.status = MatchStatus::Assigned;
+            result.reason = format!("Score: {} (No instructor validation)", score);
+            return result;
+        }
+
+        let Some(instructor) = instructor else {
+            result.status = MatchStatus::NotFound;
+            result.reason = "Instructor not found".to_string();
+            return result;
+        };
+
+        result.status = if meeting.host_id == instructor.id {
+            MatchStatus::Assigned
+        } else {
+            MatchStatus::ToUpdate
+        };
+        result.reason = if evaluation.confidence == Confidence::High {
+            "-".to_string()
+        } else {
+            format!("Score: {}", score)
+        };
+        if let Some((alias, _)) = alias {
+            result.reason = "Learned alias".to_string();
+            result.detailed_reason = Some(format!(
+                "Assigned by alias #{} from a previous manual resolution",
+                alias.id
+            ));
+        }
+
+        result
+    }
+
+    /// Coincidencia solo por tema, sin validación de instructor.
+    /// Usado para verificar si ya existe una reunión (CreateLinkModal).
+    pub fn find_match_by_topic(&self, topic: &str, options: MatchOptions) -> MatchOutcome {
+        let mut result = MatchOutcome {
+            dictionary_version: self.rules.dictionary_version,
+            ..Default::default()
+        };
+
+        let topic_normalized = self.rules.normalize(topic);
+        let found = self.find_meeting_candidates(&topic_normalized);
+        result.candidates = found
+            .indices
+            .iter()
+            .map(|&idx| self.meetings[idx].meeting.clone())
+            .collect();
+
+        if found.indices.is_empty() {
+            result.reason = "Not found".to_string();
+            result.trace = options.trace.then(|| MatchTrace::empty(&topic_normalized));
+            return result;
+        }
+
+        let candidates = self.candidate_refs(&found.indices);
+        let evaluation = evaluate_match(&self.rules, topic, &candidates, options);
+        result.trace = options
+            .trace
+            .then(|| MatchTrace::from_evaluation(&topic_normalized, found.strategy, &evaluation));
+
+        match evaluation.decision {
+            Decision::NotFound => {
+                result.reason = evaluation.reason;
+                result.detailed_reason = evaluation.detailed_reason;
+                if let Some(best) = evaluation.best_match {
+                    result.best_match = Some(best.candidate.clone());
+                    result.matched_candidate = Some(best.candidate.clone());
+                }
+            }
+            Decision::Ambiguous => {
+                result.status = MatchStatus::Ambiguous;
+                result.reason = evaluation.reason;
+                result.detailed_reason = evaluation.detailed_reason;
+                result.ambiguous_candidates = evaluation
+                    .ambiguous_candidates
+                    .map(|c| c.into_iter().cloned().collect());
+                if let Some(best) = evaluation.best_match {
+                    result.best_match = Some(best.candidate.clone());
+                    result.score = Some(best.final_score);
+                }
+            }
+            Decision::Assigned => {
+                let best = evaluation
+                    .best_match
+                    .expect("assigned evaluation has a best match");
+                result.status = MatchStatus::Assigned;
+                result.meeting_id = Some(best.candidate.meeting_id.clone());
+                result.matched_candidate = Some(best.candidate.clone());
+                result.best_match = Some(best.candidate.clone());
+                result.score = Some(best.final_score);
+                result.reason = format!("Score: {}", best.final_score);
+            }
+        }
+
+        result
+    }
+
+    /// Como `find_match_with_aliases` para cada horario, repartidos entre los núcleos.
+    /// Los resultados conservan el orden de `schedules`.
+    /// `on_progress(done, total)` se llama cada ~1% de avance y al terminar (desde los
+    /// hilos de trabajo, por eso `done` puede llegar desordenado).
+    /// Retorna None si `cancel` se activó antes de procesar todos los horarios.
+    pub fn match_batch(
+        &self,
+        schedules: &[Schedule],
+        options: MatchOptions,
+        aliases: &AliasTable,
+        now: i64,
+        cancel: &AtomicBool,
+        on_progress: impl Fn(usize, usize) + Sync,
+    ) -> Option<Vec<MatchOutcome>> {
+        let total = schedules.len();
+        let step = (total / 100).max(1);
+        let done = AtomicUsize::new(0);
+
+        schedules
+            .par_iter()
+            .map(|schedule| {
+                if cancel.load(Ordering::Relaxed) {
+                    return None;
+                }
+                let outcome = self.find_match_with_aliases(schedule, options, aliases, now);
+                let count = done.fetch_add(1, Ordering::Relaxed) + 1;
+                if count.is_multiple_of(step) || count == total {
+                    on_progress(count, total);
+                }
+                Some(outcome)
+            })
+            .collect()
+    }
+
+    /// Procesa todos los horarios
+    pub fn match_all(&self, schedules: &[Schedule]) -> Vec<MatchResult> {
+        schedules
+            .iter()
+            .map(|s| MatchResult {
+                schedule: s.clone(),
+                outcome: self.find_match(s, MatchOptions::default()),
+            })
+            .collect()
+    }
+}
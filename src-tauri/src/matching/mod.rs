@@ -0,0 +1,438 @@
+//! Matching de horarios con reuniones de Zoom (equivalente a `features/matching`).
+//!
+//! El frontend inicializa el matcher con los meetings y usuarios de Zoom
+//! (`init_matching`) y luego ejecuta `run_matching` sobre los horarios cargados.
+//! Las reglas salen de `matching.config.json` (ver [`loader`]) y las resoluciones manuales
+//! se recuerdan como alias (ver [`aliases`]).
+
+pub mod aliases;
+pub mod allocation;
+pub mod config;
+pub mod dictionary;
+pub mod fuzzy;
+pub mod hosts;
+pub mod index;
+pub mod loader;
+pub mod matcher;
+pub mod normalizer;
+pub mod penalties;
+pub mod report;
+pub mod scorer;
+pub mod trace;
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::error::{AppError, AppResult};
+use crate::schedule::{self, FieldError, Schedule};
+use aliases::AliasTable;
+pub use config::MatchingRules;
+pub use matcher::MatchingService;
+use trace::MatchTrace;
+
+// =============================================================================
+// TIPOS DE DATOS
+// =============================================================================
+
+/// Reunión de Zoom candidata (fila de `zoom_meetings`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ZoomMeetingCandidate {
+    pub meeting_id: String,
+    pub topic: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    pub host_id: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    pub start_time: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub join_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+}
+
+/// Usuario de Zoom (fila de `zoom_users`); los campos nulos se tratan como vacíos
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ZoomUserCandidate {
+    pub id: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    pub email: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    pub first_name: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    pub last_name: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    pub display_name: String,
+}
+
+fn null_as_empty<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Opciones para configurar el comportamiento del matching
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MatchOptions {
+    /// Los conflictos de nivel no descartan (para detección de duplicados)
+    pub ignore_level_mismatch: bool,
+    /// Incluir la traza del matching (`MatchOutcome::trace`)
+    pub trace: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchStatus {
+    Assigned,
+    ToUpdate,
+    #[default]
+    NotFound,
+    Ambiguous,
+    Manual,
+}
+
+impl MatchStatus {
+    /// Mismo valor que en el JSON ("to_update", "not_found"...)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MatchStatus::Assigned => "assigned",
+            MatchStatus::ToUpdate => "to_update",
+            MatchStatus::NotFound => "not_found",
+            MatchStatus::Ambiguous => "ambiguous",
+            MatchStatus::Manual => "manual",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FoundInstructor {
+    pub id: String,
+    pub email: String,
+    pub display_name: String,
+}
+
+impl From<&ZoomUserCandidate> for FoundInstructor {
+    fn from(user: &ZoomUserCandidate) -> Self {
+        Self {
+            id: user.id.clone(),
+            email: user.email.clone(),
+            display_name: user.display_name.clone(),
+        }
+    }
+}
+
+/// Resultado del matching de un horario (campos de `MatchResult` sin el horario)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MatchOutcome {
+    pub status: MatchStatus,
+    /// Mensaje corto para la columna Reason
+    pub reason: String,
+    /// Mensaje detallado para el hover card
+    #[serde(
+        rename = "detailedReason",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub detailed_reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meeting_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub found_instructor: Option<FoundInstructor>,
+    #[serde(rename = "bestMatch", default, skip_serializing_if = "Option::is_none")]
+    pub best_match: Option<ZoomMeetingCandidate>,
+    #[serde(default)]
+    pub candidates: Vec<ZoomMeetingCandidate>,
+    #[serde(
+        rename = "ambiguousCandidates",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ambiguous_candidates: Option<Vec<ZoomMeetingCandidate>>,
+    #[serde(
+        rename = "matchedCandidate",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub matched_candidate: Option<ZoomMeetingCandidate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<i32>,
+    /// Traza del matching, solo si se pidió con `MatchOptions::trace`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace: Option<MatchTrace>,
+    /// Versión del diccionario del usuario con la que se normalizó (0 = ninguno)
+    #[serde(rename = "dictionaryVersion", default)]
+    pub dictionary_version: u32,
+}
+
+/// Evento emitido por `run_matching` a medida que avanza
+pub const PROGRESS_EVENT: &str = "matching://progress";
+
+/// Payload de `matching://progress`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchingProgress {
+    pub done: usize,
+    pub total: usize,
+}
+
+/// Horario con su resultado de matching (equivalente a `MatchResult`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchResult {
+    pub schedule: Schedule,
+    #[serde(flatten)]
+    pub outcome: MatchOutcome,
+}
+
+// =============================================================================
+// COMANDOS
+// =============================================================================
+
+/// Reglas vigentes, alias aprendidos y matcher activo, construido con los últimos
+/// datos de Zoom
+pub struct MatcherState {
+    rules: RwLock<Arc<MatchingRules>>,
+    aliases: RwLock<Arc<AliasTable>>,
+    service: RwLock<Option<Arc<MatchingService>>>,
+    /// Bandera de cancelación del `run_matching` en curso
+    running: Mutex<Option<Arc<AtomicBool>>>,
+}
+
+impl Default for MatcherState {
+    fn default() -> Self {
+        Self {
+            rules: RwLock::new(MatchingRules::bundled()),
+            aliases: RwLock::default(),
+            service: RwLock::new(None),
+            running: Mutex::new(None),
+        }
+    }
+}
+
+impl MatcherState {
+    fn current(&self) -> AppResult<Arc<MatchingService>> {
+        self.service
+            .read()
+            .unwrap()
+            .clone()
+            .ok_or(AppError::MatcherNotInitialized)
+    }
+
+    fn rules(&self) -> Arc<MatchingRules> {
+        self.rules.read().unwrap().clone()
+    }
+
+    fn aliases(&self) -> Arc<AliasTable> {
+        self.aliases.read().unwrap().clone()
+    }
+
+    /// Recarga la configuración (incluida + override + diccionario del usuario, de
+    /// AppLocalData). Si el override es inválido se conservan las reglas actuales; si el
+    /// diccionario no se puede leer o es inválido se registra y se usa el override sin él.
+    pub fn reload_config(&self, app: &AppHandle) -> AppResult<()> {
+        let rules = loader::load_rules(&config_override_path(app)?)?;
+        let with_dictionary = dictionary::dictionary_path(app)
+            .and_then(|path| dictionary::load(&path))
+            .and_then(|dictionary| {
+                rules
+                    .with_dictionary(&dictionary)
+                    .map_err(AppError::InvalidConfig)
+            });
+        let rules = match with_dictionary {
+            Ok(rules) => rules,
+            Err(e) => {
+                eprintln!("Matching dictionary ignored: {}", e);
+                rules
+            }
+        };
+        self.set_rules(app, rules)
+    }
+
+    /// Registra una ejecución nueva; la anterior (si sigue en curso) se cancela
+    fn begin_run(&self) -> Arc<AtomicBool> {
+        let cancel = Arc::new(AtomicBool::new(false));
+        if let Some(previous) = self.running.lock().unwrap().replace(cancel.clone()) {
+            previous.store(true, Ordering::Relaxed);
+        }
+        cancel
+    }
+
+    /// Libera la ejecución si sigue siendo la actual
+    fn end_run(&self, cancel: &Arc<AtomicBool>) {
+        let mut running = self.running.lock().unwrap();
+        if running.as_ref().is_some_and(|r| Arc::ptr_eq(r, cancel)) {
+            *running = None;
+        }
+    }
+
+    /// Reemplaza las reglas y vuelve a indexar el matcher activo con ellas, con los
+    /// mismos datos de Zoom. Todo ocurre con el matcher bloqueado para escritura, así un
+    /// `init_matching` en curso no puede instalar un matcher con las reglas anteriores.
+    /// Después se recalculan las claves de los alias aprendidos.
+    fn set_rules(&self, app: &AppHandle, rules: MatchingRules) -> AppResult<()> {
+        {
+            let rules = Arc::new(rules);
+            let mut service = self.service.write().unwrap();
+            if let Some(current) = service.as_ref() {
+                *service = Some(Arc::new(current.with_rules(rules.clone())));
+            }
+            *self.rules.write().unwrap() = rules;
+        }
+        self.rekey_aliases(app)
+    }
+}
+
+fn config_override_path(app: &AppHandle) -> AppResult<PathBuf> {
+    Ok(app.path().app_local_data_dir()?.join(loader::OVERRIDE_FILE))
+}
+
+/// Construye el matcher con los meetings y usuarios de Zoom (indexación costosa,
+/// se hace una vez por carga de datos y se reutiliza en cada `run_matching`)
+#[tauri::command]
+pub async fn init_matching(
+    state: State<'_, MatcherState>,
+    meetings: Vec<ZoomMeetingCandidate>,
+    users: Vec<ZoomUserCandidate>,
+) -> AppResult<()> {
+    let rules = state.rules();
+    let service =
+        tauri::async_runtime::spawn_blocking(move || MatchingService::new(meetings, users, rules))
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
+    // Si las reglas cambiaron mientras se indexaba, se vuelve a indexar con las vigentes
+    let mut current = state.service.write().unwrap();
+    let rules = state.rules();
+    *current = Some(Arc::new(if service.uses_rules(&rules) {
+        service
+    } else {
+        service.with_rules(rules)
+    }));
+    Ok(())
+}
+
+/// Resultado de una fila que no pasó la validación (ej: una hora editada a mano)
+pub fn invalid_row_outcome(error: &FieldError) -> MatchOutcome {
+    MatchOutcome {
+        status: MatchStatus::NotFound,
+        reason: "Invalid row".to_string(),
+        detailed_reason: Some(error.to_string()),
+        ..Default::default()
+    }
+}
+
+/// Empareja las filas recibidas del frontend, en el mismo orden.
+/// Una fila inválida no detiene el lote: vuelve como `not_found` con su error de
+/// validación ([`invalid_row_outcome`]) y las demás se emparejan igual.
+/// Retorna None si se canceló.
+pub fn match_rows(
+    service: &MatchingService,
+    rows: Vec<Value>,
+    options: MatchOptions,
+    aliases: &AliasTable,
+    now: i64,
+    cancel: &AtomicBool,
+    on_progress: impl Fn(usize, usize) + Sync,
+) -> Option<Vec<MatchOutcome>> {
+    let parsed = schedule::parse_json_rows::<Schedule>(rows);
+    let valid: Vec<Schedule> = parsed.iter().flatten().cloned().collect();
+    let mut matched = service
+        .match_batch(&valid, options, aliases, now, cancel, on_progress)?
+        .into_iter();
+
+    parsed
+        .iter()
+        .map(|row| match row {
+            Ok(_) => matched.next(),
+            Err(error) => Some(invalid_row_outcome(error)),
+        })
+        .collect()
+}
+
+/// Ejecuta el matching sobre los horarios, en paralelo entre los núcleos. Los resultados
+/// vienen en el mismo orden que los horarios recibidos (sin el horario, que el frontend
+/// ya tiene).
+/// 1. Los alias aprendidos se consultan antes de la búsqueda difusa
+/// 2. Con `options.trace` cada resultado incluye la traza del matching
+/// 3. El avance se emite como `matching://progress` ({ done, total })
+/// 4. Las filas inválidas vuelven como `not_found` con el error, sin detener el lote
+/// 5. Falla con CANCELLED si se llamó `cancel_matching` o empezó otra ejecución
+#[tauri::command]
+pub async fn run_matching(
+    app: AppHandle,
+    state: State<'_, MatcherState>,
+    schedules: Vec<Value>,
+    options: Option<MatchOptions>,
+) -> AppResult<Vec<MatchOutcome>> {
+    let service = state.current()?;
+    let options = options.unwrap_or_default();
+    let aliases = state.aliases();
+    let cancel = state.begin_run();
+
+    let flag = cancel.clone();
+    let outcomes = tauri::async_runtime::spawn_blocking(move || {
+        let now = crate::time::now_ms();
+        match_rows(
+            &service,
+            schedules,
+            options,
+            &aliases,
+            now,
+            &flag,
+            |done, total| {
+                if let Err(e) = app.emit(PROGRESS_EVENT, MatchingProgress { done, total }) {
+                    eprintln!("Failed to emit matching progress: {}", e);
+                }
+            },
+        )
+    })
+    .await;
+    state.end_run(&cancel);
+
+    outcomes
+        .map_err(|e| AppError::Internal(e.to_string()))?
+        .ok_or(AppError::Cancelled)
+}
+
+/// Cancela el `run_matching` en curso (ej: el usuario cerró la vista).
+/// Retorna false si no había ninguno.
+#[tauri::command]
+pub fn cancel_matching(state: State<'_, MatcherState>) -> bool {
+    match state.running.lock().unwrap().take() {
+        Some(cancel) => {
+            cancel.store(true, Ordering::Relaxed);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Busca una reunión existente solo por tema (sin validar instructor)
+#[tauri::command]
+pub fn match_topic(
+    state: State<'_, MatcherState>,
+    topic: String,
+    options: Option<MatchOptions>,
+) -> AppResult<MatchOutcome> {
+    Ok(state
+        .current()?
+        .find_match_by_topic(&topic, options.unwrap_or_default()))
+}
+
+/// Recarga `matching.config.json` desde AppLocalData y vuelve a indexar el matcher activo.
+/// Retorna los errores de validación (JSON pointer + mensaje) si el override es inválido.
+#[tauri::command]
+pub async fn reload_matching_config(app: AppHandle) -> AppResult<()> {
+    tauri::async_runtime::spawn_blocking(move || app.state::<MatcherState>().reload_config(&app))
+        .await
+        .map_err(|e| AppError::Internal(e.to_string()))?
+}
+
+/// Valida un override de configuración sin aplicarlo (ej: antes de guardarlo)
+#[tauri::command]
+pub fn validate_matching_config(config: Value) -> AppResult<()> {
+    loader::validate_override(config)
+        .map(|_| ())
+        .map_err(AppError::InvalidConfig)
+}
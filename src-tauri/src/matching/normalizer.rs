@@ -0,0 +1,119 @@
+//! Normalización de cadenas para búsqueda difusa (equivalente a `normalizer.ts`).
+
+use unicode_normalization::UnicodeNormalization;
+
+use super::config::MatchingRules;
+
+/// Espacios de `\s` en JavaScript (incluye NBSP, BOM y separadores Unicode)
+pub(crate) fn is_js_whitespace(c: char) -> bool {
+    const EXTRA: [char; 8] = [
+        '\u{A0}', '\u{1680}', '\u{2028}', '\u{2029}', '\u{202F}', '\u{205F}', '\u{3000}',
+        '\u{FEFF}',
+    ];
+    matches!(c, '\t' | '\n' | '\u{0B}' | '\u{0C}' | '\r' | ' ')
+        || ('\u{2000}'..='\u{200A}').contains(&c)
+        || EXTRA.contains(&c)
+}
+
+/// Colapsa secuencias de espacios (`\s+` -> " ") y recorta los extremos
+pub(crate) fn collapse_whitespace(s: &str) -> String {
+    s.split(is_js_whitespace)
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl MatchingRules {
+    /// Elimina palabras irrelevantes del texto basado en la lista configurada
+    pub fn remove_irrelevant(&self, text: &str) -> String {
+        if text.is_empty() {
+            return String::new();
+        }
+        match &self.irrelevant_pattern {
+            Some(pattern) => collapse_whitespace(&pattern.replace_all(text, " ")),
+            None => collapse_whitespace(text),
+        }
+    }
+
+    /// Lógica central de normalización.
+    /// 1. Pre-limpiar caracteres especiales (underscores y guiones a espacios)
+    /// 2. Eliminar palabras irrelevantes
+    /// 3. Normalizar Unicode (NFD) y eliminar diacríticos
+    /// 4. Convertir a minúsculas y limpiar
+    /// 5. Reemplazar los sinónimos del diccionario del usuario por su forma canónica
+    pub fn normalize(&self, s: &str) -> String {
+        if s.is_empty() {
+            return String::new();
+        }
+
+        // 1. Pre-limpiar: "F2F_PER" -> "F2F PER" para que ambas palabras se eliminen
+        let processed = pre_clean(s);
+
+        // 2. Eliminar palabras irrelevantes
+        let processed = self.remove_irrelevant(&processed);
+
+        // 3-4. Sin acentos, minúsculas y sin caracteres especiales
+        let processed = fold(&processed);
+
+        // 5. Sinónimos ("3 pax" -> "trio")
+        if self.synonym_rewrites.is_empty() {
+            return processed;
+        }
+        let mut processed = processed;
+        for (variant, canonical) in &self.synonym_rewrites {
+            if let std::borrow::Cow::Owned(replaced) =
+                variant.replace_all(&processed, canonical.as_str())
+            {
+                processed = replaced;
+            }
+        }
+        collapse_whitespace(&processed)
+    }
+
+    /// Tokens no vacíos de la cadena normalizada
+    pub fn tokenize(&self, s: &str) -> Vec<String> {
+        split_tokens(&self.normalize(s))
+    }
+}
+
+/// Underscores y guiones a espacios
+pub(crate) fn pre_clean(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '-' | '_' | '–' | '—' => ' ',
+            c => c,
+        })
+        .collect()
+}
+
+/// Pasos 3 y 4 de `normalize`: descompone (NFD) y elimina acentos, pasa a minúsculas y
+/// reemplaza los caracteres especiales por espacios
+pub(crate) fn fold(s: &str) -> String {
+    let processed: String = s
+        .nfd()
+        .filter(|c| !('\u{0300}'..='\u{036F}').contains(c))
+        .collect();
+
+    // Comillas normalizadas, el resto de caracteres especiales a espacios.
+    // `\w` en JavaScript es solo ASCII: letras sin equivalente ASCII también se descartan
+    let processed: String = processed
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'ʻ' | '‚' => '\'',
+            c if c.is_ascii_alphanumeric() || c == '_' || c == '\'' => c,
+            _ => ' ',
+        })
+        .collect();
+
+    collapse_whitespace(&processed)
+}
+
+/// Separa una cadena ya normalizada en tokens no vacíos
+pub(crate) fn split_tokens(normalized: &str) -> Vec<String> {
+    normalized
+        .split(' ')
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .collect()
+}
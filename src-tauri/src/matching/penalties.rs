@@ -0,0 +1,639 @@
+//! Funciones de penalización individuales (equivalente a `penalties.ts`).
+//! Cada función evalúa una condición específica y retorna la penalización si aplica.
+
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::config::MatchingRules;
+use super::normalizer::split_tokens;
+use super::{MatchOptions, ZoomMeetingCandidate};
+
+/// Contexto de scoring: toda la información necesaria para evaluar un candidato.
+/// `'a` es la vida de los meetings indexados, `'s` la de la evaluación en curso.
+pub struct ScoringContext<'s, 'a> {
+    pub rules: &'s MatchingRules,
+    pub raw_program: &'s str,
+    pub raw_topic: &'a str,
+    pub normalized_program: &'s str,
+    pub normalized_topic: &'a str,
+    pub candidate: &'a ZoomMeetingCandidate,
+    /// Todos los candidatos evaluados junto con su topic normalizado
+    pub all_candidates: &'s [(&'a ZoomMeetingCandidate, &'a str)],
+    pub options: MatchOptions,
+}
+
+/// Una penalización aplicada durante el scoring
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppliedPenalty {
+    pub name: String,
+    pub points: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Metadatos estructurados para lógica programática
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<CoverageMetadata>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoverageMetadata {
+    pub coverage: f64,
+    pub min_coverage: f64,
+}
+
+impl AppliedPenalty {
+    fn new(name: &str, points: i32, reason: String) -> Self {
+        Self {
+            name: name.to_string(),
+            points,
+            reason: Some(reason),
+            metadata: None,
+        }
+    }
+}
+
+/// Función de penalización: retorna la penalización (puntos negativos) o None
+pub type PenaltyFunction = fn(&ScoringContext) -> Option<AppliedPenalty>;
+
+/// Registro de todas las penalizaciones (en orden de evaluación)
+pub const ALL_PENALTIES: &[PenaltyFunction] = &[
+    critical_token_mismatch,
+    level_conflict,
+    company_conflict,
+    program_vs_person,
+    structural_token_missing,
+    weak_match,
+    group_number_conflict,
+    numeric_conflict,
+    orphan_number_with_siblings,
+    orphan_level_with_siblings,
+];
+
+// ============================================================================
+// UTILIDADES COMUNES
+// ============================================================================
+
+/// Indicadores de nivel (L3, N4, Level 5, Nivel 3)
+static LEVEL_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?-u:\b)(?:l|n|level|nivel)[\s\x{FEFF}]*([0-9]+)(?-u:\b)").unwrap()
+});
+/// Niveles cortos (L3, N4) para comparar candidatos hermanos
+static SHORT_LEVEL_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)(?-u:\b)(?:l|n)([0-9]+)(?-u:\b)").unwrap());
+static NUMBER_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[0-9]+").unwrap());
+/// Códigos cortos como l7, n8, fr3, kb1
+static CODE_TOKEN_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^[a-z]{1,3}[0-9]+$").unwrap());
+static LEVEL_TOKEN_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^l[0-9]+$").unwrap());
+/// Contenido entre paréntesis: (HAYDUK), (SCOTIABANK), (ONLINE)
+static PARENTHESES_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\(([^)]+)\)").unwrap());
+
+fn is_digits(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Tokens de más de 2 caracteres que no son solo números
+fn distinctive_tokens(normalized: &str) -> Vec<String> {
+    normalized
+        .split(' ')
+        .filter(|t| t.chars().count() > 2 && !is_digits(t))
+        .map(str::to_string)
+        .collect()
+}
+
+fn extract_levels(s: &str) -> Vec<String> {
+    LEVEL_PATTERN
+        .captures_iter(s)
+        .map(|c| c[1].to_string())
+        .collect()
+}
+
+fn extract_numbers(s: &str) -> Vec<String> {
+    NUMBER_PATTERN
+        .find_iter(s)
+        .map(|m| m.as_str().to_string())
+        .collect()
+}
+
+fn extract_non_level_numbers(s: &str) -> Vec<String> {
+    extract_numbers(&LEVEL_PATTERN.replace_all(s, ""))
+}
+
+/// Valores únicos conservando el orden de aparición (como `[...new Set(arr)]`)
+fn unique(values: Vec<String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    values
+        .into_iter()
+        .filter(|v| seen.insert(v.clone()))
+        .collect()
+}
+
+/// Distancia de Levenshtein entre dos strings
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.is_empty() {
+        return b.len();
+    }
+    if b.is_empty() {
+        return a.len();
+    }
+    // `a` siempre es el más corto para minimizar memoria
+    let (a, b) = if a.len() > b.len() { (b, a) } else { (a, b) };
+
+    let mut prev: Vec<usize> = (0..=a.len()).collect();
+    let mut curr = vec![0; a.len() + 1];
+    for i in 1..=b.len() {
+        curr[0] = i;
+        for j in 1..=a.len() {
+            curr[j] = if b[i - 1] == a[j - 1] {
+                prev[j - 1]
+            } else {
+                1 + prev[j].min(curr[j - 1]).min(prev[j - 1])
+            };
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[a.len()]
+}
+
+// ============================================================================
+// PENALIZACIONES
+// ============================================================================
+
+/// Detecta qué tipo de programa está presente en un conjunto de tokens.
+/// El orden de `programTypeGroups` determina la prioridad.
+fn detect_program_type<'a>(rules: &'a MatchingRules, tokens: &HashSet<String>) -> Option<&'a str> {
+    rules
+        .config
+        .tokens
+        .program_type_groups
+        .iter()
+        .find(|group| group.tokens.iter().any(|t| tokens.contains(t)))
+        .map(|group| group.id.as_str())
+}
+
+/// CH vs TRIO vs DUO - tokens mutuamente excluyentes
+pub fn critical_token_mismatch(ctx: &ScoringContext) -> Option<AppliedPenalty> {
+    let q_tokens: HashSet<String> = split_tokens(ctx.normalized_program).into_iter().collect();
+    let t_tokens: HashSet<String> = split_tokens(ctx.normalized_topic).into_iter().collect();
+
+    let query_type = detect_program_type(ctx.rules, &q_tokens)?;
+    let topic_type = detect_program_type(ctx.rules, &t_tokens)?;
+
+    // Si ambos tienen un tipo y son diferentes, es conflicto crítico
+    (query_type != topic_type).then(|| {
+        AppliedPenalty::new(
+            "CRITICAL_TOKEN_MISMATCH",
+            ctx.rules.penalties().critical_token_mismatch,
+            format!("{} vs {}", query_type, topic_type),
+        )
+    })
+}
+
+/// Conflicto de nivel (L3 vs L2)
+pub fn level_conflict(ctx: &ScoringContext) -> Option<AppliedPenalty> {
+    let q_levels = unique(extract_levels(ctx.raw_program));
+    let t_levels = unique(extract_levels(ctx.raw_topic));
+
+    if q_levels.is_empty() || t_levels.is_empty() || q_levels.iter().any(|l| t_levels.contains(l)) {
+        return None;
+    }
+
+    let levels = format!("L{} vs L{}", q_levels.join("/"), t_levels.join("/"));
+
+    // Para detección de duplicados el conflicto de nivel no descarta
+    if ctx.options.ignore_level_mismatch {
+        return Some(AppliedPenalty::new(
+            "LEVEL_MISMATCH_IGNORED",
+            ctx.rules.penalties().level_mismatch_ignored,
+            format!("Ignorado: {}", levels),
+        ));
+    }
+
+    Some(AppliedPenalty::new(
+        "LEVEL_CONFLICT",
+        ctx.rules.penalties().level_conflict,
+        levels,
+    ))
+}
+
+/// Query es programa pero topic es persona.
+///
+/// No penaliza si la query tiene prefijo BV* (BVP/BVD/BVS): esos prefijos suelen estar
+/// en los schedules pero NO en los topics de Zoom, y la persona del topic ES el match.
+pub fn program_vs_person(ctx: &ScoringContext) -> Option<AppliedPenalty> {
+    let q_tokens: HashSet<String> = split_tokens(ctx.normalized_program).into_iter().collect();
+    let t_tokens: HashSet<String> = split_tokens(ctx.normalized_topic).into_iter().collect();
+    let rules = ctx.rules;
+
+    let query_is_program = rules.program_types.iter().any(|t| q_tokens.contains(t));
+    if !query_is_program || !rules.is_person_format(ctx.raw_topic) {
+        return None;
+    }
+
+    // Excepción 1: el topic también tiene tokens de programa ("TRIO GRUPO A - L3")
+    let topic_is_also_program = rules.program_types.iter().any(|t| t_tokens.contains(t))
+        || rules.structural.iter().any(|t| t_tokens.contains(t));
+    if topic_is_also_program {
+        return None;
+    }
+
+    // Excepción 2: BVP/BVS/BVD denotan clases con alumnos específicos (asignadas a personas)
+    if ["bvp", "bvd", "bvs"].iter().any(|t| q_tokens.contains(*t)) {
+        return None;
+    }
+
+    Some(AppliedPenalty::new(
+        "PROGRAM_VS_PERSON",
+        rules.penalties().program_vs_person,
+        "Query busca programa, topic es persona".to_string(),
+    ))
+}
+
+/// Token estructural (TRIO/CH/DUO) en query pero no en topic
+pub fn structural_token_missing(ctx: &ScoringContext) -> Option<AppliedPenalty> {
+    // En modo relajado (buscando duplicados) se ignoran tokens estructurales faltantes
+    if ctx.options.ignore_level_mismatch {
+        return None;
+    }
+
+    let q_tokens: HashSet<String> = split_tokens(ctx.normalized_program).into_iter().collect();
+    let t_tokens: HashSet<String> = split_tokens(ctx.normalized_topic).into_iter().collect();
+
+    for group in &ctx.rules.config.tokens.synonyms {
+        let topic_has_group = group.iter().any(|t| t_tokens.contains(t));
+        if let Some(missing) = group.iter().find(|t| q_tokens.contains(*t)) {
+            if !topic_has_group {
+                return Some(AppliedPenalty::new(
+                    "STRUCTURAL_TOKEN_MISSING",
+                    ctx.rules.penalties().structural_token_missing,
+                    format!("\"{}\" no está en topic", missing.to_uppercase()),
+                ));
+            }
+        }
+    }
+
+    None
+}
+
+// ============================================================================
+// FUNCIONES AUXILIARES PARA WEAK MATCH
+// ============================================================================
+
+/// Filtra tokens distintivos, excluyendo estructurales y códigos numéricos
+fn filter_distinctive_tokens(rules: &MatchingRules, tokens: Vec<String>) -> Vec<String> {
+    tokens
+        .into_iter()
+        .filter(|t| {
+            !rules.structural.contains(t) && !CODE_TOKEN_PATTERN.is_match(t) && !is_digits(t)
+        })
+        .collect()
+}
+
+/// Coincidencia fuzzy con distancia 1: solo tolera typos menores (GARCIA -> GARCYA).
+/// Distancia 2 causaba falsos positivos con nombres similares (MARIA <-> MAYRA).
+fn has_fuzzy_match(token: &str, reference_tokens: &[String]) -> bool {
+    reference_tokens.iter().any(|r| levenshtein(token, r) <= 1)
+}
+
+struct TokenCoverage {
+    is_fully_covered: bool,
+    is_specific: bool,
+    coverage: f64,
+}
+
+/// Cobertura del topic por la query
+fn token_coverage(query_tokens: &[String], topic_tokens: &[String]) -> TokenCoverage {
+    let matched = topic_tokens
+        .iter()
+        .filter(|t| query_tokens.contains(t) || has_fuzzy_match(t, query_tokens))
+        .count();
+    let total = topic_tokens.len();
+
+    TokenCoverage {
+        is_fully_covered: matched >= total,
+        is_specific: total >= 1,
+        coverage: if total > 0 {
+            matched as f64 / total as f64
+        } else {
+            0.0
+        },
+    }
+}
+
+/// Aplica la penalización por tokens faltantes según el contexto
+fn missing_token_penalty(
+    rules: &MatchingRules,
+    missing_tokens: &[String],
+    total_query_tokens: usize,
+    allow_extra_info: bool,
+    has_person_title: bool,
+    is_topic_covered: bool,
+    is_relaxed_mode: bool,
+) -> Option<AppliedPenalty> {
+    if missing_tokens.is_empty() {
+        return None;
+    }
+    let penalties = rules.penalties();
+
+    // Caso 1: ningún token distintivo coincide
+    if missing_tokens.len() == total_query_tokens {
+        return Some(AppliedPenalty {
+            metadata: Some(CoverageMetadata {
+                coverage: 0.0,
+                min_coverage: 1.0,
+            }),
+            ..AppliedPenalty::new(
+                "WEAK_MATCH",
+                penalties.weak_match,
+                format!(
+                    "Ningún token distintivo coincide: {}",
+                    missing_tokens.join(", ")
+                ),
+            )
+        });
+    }
+
+    // Caso 2: penalización leve por info extra (topic cubierto)
+    if allow_extra_info {
+        let mut total_points = 0;
+        let mut details = Vec::new();
+        for token in missing_tokens {
+            // En modo relajado: ruido (TIME, ZONE, KIDS) -2, info importante (nombres) -15
+            let penalty = if !is_relaxed_mode {
+                penalties.missing_token_extra_info
+            } else if rules.irrelevant_tokens.contains(&token.to_lowercase()) {
+                -2
+            } else {
+                -15
+            };
+            total_points += penalty;
+            details.push(format!("{}({})", token, penalty));
+        }
+        return Some(AppliedPenalty::new(
+            "PARTIAL_MATCH_MISSING_TOKENS",
+            total_points,
+            format!("Faltan tokens (Info Extra): {}", details.join(", ")),
+        ));
+    }
+
+    // Caso 3: penalización estándar por tokens faltantes
+    let mismatch_reason = if has_person_title {
+        "TitleDetected"
+    } else if !is_topic_covered {
+        "NoCoverage"
+    } else {
+        "NotSpecific"
+    };
+
+    let mut total_points = 0;
+    let mut details = Vec::new();
+    for token in missing_tokens {
+        if is_digits(token) {
+            total_points += penalties.missing_numeric_token;
+            details.push(format!("{} (Num)", token));
+        } else {
+            total_points += penalties.missing_token;
+            details.push(token.clone());
+        }
+    }
+
+    Some(AppliedPenalty::new(
+        "PARTIAL_MATCH_MISSING_TOKENS",
+        total_points,
+        format!(
+            "Faltan tokens (Mismatch - {}): {}",
+            mismatch_reason,
+            details.join(", ")
+        ),
+    ))
+}
+
+/// Penaliza si faltan tokens distintivos, usando fuzzy matching.
+/// "Topic Saturation": si el topic ya está cubierto, los tokens extra penalizan menos.
+pub fn weak_match(ctx: &ScoringContext) -> Option<AppliedPenalty> {
+    let rules = ctx.rules;
+
+    // 1. Tokenizar y filtrar
+    let query_tokens = distinctive_tokens(ctx.normalized_program);
+    let topic_tokens = filter_distinctive_tokens(rules, distinctive_tokens(ctx.normalized_topic));
+    let distinctive_query_tokens = filter_distinctive_tokens(rules, query_tokens.clone());
+
+    if distinctive_query_tokens.is_empty() {
+        return None;
+    }
+
+    // 2. Calcular cobertura y detectar formatos de persona
+    let coverage = token_coverage(&query_tokens, &topic_tokens);
+    let both_are_people =
+        rules.is_person_format(ctx.raw_program) && rules.is_person_format(ctx.raw_topic);
+    let has_person_title = rules
+        .title_pattern
+        .as_ref()
+        .is_some_and(|p| p.is_match(&ctx.raw_program.to_lowercase()));
+
+    // 3. Solo se relaja la cobertura con más de un token distintivo, para evitar falsos
+    // positivos en queries cortas genéricas ("WORKSHOP" vs "Workshop/Training")
+    let is_relaxed_mode = ctx.options.ignore_level_mismatch && distinctive_query_tokens.len() > 1;
+    let min_coverage = if is_relaxed_mode { 0.4 } else { 0.66 };
+
+    let allow_extra_info = (coverage.is_fully_covered && coverage.is_specific && !has_person_title)
+        || (both_are_people && coverage.is_fully_covered)
+        || (is_relaxed_mode && coverage.coverage >= min_coverage);
+
+    if coverage.coverage < min_coverage {
+        return Some(AppliedPenalty {
+            metadata: Some(CoverageMetadata {
+                coverage: coverage.coverage,
+                min_coverage,
+            }),
+            ..AppliedPenalty::new(
+                "WEAK_MATCH",
+                rules.penalties().weak_match,
+                format!(
+                    "Cobertura insuficiente ({}% < {}%)",
+                    (coverage.coverage * 100.0).round(),
+                    (min_coverage * 100.0).round()
+                ),
+            )
+        });
+    }
+
+    // 4. Encontrar tokens faltantes
+    let mut missing_tokens: Vec<String> = distinctive_query_tokens
+        .iter()
+        .filter(|q| !topic_tokens.contains(q) && !has_fuzzy_match(q, &topic_tokens))
+        .cloned()
+        .collect();
+
+    // Si se ignora el nivel, un token de nivel faltante (l7, l12) no penaliza
+    if ctx.options.ignore_level_mismatch {
+        missing_tokens.retain(|t| !LEVEL_TOKEN_PATTERN.is_match(t));
+    }
+
+    // 5. Aplicar penalización
+    missing_token_penalty(
+        rules,
+        &missing_tokens,
+        distinctive_query_tokens.len(),
+        allow_extra_info,
+        has_person_title,
+        coverage.is_fully_covered,
+        is_relaxed_mode,
+    )
+}
+
+/// Conflicto de número de grupo (CH 1 vs CH 3)
+pub fn group_number_conflict(ctx: &ScoringContext) -> Option<AppliedPenalty> {
+    // Buscando duplicados se ignora el cambio de grupo (G1 a G3)
+    if ctx.options.ignore_level_mismatch {
+        return None;
+    }
+
+    let q_nums = extract_non_level_numbers(ctx.raw_program);
+    let t_nums = extract_non_level_numbers(ctx.raw_topic);
+
+    if q_nums.is_empty() || t_nums.is_empty() || q_nums.iter().any(|n| t_nums.contains(n)) {
+        return None;
+    }
+
+    Some(AppliedPenalty::new(
+        "GROUP_NUMBER_CONFLICT",
+        ctx.rules.penalties().group_number_conflict,
+        format!("Grupo {} vs {}", q_nums.join("/"), t_nums.join("/")),
+    ))
+}
+
+/// Conflicto numérico general
+pub fn numeric_conflict(ctx: &ScoringContext) -> Option<AppliedPenalty> {
+    let q_nums = extract_numbers(ctx.raw_program);
+    let t_nums = extract_numbers(ctx.raw_topic);
+
+    if q_nums.is_empty() || t_nums.is_empty() {
+        return None;
+    }
+
+    // Ignorando nivel, los conflictos numéricos pueden derivar de ahí:
+    // se confía en la validación por nombre/topic
+    if ctx.options.ignore_level_mismatch || q_nums.iter().any(|n| t_nums.contains(n)) {
+        return None;
+    }
+
+    Some(AppliedPenalty::new(
+        "NUMERIC_CONFLICT",
+        ctx.rules.penalties().numeric_conflict,
+        "Números no coinciden".to_string(),
+    ))
+}
+
+/// Número huérfano en topic con candidatos hermanos
+pub fn orphan_number_with_siblings(ctx: &ScoringContext) -> Option<AppliedPenalty> {
+    let q_nums = extract_non_level_numbers(ctx.raw_program);
+    let t_nums = extract_non_level_numbers(ctx.raw_topic);
+
+    // Números en el topic que no están en la query
+    let orphan = t_nums.iter().find(|n| !q_nums.contains(n))?;
+
+    // Hermanos: otros candidatos con el mismo topic salvo los números
+    let base_pattern = strip_numbers(ctx.normalized_topic);
+    let has_siblings = ctx.all_candidates.iter().any(|(m, normalized)| {
+        m.meeting_id != ctx.candidate.meeting_id && strip_numbers(normalized) == base_pattern
+    });
+
+    has_siblings.then(|| {
+        AppliedPenalty::new(
+            "ORPHAN_NUMBER_WITH_SIBLINGS",
+            ctx.rules.penalties().orphan_number_with_siblings,
+            format!("Número \"{}\" no solicitado, hay otras versiones", orphan),
+        )
+    })
+}
+
+fn strip_numbers(s: &str) -> String {
+    NUMBER_PATTERN.replace_all(s, "").trim().to_string()
+}
+
+/// Nivel huérfano en topic con candidatos hermanos
+pub fn orphan_level_with_siblings(ctx: &ScoringContext) -> Option<AppliedPenalty> {
+    // Solo si la query no tiene nivel pero el topic sí
+    if !extract_levels(ctx.raw_program).is_empty() {
+        return None;
+    }
+    let orphan_level = extract_levels(ctx.raw_topic).into_iter().next()?;
+
+    let strip_levels = |s: &str| SHORT_LEVEL_PATTERN.replace_all(s, "").trim().to_string();
+    let base_pattern = strip_levels(ctx.normalized_topic);
+    let has_siblings = ctx.all_candidates.iter().any(|(m, _)| {
+        m.meeting_id != ctx.candidate.meeting_id
+            && strip_levels(&m.topic.to_lowercase()) == base_pattern
+    });
+
+    has_siblings.then(|| {
+        AppliedPenalty::new(
+            "ORPHAN_LEVEL_WITH_SIBLINGS",
+            ctx.rules.penalties().orphan_level_with_siblings,
+            format!(
+                "Nivel \"L{}\" no solicitado, hay otros niveles",
+                orphan_level
+            ),
+        )
+    })
+}
+
+/// Conflicto de compañía (Scotiabank vs Hayduk): la query empieza con una compañía
+/// explícita y el topic tiene otra diferente entre paréntesis.
+pub fn company_conflict(ctx: &ScoringContext) -> Option<AppliedPenalty> {
+    let rules = ctx.rules;
+    let is_company_token = |t: &String| {
+        t.chars().count() > 2 && !rules.ignored_company_tokens.contains(t) && !is_digits(t)
+    };
+
+    // 1. Posible compañía en la query: primer token significativo
+    let query_company = split_tokens(ctx.normalized_program)
+        .into_iter()
+        .find(is_company_token)?;
+
+    // 2. Compañías en el topic (dentro de paréntesis), sin tokens ignorados (ONLINE, HIBRIDO...)
+    let topic_companies: Vec<String> = PARENTHESES_PATTERN
+        .captures_iter(ctx.raw_topic)
+        .flat_map(|c| rules.tokenize(&c[1]))
+        .filter(is_company_token)
+        .collect();
+
+    if topic_companies.is_empty() {
+        return None;
+    }
+
+    // 3. Coincidencia exacta o fuzzy cercana con alguna compañía del topic
+    let has_match = topic_companies
+        .iter()
+        .any(|tc| *tc == query_company || levenshtein(tc, &query_company) <= 2);
+    if has_match {
+        return None;
+    }
+
+    // Validar si la "compañía" es en realidad parte del nombre de la persona:
+    // query "ESPINOZA" vs topic "JUAN ESPINOZA (REPSOL)"
+    let topic_name_part = PARENTHESES_PATTERN.replace_all(ctx.raw_topic, "");
+    let is_part_of_name = rules.tokenize(&topic_name_part).iter().any(|t| {
+        *t == query_company || (t.chars().count() > 3 && levenshtein(t, &query_company) <= 1)
+    });
+    if is_part_of_name {
+        return None;
+    }
+
+    Some(AppliedPenalty::new(
+        "COMPANY_CONFLICT",
+        rules.penalties().company_conflict,
+        format!(
+            "Compañía query '{}' vs topic '{}'",
+            query_company.to_uppercase(),
+            topic_companies.join(", ").to_uppercase()
+        ),
+    ))
+}
@@ -0,0 +1,146 @@
+//! Exportación y comparación de resultados de matching fuera de la app
+//! (CLI `minerva-cli`, ver `src/cli.rs`).
+//!
+//! Los resultados se escriben como JSON (`MatchResult` completos, reutilizables como
+//! archivo esperado) o CSV. La comparación contra un archivo esperado reporta cada fila
+//! cuyo status o meeting asignado cambió.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::{MatchResult, MatchStatus, ZoomMeetingCandidate, ZoomUserCandidate};
+use crate::schedule::ScheduleKey;
+
+/// Export de Zoom: lista de meetings, o un objeto con meetings y (opcionalmente) usuarios
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum ZoomExport {
+    Meetings(Vec<ZoomMeetingCandidate>),
+    Full {
+        meetings: Vec<ZoomMeetingCandidate>,
+        #[serde(default)]
+        users: Vec<ZoomUserCandidate>,
+    },
+}
+
+impl ZoomExport {
+    pub fn into_parts(self) -> (Vec<ZoomMeetingCandidate>, Vec<ZoomUserCandidate>) {
+        match self {
+            ZoomExport::Meetings(meetings) => (meetings, Vec::new()),
+            ZoomExport::Full { meetings, users } => (meetings, users),
+        }
+    }
+}
+
+const CSV_HEADER: &[&str] = &[
+    "date",
+    "start_time",
+    "end_time",
+    "instructor",
+    "program",
+    "status",
+    "meeting_id",
+    "score",
+    "reason",
+];
+
+/// Resultados como CSV (una fila por horario, con el encabezado de `CSV_HEADER`)
+pub fn to_csv(results: &[MatchResult]) -> String {
+    let mut out = csv_line(CSV_HEADER.iter().map(|h| h.to_string()));
+    for r in results {
+        let s = &r.schedule;
+        out.push_str(&csv_line([
+            s.date.to_string(),
+            s.start_time.to_string(),
+            s.end_time.to_string(),
+            s.instructor.clone(),
+            s.program.clone(),
+            r.outcome.status.as_str().to_string(),
+            r.outcome.meeting_id.clone().unwrap_or_default(),
+            r.outcome.score.map(|s| s.to_string()).unwrap_or_default(),
+            r.outcome.reason.clone(),
+        ]));
+    }
+    out
+}
+
+fn csv_line(fields: impl IntoIterator<Item = String>) -> String {
+    let mut line = fields
+        .into_iter()
+        .map(|f| {
+            if f.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", f.replace('"', "\"\""))
+            } else {
+                f
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push('\n');
+    line
+}
+
+// =============================================================================
+// COMPARACIÓN
+// =============================================================================
+
+/// Diferencia de una fila esperada respecto al resultado actual
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Regression {
+    pub key: ScheduleKey,
+    pub expected_status: MatchStatus,
+    pub expected_meeting_id: Option<String>,
+    /// None si la fila ya no aparece en los resultados
+    pub actual_status: Option<MatchStatus>,
+    pub actual_meeting_id: Option<String>,
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let meeting = |id: &Option<String>| id.clone().unwrap_or_else(|| "-".to_string());
+        match self.actual_status {
+            None => write!(f, "{}: missing from results", self.key),
+            Some(actual) => write!(
+                f,
+                "{}: expected {} ({}), got {} ({})",
+                self.key,
+                self.expected_status.as_str(),
+                meeting(&self.expected_meeting_id),
+                actual.as_str(),
+                meeting(&self.actual_meeting_id),
+            ),
+        }
+    }
+}
+
+/// Compara los resultados con los esperados fila por fila (por clave de horario;
+/// las claves repetidas se emparejan en orden). Solo cuentan status y meeting asignado:
+/// los cambios de score o de mensaje no son regresiones.
+pub fn compare(expected: &[MatchResult], actual: &[MatchResult]) -> Vec<Regression> {
+    let mut by_key: HashMap<ScheduleKey, Vec<&MatchResult>> = HashMap::new();
+    for r in actual.iter().rev() {
+        by_key.entry(r.schedule.key()).or_default().push(r);
+    }
+
+    expected
+        .iter()
+        .filter_map(|exp| {
+            let key = exp.schedule.key();
+            let act = by_key.get_mut(&key).and_then(|rows| rows.pop());
+            let unchanged = act.is_some_and(|act| {
+                act.outcome.status == exp.outcome.status
+                    && act.outcome.meeting_id == exp.outcome.meeting_id
+            });
+            (!unchanged).then(|| Regression {
+                key,
+                expected_status: exp.outcome.status,
+                expected_meeting_id: exp.outcome.meeting_id.clone(),
+                actual_status: act.map(|a| a.outcome.status),
+                actual_meeting_id: act.and_then(|a| a.outcome.meeting_id.clone()),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,282 @@
+//! Scorer: calcula el score de cada candidato aplicando todas las penalizaciones
+//! y determina la decisión final de matching (equivalente a `scorer.ts`).
+
+use serde::{Deserialize, Serialize};
+
+use super::config::MatchingRules;
+use super::penalties::{AppliedPenalty, ScoringContext, ALL_PENALTIES};
+use super::{MatchOptions, ZoomMeetingCandidate};
+
+/// Resultado del scoring para un candidato
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoringResult<'a> {
+    pub candidate: &'a ZoomMeetingCandidate,
+    pub normalized_topic: &'a str,
+    pub base_score: i32,
+    pub final_score: i32,
+    pub penalties: Vec<AppliedPenalty>,
+    /// score <= 0
+    pub is_disqualified: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Decision {
+    Assigned,
+    Ambiguous,
+    NotFound,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Confidence {
+    High,
+    Medium,
+    Low,
+    None,
+}
+
+/// Resultado de evaluar todos los candidatos
+#[derive(Debug, Clone)]
+pub struct MatchEvaluation<'a> {
+    pub best_match: Option<ScoringResult<'a>>,
+    pub all_results: Vec<ScoringResult<'a>>,
+    pub decision: Decision,
+    pub confidence: Confidence,
+    /// Mensaje corto para la columna Reason
+    pub reason: String,
+    /// Mensaje detallado para el hover card
+    pub detailed_reason: Option<String>,
+    pub ambiguous_candidates: Option<Vec<&'a ZoomMeetingCandidate>>,
+}
+
+/// Convierte un nombre de penalización técnica en un mensaje corto para el usuario
+fn short_reason(penalty: &AppliedPenalty) -> &'static str {
+    match penalty.name.as_str() {
+        "LEVEL_CONFLICT" => "Level mismatch",
+        "CRITICAL_TOKEN_MISMATCH" => "Program type mismatch",
+        "COMPANY_CONFLICT" => "Company mismatch",
+        "GROUP_NUMBER_CONFLICT" => "Group number mismatch",
+        "NUMERIC_CONFLICT" => "Number mismatch",
+        "PROGRAM_VS_PERSON" => "Program vs person mismatch",
+        "STRUCTURAL_TOKEN_MISSING" => "Missing program type",
+        "WEAK_MATCH" => "Weak match",
+        "PARTIAL_MATCH_MISSING_TOKENS" => "Missing tokens",
+        "ORPHAN_NUMBER_WITH_SIBLINGS" => "Unspecified group number",
+        "ORPHAN_LEVEL_WITH_SIBLINGS" => "Unspecified level",
+        "LEVEL_MISMATCH_IGNORED" => "Level mismatch (Ignored)",
+        _ => "Match conflict",
+    }
+}
+
+/// Mensaje detallado con todas las penalizaciones para el hover card
+fn detailed_reason(penalties: &[AppliedPenalty]) -> String {
+    penalties
+        .iter()
+        .map(|p| {
+            format!(
+                "{}: {}",
+                p.name,
+                p.reason.as_deref().unwrap_or("Unknown conflict")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Calcula el score de un candidato aplicando todas las penalizaciones
+pub fn score_candidate<'a>(ctx: &ScoringContext<'_, 'a>) -> ScoringResult<'a> {
+    let base_score = ctx.rules.config.scoring.base_score;
+    let penalties: Vec<AppliedPenalty> =
+        ALL_PENALTIES.iter().filter_map(|rule| rule(ctx)).collect();
+    let score = base_score + penalties.iter().map(|p| p.points).sum::<i32>();
+
+    ScoringResult {
+        candidate: ctx.candidate,
+        normalized_topic: ctx.normalized_topic,
+        base_score,
+        final_score: score.max(0),
+        penalties,
+        is_disqualified: score <= 0,
+    }
+}
+
+/// Evalúa todos los candidatos (con su topic normalizado) y determina la mejor decisión
+pub fn evaluate_match<'a>(
+    rules: &MatchingRules,
+    raw_program: &str,
+    candidates: &[(&'a ZoomMeetingCandidate, &'a str)],
+    options: MatchOptions,
+) -> MatchEvaluation<'a> {
+    if candidates.is_empty() {
+        return MatchEvaluation {
+            best_match: None,
+            all_results: Vec::new(),
+            decision: Decision::NotFound,
+            confidence: Confidence::None,
+            reason: "No hay candidatos disponibles".to_string(),
+            detailed_reason: None,
+            ambiguous_candidates: None,
+        };
+    }
+    let thresholds = rules.thresholds();
+    let normalized_program = rules.normalize(raw_program);
+
+    // 1. Calcular score para cada candidato y ordenar por score descendente (orden estable)
+    let mut results: Vec<ScoringResult<'a>> = candidates
+        .iter()
+        .map(|(candidate, normalized_topic)| {
+            score_candidate(&ScoringContext {
+                rules,
+                raw_program,
+                raw_topic: &candidate.topic,
+                normalized_program: &normalized_program,
+                normalized_topic,
+                candidate,
+                all_candidates: candidates,
+                options,
+            })
+        })
+        .collect();
+    results.sort_by_key(|r| std::cmp::Reverse(r.final_score));
+
+    // 2. Candidatos no descalificados y sobre el mínimo
+    let valid: Vec<&ScoringResult<'a>> = results
+        .iter()
+        .filter(|r| !r.is_disqualified && r.final_score >= thresholds.minimum)
+        .collect();
+
+    if valid.is_empty() {
+        // El mejor candidato rechazado da la razón
+        let best_rejected = results[0].clone();
+        let main_penalty = best_rejected.penalties.first();
+        let reason = main_penalty
+            .map(short_reason)
+            .unwrap_or("No valid matches")
+            .to_string();
+
+        // Rechazo "duro" (no ambiguo): compañía o tipo de programa distintos,
+        // o WEAK_MATCH con cobertura 0%. Con cobertura > 0% es ambiguo.
+        let is_hard_reject = best_rejected
+            .penalties
+            .iter()
+            .any(|p| p.name == "COMPANY_CONFLICT" || p.name == "CRITICAL_TOKEN_MISMATCH");
+        let is_zero_coverage_weak_match = best_rejected
+            .penalties
+            .iter()
+            .find(|p| p.name == "WEAK_MATCH")
+            .and_then(|p| p.metadata.as_ref())
+            .is_some_and(|m| m.coverage == 0.0);
+
+        if is_hard_reject || is_zero_coverage_weak_match {
+            return MatchEvaluation {
+                best_match: None,
+                detailed_reason: Some(match main_penalty {
+                    Some(_) => detailed_reason(&best_rejected.penalties),
+                    None => "Match rejected due to critical conflict.".to_string(),
+                }),
+                all_results: results,
+                decision: Decision::NotFound,
+                confidence: Confidence::None,
+                reason,
+                ambiguous_candidates: None,
+            };
+        }
+
+        return MatchEvaluation {
+            detailed_reason: Some(match main_penalty {
+                Some(_) => detailed_reason(&best_rejected.penalties),
+                None => "Candidates found but rejected. Review and select manually if appropriate."
+                    .to_string(),
+            }),
+            best_match: Some(best_rejected),
+            ambiguous_candidates: Some(results.iter().take(5).map(|r| r.candidate).collect()),
+            all_results: results,
+            decision: Decision::Ambiguous,
+            confidence: Confidence::Low,
+            reason,
+        };
+    }
+
+    let best = valid[0].clone();
+
+    // 3. Ambigüedad por score similar
+    if let Some(second) = valid.get(1) {
+        if best.final_score - second.final_score < thresholds.ambiguity_diff {
+            return MatchEvaluation {
+                best_match: Some(best),
+                ambiguous_candidates: Some(valid.iter().map(|r| r.candidate).collect()),
+                all_results: results,
+                decision: Decision::Ambiguous,
+                confidence: Confidence::Low,
+                reason: "Multiple matches found".to_string(),
+                detailed_reason: Some(
+                    "Multiple matches found. Please review the list and manually select the best match."
+                        .to_string(),
+                ),
+            };
+        }
+    }
+
+    // 4. Penalizaciones de ambigüedad (números/niveles huérfanos)
+    let ambiguity_penalty = best.penalties.iter().find(|p| {
+        p.name == "ORPHAN_NUMBER_WITH_SIBLINGS" || p.name == "ORPHAN_LEVEL_WITH_SIBLINGS"
+    });
+    if let Some(penalty) = ambiguity_penalty {
+        if best.final_score < thresholds.high_confidence {
+            return MatchEvaluation {
+                reason: short_reason(penalty).to_string(),
+                detailed_reason: Some(detailed_reason(&best.penalties)),
+                ambiguous_candidates: Some(valid.iter().take(5).map(|r| r.candidate).collect()),
+                best_match: Some(best),
+                all_results: results,
+                decision: Decision::Ambiguous,
+                confidence: Confidence::Low,
+            };
+        }
+    }
+
+    // 5. Nivel de confianza
+    let confidence = if best.final_score >= thresholds.high_confidence {
+        Confidence::High
+    } else if best.final_score >= thresholds.medium_confidence {
+        Confidence::Medium
+    } else {
+        Confidence::Low
+    };
+
+    // Confianza baja: ambiguo (pero con bestMatch)
+    if confidence == Confidence::Low {
+        return MatchEvaluation {
+            detailed_reason: Some(if best.penalties.is_empty() {
+                format!(
+                    "Low confidence score ({}) - requires verification",
+                    best.final_score
+                )
+            } else {
+                detailed_reason(&best.penalties)
+            }),
+            ambiguous_candidates: Some(valid.iter().take(5).map(|r| r.candidate).collect()),
+            best_match: Some(best),
+            all_results: results,
+            decision: Decision::Ambiguous,
+            confidence: Confidence::Low,
+            reason: "Low confidence match".to_string(),
+        };
+    }
+
+    MatchEvaluation {
+        reason: if confidence == Confidence::High {
+            "-".to_string()
+        } else {
+            format!("Medium confidence (score: {})", best.final_score)
+        },
+        detailed_reason: (!best.penalties.is_empty()).then(|| detailed_reason(&best.penalties)),
+        best_match: Some(best),
+        all_results: results,
+        decision: Decision::Assigned,
+        confidence,
+        ambiguous_candidates: None,
+    }
+}
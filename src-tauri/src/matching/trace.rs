@@ -0,0 +1,75 @@
+//! Traza explicable del matching de un horario.
+//!
+//! Reemplaza al `logger.group` de `logger.ts` (solo visible en la consola de desarrollo):
+//! se devuelve junto al resultado para que quien resuelve un `ambiguous` vea por qué
+//! perdió cada candidato.
+
+use serde::{Deserialize, Serialize};
+
+use super::index::Retrieval;
+use super::penalties::AppliedPenalty;
+use super::scorer::{Confidence, Decision, MatchEvaluation};
+
+/// Traza de un horario: query normalizada, candidatos evaluados y decisión final
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchTrace {
+    pub normalized_query: String,
+    /// Candidatos en el orden del scorer (score descendente)
+    pub candidates: Vec<CandidateTrace>,
+    pub decision: Decision,
+    /// Banda de confianza de la decisión
+    pub confidence: Confidence,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CandidateTrace {
+    pub meeting_id: String,
+    pub topic: String,
+    pub normalized_topic: String,
+    /// Estrategia del índice que encontró el candidato
+    pub retrieval: Retrieval,
+    pub base_score: i32,
+    pub final_score: i32,
+    pub disqualified: bool,
+    pub penalties: Vec<AppliedPenalty>,
+}
+
+impl MatchTrace {
+    /// Traza sin candidatos (ninguna estrategia encontró resultados)
+    pub fn empty(normalized_query: &str) -> Self {
+        Self {
+            normalized_query: normalized_query.to_string(),
+            candidates: Vec::new(),
+            decision: Decision::NotFound,
+            confidence: Confidence::None,
+        }
+    }
+
+    pub fn from_evaluation(
+        normalized_query: &str,
+        retrieval: Retrieval,
+        evaluation: &MatchEvaluation<'_>,
+    ) -> Self {
+        Self {
+            normalized_query: normalized_query.to_string(),
+            candidates: evaluation
+                .all_results
+                .iter()
+                .map(|r| CandidateTrace {
+                    meeting_id: r.candidate.meeting_id.clone(),
+                    topic: r.candidate.topic.clone(),
+                    normalized_topic: r.normalized_topic.to_string(),
+                    retrieval,
+                    base_score: r.base_score,
+                    final_score: r.final_score,
+                    disqualified: r.is_disqualified,
+                    penalties: r.penalties.clone(),
+                })
+                .collect(),
+            decision: evaluation.decision,
+            confidence: evaluation.confidence,
+        }
+    }
+}
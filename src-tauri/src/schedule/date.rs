@@ -0,0 +1,82 @@
+//! Fecha de una clase. Se serializa como "DD/MM/YYYY", el formato que usa la UI.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serial de Excel correspondiente al 1 de enero de 1970
+const EXCEL_UNIX_EPOCH: f64 = 25569.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScheduleDate(NaiveDate);
+
+impl ScheduleDate {
+    /// Desde serial de fecha Excel (días desde 1900, parte entera)
+    pub fn from_excel_serial(serial: f64) -> Option<Self> {
+        let days = (serial - EXCEL_UNIX_EPOCH).floor() as i64;
+        NaiveDate::from_ymd_opt(1970, 1, 1)?
+            .checked_add_signed(Duration::try_days(days)?)
+            .map(Self)
+    }
+
+    pub fn naive(&self) -> NaiveDate {
+        self.0
+    }
+}
+
+impl fmt::Display for ScheduleDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}/{:02}/{:04}",
+            self.0.day(),
+            self.0.month(),
+            self.0.year()
+        )
+    }
+}
+
+impl FromStr for ScheduleDate {
+    type Err = String;
+
+    /// Acepta los formatos que maneja `toISODateTime`: DD/MM/YYYY, YYYY/MM/DD y YYYY-MM-DD.
+    /// Un entero suelto (ej: "46044") se interpreta como serial de Excel.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let text = value.trim();
+        let invalid = || format!("expected a date in DD/MM/YYYY format, got \"{}\"", value);
+
+        if !text.is_empty() && text.chars().all(|c| c.is_ascii_digit()) {
+            let serial: f64 = text.parse().map_err(|_| invalid())?;
+            return Self::from_excel_serial(serial).ok_or_else(invalid);
+        }
+
+        let parts: Vec<&str> = text.split(['/', '-']).collect();
+        let [a, b, c] = parts.as_slice() else {
+            return Err(invalid());
+        };
+        let (year, month, day) = if a.len() == 4 { (a, b, c) } else { (c, b, a) };
+
+        let year: i32 = year.parse().map_err(|_| invalid())?;
+        let month: u32 = month.parse().map_err(|_| invalid())?;
+        let day: u32 = day.parse().map_err(|_| invalid())?;
+
+        NaiveDate::from_ymd_opt(year, month, day)
+            .map(Self)
+            .ok_or_else(invalid)
+    }
+}
+
+impl Serialize for ScheduleDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScheduleDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,220 @@
+//! Modelo de dominio de horarios compartido por todos los comandos
+//! (parseo, exportación, detección de cruces, publicación).
+
+pub mod date;
+pub mod overlap;
+pub mod time;
+
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::error::AppResult;
+
+pub use date::ScheduleDate;
+pub use time::ScheduleTime;
+
+// =============================================================================
+// TIPOS DE DATOS
+// =============================================================================
+
+/// Fila de horario validada. Mismos campos que la interfaz `Schedule` de TypeScript;
+/// fechas y horas viajan por IPC como "DD/MM/YYYY" y "HH:MM".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Schedule {
+    pub date: ScheduleDate,
+    pub shift: String,
+    pub branch: String,
+    pub start_time: ScheduleTime,
+    pub end_time: ScheduleTime,
+    pub code: String,
+    pub instructor: String,
+    pub program: String,
+    pub minutes: String,
+    pub units: u32,
+}
+
+/// Horario con los campos de incidencia del día (equivalente a `DailyIncidence`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyIncidence {
+    #[serde(flatten)]
+    pub schedule: Schedule,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub substitute: Option<String>,
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub department: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feedback: Option<String>,
+}
+
+impl Schedule {
+    /// Clave de fila (equivalente a `getScheduleKey`)
+    pub fn key(&self) -> ScheduleKey {
+        ScheduleKey {
+            date: self.date,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            instructor: self.instructor.clone(),
+            program: self.program.clone(),
+        }
+    }
+
+    /// Clave para detección de duplicados (equivalente a `getUniqueScheduleKey`)
+    pub fn unique_key(&self) -> UniqueScheduleKey {
+        UniqueScheduleKey {
+            date: self.date,
+            shift: self.shift.clone(),
+            branch: self.branch.clone(),
+            start_time: self.start_time,
+            end_time: self.end_time,
+            instructor: self.instructor.clone(),
+            code: self.code.clone(),
+            program: self.program.clone(),
+        }
+    }
+}
+
+// =============================================================================
+// CLAVES DE IDENTIDAD
+// =============================================================================
+
+/// Identifica una fila: fecha, horario, instructor y programa.
+/// Se serializa con el mismo formato que `getScheduleKey` ("a|b|c|d|e").
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScheduleKey {
+    pub date: ScheduleDate,
+    pub start_time: ScheduleTime,
+    pub end_time: ScheduleTime,
+    pub instructor: String,
+    pub program: String,
+}
+
+impl fmt::Display for ScheduleKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}",
+            self.date, self.start_time, self.end_time, self.instructor, self.program
+        )
+    }
+}
+
+impl Serialize for ScheduleKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Clave completa de la lógica v1 (Python) para detectar filas duplicadas.
+/// Se serializa con el mismo formato que `getUniqueScheduleKey`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UniqueScheduleKey {
+    pub date: ScheduleDate,
+    pub shift: String,
+    pub branch: String,
+    pub start_time: ScheduleTime,
+    pub end_time: ScheduleTime,
+    pub instructor: String,
+    pub code: String,
+    pub program: String,
+}
+
+impl fmt::Display for UniqueScheduleKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}|{}|{}",
+            self.date,
+            self.shift,
+            self.branch,
+            self.start_time,
+            self.end_time,
+            self.instructor,
+            self.code,
+            self.program
+        )
+    }
+}
+
+impl Serialize for UniqueScheduleKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+// =============================================================================
+// VALIDACIÓN
+// =============================================================================
+
+/// Error de validación de un campo concreto, pensado para mostrarse en la UI
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldError {
+    /// Índice de la fila en el arreglo recibido
+    pub row: usize,
+    /// Campo con el valor inválido (ej: "start_time"); vacío si es la fila completa
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.field.is_empty() {
+            write!(f, "row {}: {}", self.row + 1, self.message)
+        } else {
+            write!(f, "row {}, {}: {}", self.row + 1, self.field, self.message)
+        }
+    }
+}
+
+/// Valida cada fila JSON por separado; retorna un resultado por fila, en el mismo orden
+pub fn parse_json_rows<T: DeserializeOwned>(rows: Vec<Value>) -> Vec<Result<T, FieldError>> {
+    rows.into_iter()
+        .enumerate()
+        .map(|(row, value)| {
+            serde_path_to_error::deserialize::<_, T>(value).map_err(|err| {
+                let field = err.path().to_string();
+                FieldError {
+                    row,
+                    field: if field == "." { String::new() } else { field },
+                    message: err.into_inner().to_string(),
+                }
+            })
+        })
+        .collect()
+}
+
+/// Valida filas JSON recibidas desde el frontend (`Schedule`, `DailyIncidence`...).
+/// Retorna todos los errores encontrados (no solo el primero).
+pub fn from_json_rows<T: DeserializeOwned>(rows: Vec<Value>) -> Result<Vec<T>, Vec<FieldError>> {
+    let mut parsed = Vec::with_capacity(rows.len());
+    let mut errors = Vec::new();
+
+    for result in parse_json_rows(rows) {
+        match result {
+            Ok(item) => parsed.push(item),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(parsed)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Valida filas de horario y las retorna normalizadas, o la lista de errores por campo.
+#[tauri::command]
+pub fn validate_schedules(rows: Vec<Value>) -> AppResult<Vec<Schedule>> {
+    Ok(from_json_rows(rows)?)
+}
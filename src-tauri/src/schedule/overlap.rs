@@ -0,0 +1,174 @@
+//! Detección de cruces de horario y clases duplicadas.
+//! Portado de `detectOverlaps` en `overlap-utils.ts`, con barrido de intervalos
+//! ordenados en lugar de comparar todos los pares de cada grupo.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use serde::Serialize;
+
+use super::{Schedule, ScheduleDate, ScheduleKey, ScheduleTime};
+use crate::error::AppResult;
+
+/// Cruces de una fila concreta, con los índices de las filas con las que choca
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RowOverlap {
+    /// Índice de la fila en el arreglo recibido
+    pub row: usize,
+    pub key: ScheduleKey,
+    /// Filas del mismo instructor y fecha cuyo horario se superpone
+    pub time_conflict_with: Vec<usize>,
+    /// Filas de la misma clase (fecha, horario, programa) con otro instructor
+    pub duplicate_class_with: Vec<usize>,
+}
+
+/// Resultado equivalente a `OverlapResult`, más el detalle por fila
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverlapReport {
+    /// Keys de filas con conflictos de tiempo (mismo instructor, horarios superpuestos)
+    pub time_conflicts: BTreeSet<ScheduleKey>,
+    /// Keys de filas con clases duplicadas (misma clase, diferentes instructores)
+    pub duplicate_classes: BTreeSet<ScheduleKey>,
+    /// Unión de ambos
+    pub all_overlaps: BTreeSet<ScheduleKey>,
+    pub overlap_count: usize,
+    /// Solo filas con al menos un cruce, ordenadas por índice
+    pub rows: Vec<RowOverlap>,
+}
+
+/// Detecta cruces de horario y clases duplicadas
+pub fn detect_overlaps(schedules: &[Schedule]) -> OverlapReport {
+    // Agrupar por fecha + instructor para detección de conflictos de tiempo
+    let mut by_date_instructor: HashMap<(ScheduleDate, &str), Vec<usize>> = HashMap::new();
+    // Agrupar por fecha + start_time + end_time + programa para detección de duplicados
+    let mut by_class: HashMap<(ScheduleDate, ScheduleTime, ScheduleTime, &str), Vec<usize>> =
+        HashMap::new();
+
+    for (index, s) in schedules.iter().enumerate() {
+        by_date_instructor
+            .entry((s.date, s.instructor.as_str()))
+            .or_default()
+            .push(index);
+        by_class
+            .entry((s.date, s.start_time, s.end_time, s.program.as_str()))
+            .or_default()
+            .push(index);
+    }
+
+    let mut time_pairs: Vec<(usize, usize)> = Vec::new();
+    for group in by_date_instructor.values().filter(|g| g.len() > 1) {
+        collect_time_conflicts(schedules, group, &mut time_pairs);
+    }
+
+    let mut time_conflict_with: HashMap<usize, BTreeSet<usize>> = HashMap::new();
+    for (a, b) in time_pairs {
+        time_conflict_with.entry(a).or_default().insert(b);
+        time_conflict_with.entry(b).or_default().insert(a);
+    }
+
+    // Clases duplicadas: se marca todo el grupo si hay instructores diferentes
+    let mut duplicate_class_with: HashMap<usize, BTreeSet<usize>> = HashMap::new();
+    for group in by_class.values().filter(|g| g.len() > 1) {
+        let instructors: HashSet<&str> = group
+            .iter()
+            .map(|&i| schedules[i].instructor.as_str())
+            .collect();
+        if instructors.len() < 2 {
+            continue;
+        }
+        for &i in group {
+            let others = group
+                .iter()
+                .copied()
+                .filter(|&j| schedules[j].instructor != schedules[i].instructor);
+            duplicate_class_with.entry(i).or_default().extend(others);
+        }
+    }
+
+    let mut report = OverlapReport::default();
+    let flagged: BTreeSet<usize> = time_conflict_with
+        .keys()
+        .chain(duplicate_class_with.keys())
+        .copied()
+        .collect();
+
+    for row in flagged {
+        let key = schedules[row].key();
+        let time_with = time_conflict_with.remove(&row).unwrap_or_default();
+        let duplicate_with = duplicate_class_with.remove(&row).unwrap_or_default();
+
+        if !time_with.is_empty() {
+            report.time_conflicts.insert(key.clone());
+        }
+        if !duplicate_with.is_empty() {
+            report.duplicate_classes.insert(key.clone());
+        }
+        report.all_overlaps.insert(key.clone());
+        report.rows.push(RowOverlap {
+            row,
+            key,
+            time_conflict_with: time_with.into_iter().collect(),
+            duplicate_class_with: duplicate_with.into_iter().collect(),
+        });
+    }
+
+    report.overlap_count = report.all_overlaps.len();
+    report
+}
+
+/// Verifica si dos rangos de tiempo (en minutos) se superponen
+fn times_overlap(start1: u32, end1: u32, start2: u32, end2: u32) -> bool {
+    start1 < end2 && start2 < end1
+}
+
+/// Pares de filas superpuestas dentro de un grupo fecha + instructor
+fn collect_time_conflicts(
+    schedules: &[Schedule],
+    group: &[usize],
+    pairs: &mut Vec<(usize, usize)>,
+) {
+    let span = |i: usize| {
+        (
+            schedules[i].start_time.minutes_since_midnight(),
+            schedules[i].end_time.minutes_since_midnight(),
+        )
+    };
+
+    // Intervalos bien formados (fin > inicio): barrido ordenado por inicio.
+    // Los activos son los que aún no terminan cuando empieza el actual.
+    let (mut valid, degenerate): (Vec<usize>, Vec<usize>) = group.iter().partition(|&&i| {
+        let (start, end) = span(i);
+        end > start
+    });
+    valid.sort_by_key(|&i| span(i));
+
+    let mut active: Vec<usize> = Vec::new();
+    for &current in &valid {
+        let (start, _) = span(current);
+        active.retain(|&other| span(other).1 > start);
+        pairs.extend(active.iter().map(|&other| (other, current)));
+        active.push(current);
+    }
+
+    // Filas con fin <= inicio (datos incompletos): comparación directa
+    for (n, &current) in degenerate.iter().enumerate() {
+        let (s1, e1) = span(current);
+        let others = valid.iter().chain(&degenerate[n + 1..]);
+        for &other in others {
+            let (s2, e2) = span(other);
+            if times_overlap(s1, e1, s2, e2) {
+                pairs.push((current, other));
+            }
+        }
+    }
+}
+
+/// Detecta cruces en un conjunto de horarios (por ejemplo, un mes completo)
+#[tauri::command]
+pub async fn detect_schedule_overlaps(
+    schedules: Vec<serde_json::Value>,
+) -> AppResult<OverlapReport> {
+    let schedules = super::from_json_rows(schedules)?;
+    Ok(detect_overlaps(&schedules))
+}
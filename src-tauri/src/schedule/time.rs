@@ -0,0 +1,132 @@
+//! Hora de inicio/fin de una clase (portado de `time-utils.ts`).
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+static AMPM_TIME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)(\d{1,2}):(\d{2})\s*(AM|PM|a\.m\.|p\.m\.)").unwrap());
+static TIME_24H: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(\d{1,2}):(\d{2})").unwrap());
+static STRICT_TIME: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(\d{1,2}):(\d{2})(?::\d{2})?(?:\s*(AM|PM|a\.m\.|p\.m\.))?$").unwrap()
+});
+
+/// Hora del día con precisión de minutos. Se serializa como "HH:MM" (24h).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ScheduleTime {
+    hours: u8,
+    minutes: u8,
+}
+
+impl ScheduleTime {
+    /// Crea una hora validando rangos (0-23, 0-59)
+    pub fn new(hours: u32, minutes: u32) -> Option<Self> {
+        (hours < 24 && minutes < 60).then_some(Self {
+            hours: hours as u8,
+            minutes: minutes as u8,
+        })
+    }
+
+    /// Desde serial de Excel (fracción del día: 0.5 = 12:00, 0.75 = 18:00)
+    pub fn from_excel_serial(value: f64) -> Self {
+        let total_minutes = (value * 24.0 * 60.0).round() as i64;
+        Self {
+            hours: (total_minutes / 60).rem_euclid(24) as u8,
+            minutes: total_minutes.rem_euclid(60) as u8,
+        }
+    }
+
+    /// Parseo tolerante igual a `parseTimeValue`: busca "2:30 PM", "8:00 a.m."
+    /// o "14:30" dentro del texto y usa 00:00 si no encuentra ninguno.
+    pub fn parse_lenient(value: &str) -> Self {
+        let text = value.trim();
+        if let Some(caps) = AMPM_TIME.captures(text) {
+            let (hours, minutes) = apply_period(&caps[1], &caps[2], &caps[3]);
+            return Self::new(hours, minutes).unwrap_or_default();
+        }
+        if let Some(caps) = TIME_24H.captures(text) {
+            let hours = caps[1].parse().unwrap_or(0);
+            let minutes = caps[2].parse().unwrap_or(0);
+            return Self::new(hours, minutes).unwrap_or_default();
+        }
+        // Fallback por defecto
+        Self::default()
+    }
+
+    pub fn hours(&self) -> u32 {
+        self.hours as u32
+    }
+
+    pub fn minutes(&self) -> u32 {
+        self.minutes as u32
+    }
+
+    /// Formato 12h (hh:mm AM/PM), como `formatTimeTo12Hour`
+    pub fn format_12h(self) -> String {
+        let period = if self.hours >= 12 { "PM" } else { "AM" };
+        let hours12 = match self.hours % 12 {
+            0 => 12, // Convierte 0 a 12
+            h => h,
+        };
+        format!("{:02}:{:02} {}", hours12, self.minutes, period)
+    }
+
+    /// Minutos desde medianoche
+    pub fn minutes_since_midnight(&self) -> u32 {
+        self.hours() * 60 + self.minutes()
+    }
+}
+
+/// Convierte hora/minuto en formato 12h a 24h según el periodo (AM/PM)
+fn apply_period(hours: &str, minutes: &str, period: &str) -> (u32, u32) {
+    let mut hours: u32 = hours.parse().unwrap_or(0);
+    let minutes: u32 = minutes.parse().unwrap_or(0);
+    let period = period.to_uppercase().replace('.', "");
+
+    if period == "PM" && hours != 12 {
+        hours += 12;
+    } else if period == "AM" && hours == 12 {
+        hours = 0;
+    }
+    (hours, minutes)
+}
+
+impl fmt::Display for ScheduleTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hours, self.minutes)
+    }
+}
+
+impl FromStr for ScheduleTime {
+    type Err = String;
+
+    /// Parseo estricto: "HH:MM", "HH:MM:SS" u "hh:mm AM/PM"
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let caps = STRICT_TIME
+            .captures(value.trim())
+            .ok_or_else(|| format!("expected a time in HH:MM format, got \"{}\"", value))?;
+
+        let (hours, minutes) = match caps.get(3) {
+            Some(period) => apply_period(&caps[1], &caps[2], period.as_str()),
+            None => (caps[1].parse().unwrap_or(0), caps[2].parse().unwrap_or(0)),
+        };
+
+        Self::new(hours, minutes).ok_or_else(|| format!("time out of range: \"{}\"", value))
+    }
+}
+
+impl Serialize for ScheduleTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScheduleTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(de::Error::custom)
+    }
+}
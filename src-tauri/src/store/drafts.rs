@@ -0,0 +1,317 @@
+//! Borrador de horarios del dashboard (antes `minerva_schedules_draft.json`).
+//!
+//! Cada autoguardado reemplaza el borrador y, en la misma transacción, agrega un snapshot
+//! completo; se conservan los últimos [`DRAFT_SNAPSHOTS`]. Los snapshots de eventos
+//! (carga de un Excel, guardado manual, restauración) se cuentan aparte, hasta
+//! [`DRAFT_PINNED_SNAPSHOTS`], así muchos autoguardados seguidos no los desplazan.
+//! Si el borrador no se puede leer (fila dañada o de un formato anterior), al cargar se
+//! recupera el snapshot válido más reciente y se vuelve a escribir como borrador.
+
+use rusqlite::{params, OptionalExtension, Transaction};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::AppHandle;
+
+use super::{blocking, from_json, history, load_rows, replace_rows, to_json, Store};
+use crate::error::AppResult;
+use crate::schedule::{self, Schedule};
+use crate::time::now_ms;
+
+const TABLE: &str = "schedules_draft";
+const SNAPSHOTS_TABLE: &str = "draft_snapshots";
+
+/// Snapshots de autoguardado que se conservan (la línea de tiempo de [`super::history`])
+pub const DRAFT_SNAPSHOTS: usize = 50;
+
+/// Snapshots de eventos que se conservan, aparte de los de autoguardado
+pub const DRAFT_PINNED_SNAPSHOTS: usize = 50;
+
+/// Por qué se tomó un snapshot
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SnapshotReason {
+    #[default]
+    Autosave,
+    /// El borrador antes de cargar un Excel
+    Upload,
+    /// Guardado manual desde la barra de herramientas
+    Manual,
+    /// El borrador antes de restaurar un snapshot
+    Restore,
+    /// Borrador de un JSON anterior que no reemplazó al actual (ver [`super::import`])
+    Import,
+}
+
+impl SnapshotReason {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Autosave => "autosave",
+            Self::Upload => "upload",
+            Self::Manual => "manual",
+            Self::Restore => "restore",
+            Self::Import => "import",
+        }
+    }
+
+    /// Un valor desconocido (de una versión posterior) cuenta como autoguardado
+    pub(crate) fn parse(value: &str) -> Self {
+        match value {
+            "upload" => Self::Upload,
+            "manual" => Self::Manual,
+            "restore" => Self::Restore,
+            "import" => Self::Import,
+            _ => Self::Autosave,
+        }
+    }
+
+    /// Las cargas y restauraciones conservan el estado anterior; el guardado manual, el nuevo
+    fn keeps_previous(self) -> bool {
+        matches!(self, Self::Upload | Self::Restore)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DraftSource {
+    /// Borrador actual
+    Draft,
+    /// El borrador no se pudo leer y se recuperó de un snapshot
+    Snapshot,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadedDraft {
+    pub schedules: Vec<Schedule>,
+    pub source: DraftSource,
+    /// Fecha (ms) del snapshot recuperado
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_at: Option<i64>,
+    /// Snapshots más recientes descartados por inválidos
+    pub skipped: usize,
+}
+
+/// Horarios del borrador; vacío si no hay borrador
+pub fn load(store: &Store) -> AppResult<Vec<Schedule>> {
+    store.read(|conn| load_rows(conn, TABLE))
+}
+
+/// Autoguardado: reemplaza el borrador (una lista vacía lo elimina, sin snapshot)
+pub fn save(store: &Store, schedules: &[Schedule], now: i64) -> AppResult<()> {
+    save_as(store, schedules, SnapshotReason::Autosave, now)
+}
+
+/// Reemplaza el borrador tras un evento.
+/// 1. Carga o restauración: el borrador anterior queda como snapshot del evento
+/// 2. Se reemplaza el borrador
+/// 3. Snapshot del borrador nuevo (del evento si es un guardado manual)
+pub fn save_as(
+    store: &Store,
+    schedules: &[Schedule],
+    reason: SnapshotReason,
+    now: i64,
+) -> AppResult<()> {
+    store.write(|tx| {
+        // 1. Estado anterior; un borrador ilegible no impide guardar
+        if reason.keeps_previous() {
+            match load_rows::<Schedule>(tx, TABLE) {
+                Ok(previous) if !previous.is_empty() => snapshot(tx, &previous, reason, now)?,
+                Ok(_) => {}
+                Err(e) => eprintln!("Could not keep the previous schedule draft: {}", e),
+            }
+        }
+
+        // 2. Borrador
+        replace_rows(tx, TABLE, schedules)?;
+
+        // 3. Estado nuevo
+        if !schedules.is_empty() {
+            let reason = match reason {
+                SnapshotReason::Manual => reason,
+                _ => SnapshotReason::Autosave,
+            };
+            snapshot(tx, schedules, reason, now)?;
+        }
+        Ok(())
+    })
+}
+
+/// Agrega un snapshot sin tocar el borrador (una lista vacía no agrega nada)
+pub fn keep(
+    store: &Store,
+    schedules: &[Schedule],
+    reason: SnapshotReason,
+    now: i64,
+) -> AppResult<()> {
+    if schedules.is_empty() {
+        return Ok(());
+    }
+    store.write(|tx| snapshot(tx, schedules, reason, now))
+}
+
+/// Elimina el borrador (los snapshots se conservan); retorna false si no había
+pub fn clear(store: &Store) -> AppResult<bool> {
+    store.write(|tx| Ok(tx.execute(&format!("DELETE FROM {}", TABLE), [])? > 0))
+}
+
+/// Carga el borrador; si no se puede leer, recupera el snapshot válido más reciente.
+/// 1. Borrador actual
+/// 2. Snapshots del más reciente al más antiguo, validados como en el guardado.
+///    Los importados se omiten: nunca fueron el borrador
+/// 3. El primero válido reemplaza al borrador dañado
+/// 4. Sin snapshots válidos se retorna el error original
+pub fn load_or_recover(store: &Store) -> AppResult<LoadedDraft> {
+    // 1. Borrador
+    let error = match load(store) {
+        Ok(schedules) => {
+            return Ok(LoadedDraft {
+                schedules,
+                source: DraftSource::Draft,
+                snapshot_at: None,
+                skipped: 0,
+            })
+        }
+        Err(e) => e,
+    };
+    eprintln!("Schedule draft unreadable, trying snapshots: {}", error);
+
+    // 2. Snapshots
+    let snapshots: Vec<(i64, String)> = store.read(|conn| {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT created_at, data FROM {} WHERE reason <> 'import' ORDER BY id DESC",
+            SNAPSHOTS_TABLE
+        ))?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    })?;
+    for (skipped, (created_at, data)) in snapshots.into_iter().enumerate() {
+        match parse_snapshot(&data) {
+            Ok(schedules) => {
+                // 3. Reparar el borrador
+                store.write(|tx| replace_rows(tx, TABLE, &schedules))?;
+                return Ok(LoadedDraft {
+                    schedules,
+                    source: DraftSource::Snapshot,
+                    snapshot_at: Some(created_at),
+                    skipped,
+                });
+            }
+            Err(e) => eprintln!("Skipping invalid draft snapshot from {}: {}", created_at, e),
+        }
+    }
+
+    // 4. Nada recuperable
+    Err(error)
+}
+
+/// Agrega un snapshot (si difiere del último) con los cambios respecto al último,
+/// y elimina los que exceden el límite de su tipo. Si es igual al último y este es un
+/// autoguardado, el último pasa a ser del evento.
+fn snapshot(
+    tx: &Transaction,
+    schedules: &[Schedule],
+    reason: SnapshotReason,
+    now: i64,
+) -> AppResult<()> {
+    let data = to_json(&schedules)?;
+    let latest: Option<(i64, String, String)> = tx
+        .query_row(
+            &format!(
+                "SELECT id, data, reason FROM {} ORDER BY id DESC LIMIT 1",
+                SNAPSHOTS_TABLE
+            ),
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()?;
+    if let Some((id, latest_data, latest_reason)) = &latest {
+        if *latest_data == data {
+            if reason != SnapshotReason::Autosave
+                && SnapshotReason::parse(latest_reason) == SnapshotReason::Autosave
+            {
+                tx.execute(
+                    &format!("UPDATE {} SET reason = ?1 WHERE id = ?2", SNAPSHOTS_TABLE),
+                    params![reason.as_str(), id],
+                )?;
+            }
+            return Ok(());
+        }
+    }
+
+    // Un snapshot anterior ilegible cuenta como vacío
+    let previous = latest
+        .and_then(|(_, latest, _)| parse_snapshot(&latest).ok())
+        .unwrap_or_default();
+    let changes = history::diff(&previous, schedules).changes();
+    tx.execute(
+        &format!(
+            "INSERT INTO {} (created_at, row_count, data, added, removed, instructors_changed, modified, reason)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            SNAPSHOTS_TABLE
+        ),
+        params![
+            now,
+            schedules.len() as i64,
+            data,
+            changes.added as i64,
+            changes.removed as i64,
+            changes.instructors_changed as i64,
+            changes.modified as i64,
+            reason.as_str()
+        ],
+    )?;
+
+    // Autoguardados y eventos se recortan por separado
+    tx.execute(
+        &format!(
+            "DELETE FROM {0} WHERE reason = 'autosave' AND id NOT IN
+             (SELECT id FROM {0} WHERE reason = 'autosave' ORDER BY id DESC LIMIT ?1)",
+            SNAPSHOTS_TABLE
+        ),
+        [DRAFT_SNAPSHOTS as i64],
+    )?;
+    tx.execute(
+        &format!(
+            "DELETE FROM {0} WHERE reason <> 'autosave' AND id NOT IN
+             (SELECT id FROM {0} WHERE reason <> 'autosave' ORDER BY id DESC LIMIT ?1)",
+            SNAPSHOTS_TABLE
+        ),
+        [DRAFT_PINNED_SNAPSHOTS as i64],
+    )?;
+    Ok(())
+}
+
+/// Un snapshot es válido si sus filas pasan la misma validación que el guardado
+pub(crate) fn parse_snapshot(data: &str) -> AppResult<Vec<Schedule>> {
+    let rows: Vec<Value> = from_json(SNAPSHOTS_TABLE, data)?;
+    Ok(schedule::from_json_rows(rows)?)
+}
+
+// =============================================================================
+// COMANDOS
+// =============================================================================
+
+#[tauri::command]
+pub async fn load_draft(app: AppHandle) -> AppResult<LoadedDraft> {
+    blocking(app, load_or_recover).await
+}
+
+/// Guarda el borrador; las filas se validan antes de escribir nada.
+/// Sin `reason` es un autoguardado.
+#[tauri::command]
+pub async fn save_draft(
+    app: AppHandle,
+    schedules: Vec<Value>,
+    reason: Option<SnapshotReason>,
+) -> AppResult<()> {
+    blocking(app, move |store| {
+        let schedules: Vec<Schedule> = schedule::from_json_rows(schedules)?;
+        save_as(store, &schedules, reason.unwrap_or_default(), now_ms())
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn clear_draft(app: AppHandle) -> AppResult<bool> {
+    blocking(app, clear).await
+}
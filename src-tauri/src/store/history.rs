@@ -0,0 +1,209 @@
+//! Historial del borrador de horarios.
+//!
+//! Cada snapshot de [`super::drafts`] guarda cuántas filas cambiaron respecto al anterior,
+//! así la línea de tiempo se lista sin leer los snapshots completos. Restaurar un snapshot
+//! lo guarda como borrador y el borrador anterior queda como snapshot de la restauración,
+//! así la restauración también se puede deshacer, incluso después de reiniciar la app.
+
+use std::collections::BTreeMap;
+
+use rusqlite::{params, OptionalExtension};
+use serde::Serialize;
+use tauri::AppHandle;
+
+use super::drafts::{self, SnapshotReason};
+use super::{blocking, Store};
+use crate::error::{AppError, AppResult};
+use crate::schedule::Schedule;
+use crate::time::now_ms;
+
+// =============================================================================
+// TIPOS DE DATOS
+// =============================================================================
+
+/// Cantidad de filas que cambiaron entre dos versiones del borrador
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftChanges {
+    pub added: usize,
+    pub removed: usize,
+    /// Misma clase (fecha, horario y programa) con otro instructor
+    pub instructors_changed: usize,
+    /// Misma clase y mismo instructor con otros datos (sede, código, turno...)
+    pub modified: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RowChange {
+    pub before: Schedule,
+    pub after: Schedule,
+}
+
+/// Diferencias de `before` a `after`
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftDiff {
+    pub added: Vec<Schedule>,
+    pub removed: Vec<Schedule>,
+    pub instructor_changes: Vec<RowChange>,
+    pub modified: Vec<RowChange>,
+}
+
+impl DraftDiff {
+    pub fn changes(&self) -> DraftChanges {
+        DraftChanges {
+            added: self.added.len(),
+            removed: self.removed.len(),
+            instructors_changed: self.instructor_changes.len(),
+            modified: self.modified.len(),
+        }
+    }
+}
+
+/// Entrada de la línea de tiempo
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftSnapshot {
+    pub id: i64,
+    /// Fecha (ms) del snapshot
+    pub created_at: i64,
+    pub row_count: usize,
+    /// Cambios respecto al snapshot anterior
+    pub changes: DraftChanges,
+    pub reason: SnapshotReason,
+}
+
+// =============================================================================
+// DIFERENCIAS
+// =============================================================================
+
+/// Compara dos versiones del borrador.
+/// 1. Las filas idénticas en ambas versiones no cuentan
+/// 2. Una fila nueva con la misma clase (fecha, horario y programa) que una fila
+///    desaparecida es un cambio de instructor (o de otros datos), no un alta y una baja
+/// 3. Lo que queda de `after` se agregó y lo que queda de `before` se eliminó
+pub fn diff(before: &[Schedule], after: &[Schedule]) -> DraftDiff {
+    let slot = |s: &Schedule| (s.date, s.start_time, s.end_time, s.program.clone());
+    let mut slots: BTreeMap<_, Vec<usize>> = BTreeMap::new();
+    for (index, row) in before.iter().enumerate() {
+        slots.entry(slot(row)).or_default().push(index);
+    }
+
+    // 1. Filas sin cambios
+    let mut unmatched = Vec::new();
+    for row in after {
+        let same = slots.get_mut(&slot(row)).and_then(|candidates| {
+            let position = candidates.iter().position(|&i| before[i] == *row)?;
+            Some(candidates.remove(position))
+        });
+        if same.is_none() {
+            unmatched.push(row);
+        }
+    }
+
+    // 2. Misma clase con otros datos
+    let mut result = DraftDiff::default();
+    for row in unmatched {
+        let previous = slots
+            .get_mut(&slot(row))
+            .filter(|candidates| !candidates.is_empty())
+            .map(|candidates| candidates.remove(0));
+        let Some(previous) = previous else {
+            // 3. Altas
+            result.added.push(row.clone());
+            continue;
+        };
+        let change = RowChange {
+            before: before[previous].clone(),
+            after: row.clone(),
+        };
+        if change.before.instructor != change.after.instructor {
+            result.instructor_changes.push(change);
+        } else {
+            result.modified.push(change);
+        }
+    }
+
+    // 3. Bajas, en el orden original
+    let mut removed: Vec<usize> = slots.into_values().flatten().collect();
+    removed.sort_unstable();
+    result.removed = removed.into_iter().map(|i| before[i].clone()).collect();
+    result
+}
+
+// =============================================================================
+// HISTORIAL
+// =============================================================================
+
+/// Snapshots del más reciente al más antiguo
+pub fn list(store: &Store) -> AppResult<Vec<DraftSnapshot>> {
+    store.read(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, created_at, row_count, added, removed, instructors_changed, modified, reason
+             FROM draft_snapshots ORDER BY id DESC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(DraftSnapshot {
+                id: row.get(0)?,
+                created_at: row.get(1)?,
+                row_count: row.get::<_, i64>(2)? as usize,
+                changes: DraftChanges {
+                    added: row.get::<_, i64>(3)? as usize,
+                    removed: row.get::<_, i64>(4)? as usize,
+                    instructors_changed: row.get::<_, i64>(5)? as usize,
+                    modified: row.get::<_, i64>(6)? as usize,
+                },
+                reason: SnapshotReason::parse(&row.get::<_, String>(7)?),
+            })
+        })?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    })
+}
+
+/// Horarios de un snapshot
+pub fn load_snapshot(store: &Store, id: i64) -> AppResult<Vec<Schedule>> {
+    let data: Option<String> = store.read(|conn| {
+        Ok(conn
+            .query_row(
+                "SELECT data FROM draft_snapshots WHERE id = ?1",
+                params![id],
+                |row| row.get(0),
+            )
+            .optional()?)
+    })?;
+    let data =
+        data.ok_or_else(|| AppError::InvalidRequest(format!("Snapshot {} not found", id)))?;
+    drafts::parse_snapshot(&data)
+}
+
+/// Lo que cambiaría en el borrador actual al restaurar el snapshot
+pub fn preview(store: &Store, id: i64) -> AppResult<DraftDiff> {
+    let snapshot = load_snapshot(store, id)?;
+    Ok(diff(&drafts::load(store)?, &snapshot))
+}
+
+/// Reemplaza el borrador por el snapshot; retorna los horarios restaurados
+pub fn restore(store: &Store, id: i64, now: i64) -> AppResult<Vec<Schedule>> {
+    let snapshot = load_snapshot(store, id)?;
+    drafts::save_as(store, &snapshot, SnapshotReason::Restore, now)?;
+    Ok(snapshot)
+}
+
+// =============================================================================
+// COMANDOS
+// =============================================================================
+
+#[tauri::command]
+pub async fn list_draft_history(app: AppHandle) -> AppResult<Vec<DraftSnapshot>> {
+    blocking(app, list).await
+}
+
+#[tauri::command]
+pub async fn preview_draft_snapshot(app: AppHandle, id: i64) -> AppResult<DraftDiff> {
+    blocking(app, move |store| preview(store, id)).await
+}
+
+#[tauri::command]
+pub async fn restore_draft_snapshot(app: AppHandle, id: i64) -> AppResult<Vec<Schedule>> {
+    blocking(app, move |store| restore(store, id, now_ms())).await
+}
@@ -0,0 +1,174 @@
+//! Importación de los JSON de AppLocalData que reemplaza la base de datos.
+//!
+//! Se ejecuta al iniciar, después de las migraciones. Primero los archivos `minerva_*.json`
+//! y luego los legacy (anteriores a ese formato, mismo contenido). Cada archivo se importa
+//! solo si su tabla está vacía (nunca pisa datos de la base; los ajustes legacy solo
+//! completan las claves que falten, y un borrador que no se importa queda en el historial
+//! de [`super::history`]) y luego se renombra a `<archivo>.imported`, así la
+//! importación ocurre una sola vez. Un archivo ilegible se deja en su lugar y se reporta.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use super::drafts::SnapshotReason;
+use super::mirror::ExcelMirror;
+use super::settings::StoredSettings;
+use super::{drafts, incidences, mirror, settings, Store};
+use crate::error::{AppError, AppResult};
+use crate::schedule::{self, DailyIncidence, Schedule};
+use crate::time::now_ms;
+
+pub const SETTINGS_FILE: &str = "minerva_app_settings.json";
+pub const DRAFT_FILE: &str = "minerva_schedules_draft.json";
+pub const INCIDENCES_FILE: &str = "minerva_incidences_log.json";
+pub const MIRROR_FILE: &str = "minerva_excel_data_mirror.json";
+
+// Archivos legacy (`LEGACY_FILES` del frontend)
+pub const LEGACY_SETTINGS_FILE: &str = "minerva-settings.json";
+pub const LEGACY_DRAFT_FILE: &str = "schedule_autosave.json";
+pub const LEGACY_INCIDENCES_FILE: &str = "incidences.json";
+pub const LEGACY_MIRROR_FILE: &str = "linked_source_cache.json";
+
+/// Sufijo de los archivos ya importados
+const IMPORTED_SUFFIX: &str = ".imported";
+
+/// Resultado de importar un archivo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportOutcome {
+    /// El archivo no existe
+    Missing,
+    Imported,
+    /// Se agregaron a la base solo los datos que faltaban (ajustes legacy)
+    Merged,
+    /// La tabla ya tenía datos: el archivo se archivó sin importar (un borrador distinto
+    /// del actual queda como snapshot)
+    Skipped,
+}
+
+/// Importa los cuatro JSON de `dir`. Los errores de un archivo no detienen al resto.
+pub fn import_json_files(
+    store: &Store,
+    dir: &Path,
+) -> Vec<(&'static str, AppResult<ImportOutcome>)> {
+    vec![
+        (
+            SETTINGS_FILE,
+            import_file(dir, SETTINGS_FILE, |settings: StoredSettings| {
+                if settings::load(store)?.is_some() {
+                    return Ok(ImportOutcome::Skipped);
+                }
+                settings::save(store, &settings)?;
+                Ok(ImportOutcome::Imported)
+            }),
+        ),
+        (DRAFT_FILE, import_draft(store, dir, DRAFT_FILE)),
+        (
+            INCIDENCES_FILE,
+            import_incidences(store, dir, INCIDENCES_FILE),
+        ),
+        (MIRROR_FILE, import_mirror(store, dir, MIRROR_FILE)),
+    ]
+}
+
+/// Importa los archivos legacy de `dir`; se ejecuta después de [`import_json_files`]
+pub fn import_legacy_files(
+    store: &Store,
+    dir: &Path,
+) -> Vec<(&'static str, AppResult<ImportOutcome>)> {
+    vec![
+        (
+            LEGACY_SETTINGS_FILE,
+            import_file(dir, LEGACY_SETTINGS_FILE, |legacy: StoredSettings| {
+                let Some(mut current) = settings::load(store)? else {
+                    settings::save(store, &legacy)?;
+                    return Ok(ImportOutcome::Imported);
+                };
+                // Los ajustes actuales ganan; los legacy solo completan claves
+                let before = current.len();
+                for (key, value) in legacy {
+                    current.entry(key).or_insert(value);
+                }
+                if current.len() == before {
+                    return Ok(ImportOutcome::Skipped);
+                }
+                settings::save(store, &current)?;
+                Ok(ImportOutcome::Merged)
+            }),
+        ),
+        (
+            LEGACY_DRAFT_FILE,
+            import_draft(store, dir, LEGACY_DRAFT_FILE),
+        ),
+        (
+            LEGACY_INCIDENCES_FILE,
+            import_incidences(store, dir, LEGACY_INCIDENCES_FILE),
+        ),
+        (
+            LEGACY_MIRROR_FILE,
+            import_mirror(store, dir, LEGACY_MIRROR_FILE),
+        ),
+    ]
+}
+
+fn import_draft(store: &Store, dir: &Path, name: &str) -> AppResult<ImportOutcome> {
+    import_file(dir, name, |rows: Vec<Value>| {
+        let rows: Vec<Schedule> = schedule::from_json_rows(rows)?;
+        let current = drafts::load(store)?;
+        if !current.is_empty() {
+            if current != rows {
+                drafts::keep(store, &rows, SnapshotReason::Import, now_ms())?;
+            }
+            return Ok(ImportOutcome::Skipped);
+        }
+        drafts::save(store, &rows, now_ms())?;
+        Ok(ImportOutcome::Imported)
+    })
+}
+
+fn import_incidences(store: &Store, dir: &Path, name: &str) -> AppResult<ImportOutcome> {
+    import_file(dir, name, |rows: Vec<Value>| {
+        let rows: Vec<DailyIncidence> = schedule::from_json_rows(rows)?;
+        if !incidences::load(store)?.is_empty() {
+            return Ok(ImportOutcome::Skipped);
+        }
+        incidences::save(store, &rows)?;
+        Ok(ImportOutcome::Imported)
+    })
+}
+
+fn import_mirror(store: &Store, dir: &Path, name: &str) -> AppResult<ImportOutcome> {
+    import_file(dir, name, |excel: ExcelMirror| {
+        if mirror::load(store)?.is_some() {
+            return Ok(ImportOutcome::Skipped);
+        }
+        mirror::save(store, &excel)?;
+        Ok(ImportOutcome::Imported)
+    })
+}
+
+/// Lee `name`, lo pasa a `import` y lo archiva
+fn import_file<T: DeserializeOwned>(
+    dir: &Path,
+    name: &str,
+    import: impl FnOnce(T) -> AppResult<ImportOutcome>,
+) -> AppResult<ImportOutcome> {
+    let path = dir.join(name);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(ImportOutcome::Missing),
+        Err(e) => return Err(AppError::io(e, &path)),
+    };
+    let value: T = serde_json::from_str(&content).map_err(|e| AppError::Io {
+        path: path.clone(),
+        message: format!("Invalid JSON: {}", e),
+    })?;
+
+    let outcome = import(value)?;
+    let archived = dir.join(format!("{}{}", name, IMPORTED_SUFFIX));
+    fs::rename(&path, &archived).map_err(|e| AppError::io(e, &path))?;
+    Ok(outcome)
+}
@@ -0,0 +1,36 @@
+//! Registro de incidencias del día (antes `minerva_incidences_log.json`).
+
+use serde_json::Value;
+use tauri::AppHandle;
+
+use super::{blocking, load_rows, replace_rows, Store};
+use crate::error::AppResult;
+use crate::schedule::{self, DailyIncidence};
+
+const TABLE: &str = "incidences";
+
+pub fn load(store: &Store) -> AppResult<Vec<DailyIncidence>> {
+    store.read(|conn| load_rows(conn, TABLE))
+}
+
+pub fn save(store: &Store, incidences: &[DailyIncidence]) -> AppResult<()> {
+    store.write(|tx| replace_rows(tx, TABLE, incidences))
+}
+
+// =============================================================================
+// COMANDOS
+// =============================================================================
+
+#[tauri::command]
+pub async fn load_incidences(app: AppHandle) -> AppResult<Vec<DailyIncidence>> {
+    blocking(app, load).await
+}
+
+#[tauri::command]
+pub async fn save_incidences(app: AppHandle, incidences: Vec<Value>) -> AppResult<()> {
+    blocking(app, move |store| {
+        let incidences: Vec<DailyIncidence> = schedule::from_json_rows(incidences)?;
+        save(store, &incidences)
+    })
+    .await
+}
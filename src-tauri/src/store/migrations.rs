@@ -0,0 +1,73 @@
+//! Migraciones del schema de `minerva.db`.
+//!
+//! La versión aplicada se guarda en `PRAGMA user_version`. Las migraciones solo se
+//! agregan al final de [`MIGRATIONS`]; nunca se editan las ya publicadas.
+
+use rusqlite::Connection;
+
+use crate::error::AppResult;
+
+/// Migración `i` lleva el schema de la versión `i` a la `i + 1`
+pub const MIGRATIONS: &[&str] = &[
+    // 1. Estado inicial (equivalente a los JSON de AppLocalData)
+    "CREATE TABLE settings (
+        id INTEGER PRIMARY KEY CHECK (id = 1),
+        data TEXT NOT NULL,
+        updated_at INTEGER NOT NULL
+    );
+    CREATE TABLE schedules_draft (
+        position INTEGER PRIMARY KEY,
+        data TEXT NOT NULL
+    );
+    CREATE TABLE incidences (
+        position INTEGER PRIMARY KEY,
+        data TEXT NOT NULL
+    );
+    CREATE TABLE excel_mirror (
+        id INTEGER PRIMARY KEY CHECK (id = 1),
+        file_id TEXT NOT NULL,
+        file_name TEXT NOT NULL,
+        timestamp INTEGER NOT NULL,
+        sheets TEXT NOT NULL
+    );
+    CREATE TABLE excel_mirror_rows (
+        sheet TEXT NOT NULL,
+        position INTEGER NOT NULL,
+        data TEXT NOT NULL,
+        PRIMARY KEY (sheet, position)
+    );",
+    // 2. Snapshots del borrador de horarios (ver `drafts`)
+    "CREATE TABLE draft_snapshots (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        created_at INTEGER NOT NULL,
+        row_count INTEGER NOT NULL,
+        data TEXT NOT NULL
+    );",
+    // 3. Cambios de cada snapshot respecto al anterior (ver `history`)
+    "ALTER TABLE draft_snapshots ADD COLUMN added INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE draft_snapshots ADD COLUMN removed INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE draft_snapshots ADD COLUMN instructors_changed INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE draft_snapshots ADD COLUMN modified INTEGER NOT NULL DEFAULT 0;",
+    // 4. Orden de las hojas del espejo, incluidas las vacías (ver `mirror`).
+    //    Un espejo anterior conserva el orden alfabético hasta la próxima sincronización.
+    "ALTER TABLE excel_mirror ADD COLUMN sheet_order TEXT NOT NULL DEFAULT '[]';",
+    // 5. Motivo de cada snapshot del borrador (ver `drafts::SnapshotReason`)
+    "ALTER TABLE draft_snapshots ADD COLUMN reason TEXT NOT NULL DEFAULT 'autosave';",
+];
+
+/// Versión del schema tras aplicar todas las migraciones
+pub const SCHEMA_VERSION: usize = MIGRATIONS.len();
+
+/// Aplica las migraciones pendientes, cada una en su propia transacción.
+/// Retorna la versión anterior.
+pub fn migrate(conn: &mut Connection) -> AppResult<usize> {
+    let current: usize = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    for (index, sql) in MIGRATIONS.iter().enumerate().skip(current) {
+        let tx = conn.transaction()?;
+        tx.execute_batch(sql)?;
+        tx.pragma_update(None, "user_version", index + 1)?;
+        tx.commit()?;
+        eprintln!("minerva.db migrated to schema version {}", index + 1);
+    }
+    Ok(current)
+}
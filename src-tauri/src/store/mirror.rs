@@ -0,0 +1,154 @@
+//! Espejo local del Excel vinculado (antes `minerva_excel_data_mirror.json`).
+//!
+//! Las filas se guardan por hoja y posición: una sincronización que cambia pocas filas
+//! solo reescribe esas filas. El orden de las hojas de `data` (el del libro) se guarda
+//! aparte, así las hojas vacías también vuelven al leer el espejo.
+
+use indexmap::IndexMap;
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::AppHandle;
+
+use super::{blocking, from_json, to_json, Store};
+use crate::error::AppResult;
+
+/// Mismo contenido que el payload de `useLinkedSourceSync`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExcelMirror {
+    pub file_id: String,
+    pub file_name: String,
+    /// Fecha de la sincronización (ms desde epoch)
+    pub timestamp: i64,
+    /// Metadatos de las hojas (tal como los retorna Microsoft Graph)
+    #[serde(default)]
+    pub sheets: Vec<Value>,
+    /// Filas por nombre de hoja, en el orden del libro
+    #[serde(default)]
+    pub data: IndexMap<String, Vec<Value>>,
+}
+
+pub fn load(store: &Store) -> AppResult<Option<ExcelMirror>> {
+    store.read(|conn| {
+        let header = conn
+            .query_row(
+                "SELECT file_id, file_name, timestamp, sheets, sheet_order
+                 FROM excel_mirror WHERE id = 1",
+                [],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, i64>(2)?,
+                        row.get::<_, String>(3)?,
+                        row.get::<_, String>(4)?,
+                    ))
+                },
+            )
+            .optional()?;
+        let Some((file_id, file_name, timestamp, sheets, sheet_order)) = header else {
+            return Ok(None);
+        };
+
+        // Hojas en el orden guardado (vacías incluidas); las filas de hojas que no están
+        // en el orden (espejos anteriores a la migración 4) se agregan al final
+        let order: Vec<String> = from_json("excel_mirror", &sheet_order)?;
+        let mut data: IndexMap<String, Vec<Value>> =
+            order.into_iter().map(|sheet| (sheet, Vec::new())).collect();
+        let mut stmt =
+            conn.prepare("SELECT sheet, data FROM excel_mirror_rows ORDER BY sheet, position")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+        for row in rows {
+            let (sheet, row) = row?;
+            data.entry(sheet)
+                .or_default()
+                .push(from_json("excel_mirror_rows", &row)?);
+        }
+
+        Ok(Some(ExcelMirror {
+            file_id,
+            file_name,
+            timestamp,
+            sheets: from_json("excel_mirror", &sheets)?,
+            data,
+        }))
+    })
+}
+
+/// Reemplaza el espejo; solo se escriben las filas que cambiaron
+pub fn save(store: &Store, mirror: &ExcelMirror) -> AppResult<()> {
+    store.write(|tx| {
+        tx.execute(
+            "INSERT INTO excel_mirror (id, file_id, file_name, timestamp, sheets, sheet_order)
+             VALUES (1, ?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(id) DO UPDATE SET file_id = excluded.file_id,
+                file_name = excluded.file_name, timestamp = excluded.timestamp,
+                sheets = excluded.sheets, sheet_order = excluded.sheet_order",
+            params![
+                mirror.file_id,
+                mirror.file_name,
+                mirror.timestamp,
+                to_json(&mirror.sheets)?,
+                to_json(&mirror.data.keys().collect::<Vec<_>>())?
+            ],
+        )?;
+
+        let mut upsert = tx.prepare(
+            "INSERT INTO excel_mirror_rows (sheet, position, data) VALUES (?1, ?2, ?3)
+             ON CONFLICT(sheet, position) DO UPDATE SET data = excluded.data
+             WHERE data <> excluded.data",
+        )?;
+        let mut trim =
+            tx.prepare("DELETE FROM excel_mirror_rows WHERE sheet = ?1 AND position >= ?2")?;
+        for (sheet, rows) in &mirror.data {
+            for (position, row) in rows.iter().enumerate() {
+                upsert.execute(params![sheet, position as i64, to_json(row)?])?;
+            }
+            trim.execute(params![sheet, rows.len() as i64])?;
+        }
+
+        // Hojas que ya no están en el espejo
+        let mut sheets = tx.prepare("SELECT DISTINCT sheet FROM excel_mirror_rows")?;
+        let stale: Vec<String> = sheets
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?
+            .into_iter()
+            .filter(|sheet| !mirror.data.contains_key(sheet))
+            .collect();
+        for sheet in stale {
+            tx.execute("DELETE FROM excel_mirror_rows WHERE sheet = ?1", [sheet])?;
+        }
+        Ok(())
+    })
+}
+
+/// Elimina el espejo; retorna false si no había
+pub fn clear(store: &Store) -> AppResult<bool> {
+    store.write(|tx| {
+        let cleared = tx.execute("DELETE FROM excel_mirror", [])? > 0;
+        tx.execute("DELETE FROM excel_mirror_rows", [])?;
+        Ok(cleared)
+    })
+}
+
+// =============================================================================
+// COMANDOS
+// =============================================================================
+
+#[tauri::command]
+pub async fn load_excel_mirror(app: AppHandle) -> AppResult<Option<ExcelMirror>> {
+    blocking(app, load).await
+}
+
+#[tauri::command]
+pub async fn save_excel_mirror(app: AppHandle, mirror: ExcelMirror) -> AppResult<()> {
+    blocking(app, move |store| save(store, &mirror)).await
+}
+
+#[tauri::command]
+pub async fn clear_excel_mirror(app: AppHandle) -> AppResult<bool> {
+    blocking(app, clear).await
+}
@@ -0,0 +1,198 @@
+//! Base de datos local (`AppLocalData/minerva.db`) con el estado de la app.
+//!
+//! Reemplaza los JSON sueltos que el frontend reescribía completos con `tauri-plugin-fs`
+//! (ajustes, borrador de horarios, incidencias y espejo del Excel vinculado). Cada
+//! guardado es una transacción: un cierre inesperado a mitad de escritura deja los datos
+//! anteriores intactos. Las listas se guardan una fila por registro y solo se reescriben
+//! las filas que cambiaron.
+//!
+//! El schema se migra al abrir (en el `setup` de `run()`), ver [`migrations`]. Una base
+//! dañada se aparta y se crea una nueva ([`Store::open_or_recover`]).
+
+pub mod drafts;
+pub mod history;
+pub mod import;
+pub mod incidences;
+pub mod migrations;
+pub mod mirror;
+pub mod settings;
+
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use rusqlite::{params, Connection, Transaction};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State};
+
+use crate::error::{AppError, AppResult};
+
+/// Nombre de la base de datos en AppLocalData
+pub const DB_FILE: &str = "minerva.db";
+
+/// Conexión compartida por los comandos (una escritura a la vez)
+pub struct Store {
+    conn: Mutex<Connection>,
+}
+
+/// Base de datos dañada al iniciar, reemplazada por una nueva
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoveredDatabase {
+    /// Path de la base apartada (`minerva.db.corrupt-<ts>`)
+    pub backup: PathBuf,
+    /// Error al abrirla o migrarla
+    pub error: String,
+}
+
+/// Estado de la base al iniciar, para avisar al usuario desde el frontend
+#[derive(Debug, Default)]
+pub struct StorageStatus {
+    pub recovered: Option<RecoveredDatabase>,
+}
+
+impl Store {
+    /// Abre (o crea) la base de datos y aplica las migraciones pendientes
+    pub fn open(path: &Path) -> AppResult<Self> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| AppError::io(e, dir))?;
+        }
+        let conn = Connection::open(path)?;
+        // WAL + synchronous FULL: cada commit queda en disco antes de retornar
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.pragma_update(None, "synchronous", "FULL")?;
+        Self::init(conn)
+    }
+
+    /// Igual que [`Store::open`], pero si SQLite reporta la base como dañada (o como un
+    /// archivo que no es una base de datos) no impide iniciar:
+    /// 1. Aparta la base y sus archivos `-wal`/`-shm` como `<db>.corrupt-<now>`
+    /// 2. Crea una base nueva y retorna dónde quedó la anterior
+    ///
+    /// Cualquier otro error (bloqueada por otra instancia, IO, una migración que falla) se
+    /// retorna sin tocar la base.
+    pub fn open_or_recover(path: &Path, now: i64) -> AppResult<(Self, Option<RecoveredDatabase>)> {
+        let error = match Self::open(path) {
+            Ok(store) => return Ok((store, None)),
+            Err(e @ AppError::DatabaseCorrupt(_)) if path.exists() => e,
+            Err(e) => return Err(e),
+        };
+
+        // 1. Apartar la base (la conexión fallida ya se cerró)
+        let backup = with_suffix(path, &format!(".corrupt-{}", now));
+        for suffix in ["-wal", "-shm"] {
+            let file = with_suffix(path, suffix);
+            if file.exists() {
+                let target = with_suffix(&backup, suffix);
+                fs::rename(&file, &target).map_err(|e| AppError::io(e, &file))?;
+            }
+        }
+        fs::rename(path, &backup).map_err(|e| AppError::io(e, path))?;
+
+        // 2. Base nueva
+        let store = Self::open(path)?;
+        Ok((
+            store,
+            Some(RecoveredDatabase {
+                backup,
+                error: error.to_string(),
+            }),
+        ))
+    }
+
+    /// Base de datos en memoria (tests y herramientas)
+    pub fn open_in_memory() -> AppResult<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(mut conn: Connection) -> AppResult<Self> {
+        migrations::migrate(&mut conn)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Ejecuta `f` con la conexión
+    pub fn read<T>(&self, f: impl FnOnce(&Connection) -> AppResult<T>) -> AppResult<T> {
+        f(&self.conn.lock().unwrap())
+    }
+
+    /// Ejecuta `f` dentro de una transacción; si falla no se aplica ningún cambio
+    pub fn write<T>(&self, f: impl FnOnce(&Transaction) -> AppResult<T>) -> AppResult<T> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        let result = f(&tx)?;
+        tx.commit()?;
+        Ok(result)
+    }
+}
+
+/// Ejecuta `f` con el `Store` en un hilo de bloqueo: los comandos no ocupan el hilo
+/// principal ni los workers async mientras SQLite lee, valida o sincroniza a disco
+pub(crate) async fn blocking<T: Send + 'static>(
+    app: AppHandle,
+    f: impl FnOnce(&Store) -> AppResult<T> + Send + 'static,
+) -> AppResult<T> {
+    tauri::async_runtime::spawn_blocking(move || f(&app.state::<Store>()))
+        .await
+        .map_err(|e| AppError::Internal(e.to_string()))?
+}
+
+/// `path` con `suffix` agregado al nombre (ej: "minerva.db" + "-wal")
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Base apartada al iniciar, si la hubo (el frontend lo muestra como aviso)
+#[tauri::command]
+pub fn get_storage_recovery(status: State<'_, StorageStatus>) -> Option<RecoveredDatabase> {
+    status.recovered.clone()
+}
+
+// =============================================================================
+// LISTAS (una fila por registro)
+// =============================================================================
+
+/// Serializa un registro para guardarlo en una columna `data`
+pub(crate) fn to_json<T: Serialize>(value: &T) -> AppResult<String> {
+    serde_json::to_string(value).map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Lee una columna `data`; un JSON ilegible es un error de la base de datos
+pub(crate) fn from_json<T: DeserializeOwned>(table: &str, data: &str) -> AppResult<T> {
+    serde_json::from_str(data)
+        .map_err(|e| AppError::Database(format!("Invalid row in {}: {}", table, e)))
+}
+
+/// Registros de una tabla `(position, data)`, en orden
+pub(crate) fn load_rows<T: DeserializeOwned>(conn: &Connection, table: &str) -> AppResult<Vec<T>> {
+    let mut stmt = conn.prepare(&format!("SELECT data FROM {} ORDER BY position", table))?;
+    let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+    rows.map(|data| from_json(table, &data?)).collect()
+}
+
+/// Reemplaza los registros de una tabla `(position, data)`.
+/// Solo se escriben las posiciones cuyo contenido cambió; las sobrantes se eliminan.
+pub(crate) fn replace_rows<T: Serialize>(
+    tx: &Transaction,
+    table: &str,
+    rows: &[T],
+) -> AppResult<()> {
+    let mut upsert = tx.prepare(&format!(
+        "INSERT INTO {} (position, data) VALUES (?1, ?2)
+         ON CONFLICT(position) DO UPDATE SET data = excluded.data WHERE data <> excluded.data",
+        table
+    ))?;
+    for (position, row) in rows.iter().enumerate() {
+        upsert.execute(params![position as i64, to_json(row)?])?;
+    }
+    tx.execute(
+        &format!("DELETE FROM {} WHERE position >= ?1", table),
+        [rows.len() as i64],
+    )?;
+    Ok(())
+}
@@ -0,0 +1,245 @@
+//! Ajustes de la app (antes `minerva_app_settings.json`).
+//!
+//! En la base se guardan como objeto JSON con `version`. Al leerlos se aplican las
+//! migraciones pendientes ([`MIGRATIONS`]) y cada clave se valida por separado: una clave
+//! desconocida o un valor inválido vuelve a su valor por defecto, así unos ajustes dañados
+//! nunca impiden iniciar. `update_settings` en cambio rechaza el cambio completo.
+//! Cada cambio se emite como `settings://changed` para que todas las ventanas lo vean.
+
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tauri::{AppHandle, Emitter};
+
+use super::{blocking, from_json, to_json, Store};
+use crate::error::{AppError, AppResult};
+use crate::matching::config::ConfigIssue;
+use crate::time::now_ms;
+
+/// Evento emitido con los ajustes completos tras cada cambio
+pub const CHANGED_EVENT: &str = "settings://changed";
+
+/// Ajustes tal como se guardan en la base (objeto JSON)
+pub type StoredSettings = Map<String, Value>;
+
+// =============================================================================
+// TIPOS DE DATOS
+// =============================================================================
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    Light,
+    Dark,
+    #[default]
+    System,
+}
+
+/// Mismos campos que `AppSettings` de `settings-provider.tsx`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AppSettings {
+    /// Versión del formato ([`SETTINGS_VERSION`]); la asigna Rust
+    pub version: u32,
+    pub actions_respect_filters: bool,
+    pub auto_save: bool,
+    pub theme: Theme,
+    pub open_after_export: bool,
+    pub clear_schedule_on_load: bool,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            version: SETTINGS_VERSION,
+            actions_respect_filters: false,
+            auto_save: true,
+            theme: Theme::System,
+            open_after_export: true,
+            clear_schedule_on_load: false,
+        }
+    }
+}
+
+// =============================================================================
+// MIGRACIONES
+// =============================================================================
+
+/// Migración `i` lleva los ajustes de la versión `i` a la `i + 1`.
+/// Solo se agregan al final; nunca se editan las ya publicadas.
+pub const MIGRATIONS: &[fn(&mut StoredSettings)] = &[
+    // 1. Ajustes sin versión de `settings-provider.tsx`: mismas claves
+    |_| {},
+];
+
+/// Versión actual del formato de ajustes
+pub const SETTINGS_VERSION: u32 = MIGRATIONS.len() as u32;
+
+/// Versión de unos ajustes guardados (0 = sin versión)
+fn stored_version(stored: &StoredSettings) -> u32 {
+    stored
+        .get("version")
+        .and_then(Value::as_u64)
+        .unwrap_or_default() as u32
+}
+
+/// Aplica las migraciones pendientes; una versión futura (downgrade) se deja como está
+fn migrate(stored: &mut StoredSettings) {
+    let from = stored_version(stored) as usize;
+    for migration in MIGRATIONS.iter().skip(from) {
+        migration(stored);
+    }
+    if from < MIGRATIONS.len() {
+        stored.insert("version".to_string(), SETTINGS_VERSION.into());
+    }
+}
+
+// =============================================================================
+// VALIDACIÓN
+// =============================================================================
+
+/// Aplica `changes` sobre `base` clave por clave.
+/// Las claves desconocidas, `version` y los valores con tipo inválido se omiten y se
+/// reportan (el pointer es la clave).
+fn apply(base: &AppSettings, changes: StoredSettings) -> (AppSettings, Vec<ConfigIssue>) {
+    let mut merged = to_map(base);
+    let mut issues = Vec::new();
+
+    for (key, value) in changes {
+        let pointer = format!("/{}", key);
+        if key == "version" {
+            if value.as_u64() != Some(u64::from(base.version)) {
+                issues.push(ConfigIssue::new(pointer, "Version is read-only"));
+            }
+            continue;
+        }
+        if !merged.contains_key(&key) {
+            issues.push(ConfigIssue::new(pointer, "Unknown setting"));
+            continue;
+        }
+        let mut candidate = merged.clone();
+        candidate.insert(key, value);
+        match serde_json::from_value::<AppSettings>(Value::Object(candidate.clone())) {
+            Ok(_) => merged = candidate,
+            Err(e) => issues.push(ConfigIssue::new(pointer, e.to_string())),
+        }
+    }
+
+    let settings = serde_json::from_value(Value::Object(merged)).unwrap_or_else(|_| base.clone());
+    (settings, issues)
+}
+
+// =============================================================================
+// ALMACENAMIENTO
+// =============================================================================
+
+/// Ajustes guardados tal cual; None si nunca se guardaron
+pub fn load(store: &Store) -> AppResult<Option<StoredSettings>> {
+    store.read(|conn| {
+        let data: Option<String> = conn
+            .query_row("SELECT data FROM settings WHERE id = 1", [], |row| {
+                row.get(0)
+            })
+            .optional()?;
+        data.map(|data| from_json("settings", &data)).transpose()
+    })
+}
+
+pub fn save(store: &Store, settings: &StoredSettings) -> AppResult<()> {
+    let data = to_json(settings)?;
+    store.write(|tx| {
+        tx.execute(
+            "INSERT INTO settings (id, data, updated_at) VALUES (1, ?1, ?2)
+             ON CONFLICT(id) DO UPDATE SET data = excluded.data, updated_at = excluded.updated_at",
+            params![data, now_ms()],
+        )?;
+        Ok(())
+    })
+}
+
+/// Elimina los ajustes guardados (vuelven los valores por defecto).
+/// Retorna false si no había ajustes.
+pub fn clear(store: &Store) -> AppResult<bool> {
+    store.write(|tx| Ok(tx.execute("DELETE FROM settings", [])? > 0))
+}
+
+/// Ajustes validados; los valores faltantes o inválidos toman su valor por defecto.
+/// 1. Migrar los ajustes guardados a la versión actual
+/// 2. Validar clave por clave sobre los valores por defecto
+/// 3. Si hubo migración o se descartaron valores, guardar el resultado
+///    (salvo ajustes de una versión futura, que no se pisan)
+pub fn get(store: &Store) -> AppResult<AppSettings> {
+    let Some(stored) = load(store)? else {
+        return Ok(AppSettings::default());
+    };
+
+    // 1. Migrar
+    let mut migrated = stored.clone();
+    migrate(&mut migrated);
+
+    // 2. Validar
+    let (settings, issues) = apply(&AppSettings::default(), migrated);
+    for issue in &issues {
+        eprintln!("Ignoring stored setting: {}", issue);
+    }
+
+    // 3. Normalizar lo guardado
+    let normalized = to_map(&settings);
+    if normalized != stored && stored_version(&stored) <= SETTINGS_VERSION {
+        save(store, &normalized)?;
+    }
+    Ok(settings)
+}
+
+/// Aplica los cambios de `patch` (claves parciales). Si alguna clave es inválida no se
+/// guarda nada. Retorna los ajustes completos.
+pub fn update(store: &Store, patch: StoredSettings) -> AppResult<AppSettings> {
+    let current = get(store)?;
+    let (settings, issues) = apply(&current, patch);
+    if !issues.is_empty() {
+        return Err(AppError::InvalidSettings(issues));
+    }
+    if settings != current {
+        save(store, &to_map(&settings))?;
+    }
+    Ok(settings)
+}
+
+fn to_map(settings: &AppSettings) -> StoredSettings {
+    match serde_json::to_value(settings) {
+        Ok(Value::Object(map)) => map,
+        _ => unreachable!("AppSettings serializes to an object"),
+    }
+}
+
+fn notify(app: &AppHandle, settings: &AppSettings) {
+    if let Err(e) = app.emit(CHANGED_EVENT, settings) {
+        eprintln!("Failed to emit settings change: {}", e);
+    }
+}
+
+// =============================================================================
+// COMANDOS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_settings(app: AppHandle) -> AppResult<AppSettings> {
+    blocking(app, get).await
+}
+
+/// Actualiza solo las claves de `patch` y emite `settings://changed`
+#[tauri::command]
+pub async fn update_settings(app: AppHandle, patch: StoredSettings) -> AppResult<AppSettings> {
+    let settings = blocking(app.clone(), move |store| update(store, patch)).await?;
+    notify(&app, &settings);
+    Ok(settings)
+}
+
+/// Vuelve a los valores por defecto y emite `settings://changed`.
+/// Retorna false si no había ajustes guardados.
+#[tauri::command]
+pub async fn clear_app_settings(app: AppHandle) -> AppResult<bool> {
+    let cleared = blocking(app.clone(), clear).await?;
+    notify(&app, &AppSettings::default());
+    Ok(cleared)
+}
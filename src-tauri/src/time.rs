@@ -0,0 +1,11 @@
+//! Reloj compartido por los módulos que guardan fechas (alias, diccionario, base local).
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Milisegundos desde epoch
+pub fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
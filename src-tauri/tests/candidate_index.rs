@@ -0,0 +1,84 @@
+use minerva_lib::matching::fuzzy::FuzzyIndex;
+use minerva_lib::matching::index::{CandidateIndex, Retrieval};
+use minerva_lib::matching::MatchingRules;
+
+const TOPICS: &[&str] = &[
+    "BVP - JUAN ALBERTO RIVERA - L9 (ONLINE)",
+    "BVP - ANA MARTINEZ GOMEZ - L7 (ONLINE)",
+    "BVP - DANIEL SANCHEZ TORRES - TRUE BEGINNER (ONLINE)",
+    "VANESSA LOPEZ DE LOS RIOS - L5 (ONLINE)",
+    "BVP - MIGUEL DE LA CRUZ FERNANDEZ - L5 (HIBRIDO)",
+    "BVP-CARMEN MENDOZA L10 (ONLINE)",
+    "PHOENIX (7 - 11) LOOK 1 (F2F_PER) 18/10",
+    "RAINBOW (4-6) LOOK & SEE 1 (PREMIUM - ONLINE)",
+    "TRIO TECHCORP L4 (ONLINE)",
+    "DUO TECHCORP L4 (ONLINE)",
+    "CH 3 ACME L2 (ONLINE)",
+    "CH 1 ACME L2 (ONLINE)",
+    "LUIS VELASQUEZ DEL AGUILA NIVELACION TEAM ZONE 1 (ONLINE)",
+    "RICARDO DEL VALLE MORENO - KEYNOTES ADVANCED (ONLINE)",
+    "CARLOS ANDRES RODRIGUEZ VEGA (ACME)(ONLINE) - ENG L3",
+    "[WORKSHOP] Club Intermedio + Avanzado",
+];
+
+const QUERIES: &[&str] = &[
+    "Rivera Iriarte (Per)(ONLINE), Juan Alberto",
+    "Martinez Gomez (Per)(ONLINE), Ana Gabriela",
+    "CH ACME L3 (ONLINE)",
+    "TRIO TECHCORP L3 (ONLINE)",
+    "TECHCORP",
+    "BVP KIDS - LUIS VELASQUEZ DEL AGUILA - TIME ZONE 3 (ONLINE)",
+    "Del Valle Moreno (Per)(Online), Ricardo David",
+    "GLOBEX CORP - ENG L3",
+    "WORKSHOP",
+    "xyz",
+];
+
+fn normalized_topics() -> Vec<String> {
+    let rules = MatchingRules::bundled();
+    TOPICS.iter().map(|t| rules.normalize(t)).collect()
+}
+
+#[test]
+fn fuzzy_retrieval_matches_full_scan() {
+    let rules = MatchingRules::bundled();
+    let threshold = rules.thresholds().fuse_max_score;
+    let topics = normalized_topics();
+    let index = CandidateIndex::new(topics.clone());
+    let full_scan = FuzzyIndex::new(topics.iter().map(|t| vec![t.clone()]).collect(), false);
+
+    for query in QUERIES {
+        let query = rules.normalize(query);
+        let expected: Vec<usize> = full_scan
+            .search(&query, threshold)
+            .into_iter()
+            .filter(|(_, score)| *score <= threshold)
+            .map(|(idx, _)| idx)
+            .collect();
+
+        let candidates = index.retrieve(&query, rules.thresholds());
+        if candidates.strategy == Retrieval::Fuzzy {
+            assert_eq!(candidates.indices, expected, "{}", query);
+        } else {
+            assert!(expected.is_empty(), "{}", query);
+        }
+    }
+}
+
+#[test]
+fn retrieval_strategies_in_order() {
+    let rules = MatchingRules::bundled();
+    let index = CandidateIndex::new(normalized_topics());
+
+    let exact = index.retrieve(&rules.normalize("TRIO TECHCORP L4"), rules.thresholds());
+    assert_eq!(exact.strategy, Retrieval::Exact);
+    assert_eq!(exact.indices, [8]);
+
+    let fuzzy = index.retrieve(&rules.normalize("TRIO TECHCORP L3"), rules.thresholds());
+    assert_eq!(fuzzy.strategy, Retrieval::Fuzzy);
+    assert!(fuzzy.indices.contains(&8));
+
+    let none = index.retrieve("xyz", rules.thresholds());
+    assert_eq!(none.strategy, Retrieval::TokenSet);
+    assert!(none.indices.is_empty());
+}
@@ -0,0 +1,134 @@
+//! Utilidades compartidas por los tests de matching.
+
+#![allow(dead_code)]
+
+use std::collections::BTreeMap;
+
+use minerva_lib::matching::hosts::HostAssignment;
+use minerva_lib::matching::penalties::{AppliedPenalty, ScoringContext};
+use minerva_lib::matching::scorer::score_candidate;
+use minerva_lib::matching::{
+    MatchOptions, MatchingRules, MatchingService, ZoomMeetingCandidate, ZoomUserCandidate,
+};
+use minerva_lib::schedule::Schedule;
+use serde_json::json;
+
+pub fn meeting(id: &str, topic: &str, host_id: &str) -> ZoomMeetingCandidate {
+    ZoomMeetingCandidate {
+        meeting_id: id.to_string(),
+        topic: topic.to_string(),
+        host_id: host_id.to_string(),
+        start_time: "2023-01-01".to_string(),
+        join_url: None,
+        created_at: None,
+    }
+}
+
+pub fn user(id: &str, first_name: &str, last_name: &str, display_name: &str) -> ZoomUserCandidate {
+    ZoomUserCandidate {
+        id: id.to_string(),
+        email: format!("{}@test.com", id),
+        first_name: first_name.to_string(),
+        last_name: last_name.to_string(),
+        display_name: display_name.to_string(),
+    }
+}
+
+/// Horario con solo programa e instructor relevantes para el matching
+pub fn schedule(program: &str, instructor: &str) -> Schedule {
+    serde_json::from_value(json!({
+        "date": "01/01/2023",
+        "shift": "",
+        "branch": "",
+        "start_time": "09:00",
+        "end_time": "10:00",
+        "code": "",
+        "instructor": instructor,
+        "program": program,
+        "minutes": "60",
+        "units": 1,
+    }))
+    .unwrap()
+}
+
+pub fn matcher(meetings: &[ZoomMeetingCandidate], users: &[ZoomUserCandidate]) -> MatchingService {
+    MatchingService::new(meetings.to_vec(), users.to_vec(), MatchingRules::bundled())
+}
+
+/// Evalúa una penalización (o todas, con `score`) sobre un candidato y sus hermanos
+pub fn with_context<T>(
+    program: &str,
+    candidate: &ZoomMeetingCandidate,
+    all_candidates: &[ZoomMeetingCandidate],
+    options: MatchOptions,
+    f: impl FnOnce(&ScoringContext) -> T,
+) -> T {
+    let rules = MatchingRules::bundled();
+    let normalized_program = rules.normalize(program);
+    let normalized: Vec<String> = all_candidates
+        .iter()
+        .map(|m| rules.normalize(&m.topic))
+        .collect();
+    let all: Vec<(&ZoomMeetingCandidate, &str)> = all_candidates
+        .iter()
+        .zip(&normalized)
+        .map(|(m, n)| (m, n.as_str()))
+        .collect();
+    let normalized_topic = rules.normalize(&candidate.topic);
+
+    f(&ScoringContext {
+        rules: &rules,
+        raw_program: program,
+        raw_topic: &candidate.topic,
+        normalized_program: &normalized_program,
+        normalized_topic: &normalized_topic,
+        candidate,
+        all_candidates: &all,
+        options,
+    })
+}
+
+/// Penalizaciones aplicadas al candidato (equivalente a `scoreCandidate`)
+pub fn score(program: &str, candidate: &ZoomMeetingCandidate) -> (i32, Vec<AppliedPenalty>) {
+    with_context(
+        program,
+        candidate,
+        std::slice::from_ref(candidate),
+        MatchOptions::default(),
+        |ctx| {
+            let result = score_candidate(ctx);
+            (result.final_score, result.penalties)
+        },
+    )
+}
+
+/// Fila con meeting y anfitrión asignados para los tests de concurrencia de hosts
+pub fn row(date: &str, start: &str, end: &str, meeting_id: &str, host_id: &str) -> HostAssignment {
+    serde_json::from_value(json!({
+        "date": date,
+        "shift": "",
+        "branch": "",
+        "start_time": start,
+        "end_time": end,
+        "code": "",
+        "instructor": "Juan Perez",
+        "program": format!("PROGRAM {}", meeting_id),
+        "minutes": "60",
+        "units": 1,
+        "meeting_id": meeting_id,
+        "host_id": host_id,
+    }))
+    .unwrap()
+}
+
+/// Anfitriones disponibles (id -> nombre); h1 coincide con el instructor de `row`
+pub fn hosts() -> BTreeMap<String, String> {
+    [
+        ("h1", "Juan Perez"),
+        ("h2", "Ana Lopez"),
+        ("h3", "Carla Diaz"),
+    ]
+    .into_iter()
+    .map(|(id, name)| (id.to_string(), name.to_string()))
+    .collect()
+}
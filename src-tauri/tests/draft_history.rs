@@ -0,0 +1,95 @@
+mod common;
+
+use std::fs;
+
+use common::schedule;
+use minerva_lib::store::drafts::SnapshotReason;
+use minerva_lib::store::history::{self, DraftChanges};
+use minerva_lib::store::{drafts, Store, DB_FILE};
+
+#[test]
+fn diff_tells_instructor_changes_from_added_and_removed_rows() {
+    let trio = schedule("TRIO TECHCORP L4", "Juan Perez");
+    let acme = schedule("CH ACME L2", "Ana Lopez");
+    let globex = schedule("DUO GLOBEX L1", "Carla Diaz");
+    let mut moved_branch = acme.clone();
+    moved_branch.branch = "HUB".to_string();
+
+    let before = [trio.clone(), acme.clone(), globex.clone()];
+    let after = [
+        schedule("TRIO TECHCORP L4", "Pedro Gomez"),
+        moved_branch.clone(),
+        schedule("KIDS INITECH L3", "Luis Rojas"),
+    ];
+    let diff = history::diff(&before, &after);
+
+    assert_eq!(
+        diff.changes(),
+        DraftChanges {
+            added: 1,
+            removed: 1,
+            instructors_changed: 1,
+            modified: 1,
+        }
+    );
+    assert_eq!(diff.instructor_changes[0].before, trio);
+    assert_eq!(diff.instructor_changes[0].after.instructor, "Pedro Gomez");
+    assert_eq!(diff.modified[0].after, moved_branch);
+    assert_eq!(diff.added[0].program, "KIDS INITECH L3");
+    assert_eq!(diff.removed, [globex]);
+
+    // Mismas filas en otro orden: sin cambios
+    let reordered = [before[2].clone(), before[0].clone(), before[1].clone()];
+    assert_eq!(
+        history::diff(&before, &reordered).changes(),
+        DraftChanges::default()
+    );
+}
+
+#[test]
+fn overwritten_draft_is_restored_after_restart() {
+    let dir = std::env::temp_dir().join(format!("minerva-history-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let path = dir.join(DB_FILE);
+
+    // Ediciones de la mañana y luego un Excel nuevo que las pisa
+    let morning = [
+        schedule("TRIO TECHCORP L4", "Juan Perez"),
+        schedule("CH ACME L2", "Ana Lopez"),
+    ];
+    let mut edited = morning.clone();
+    edited[1].instructor = "Carla Diaz".to_string();
+    let upload = [schedule("DUO GLOBEX L1", "Luis Rojas")];
+
+    let store = Store::open(&path).unwrap();
+    drafts::save(&store, &morning, 100).unwrap();
+    drafts::save(&store, &edited, 200).unwrap();
+    drafts::save(&store, &upload, 300).unwrap();
+    drop(store);
+
+    let store = Store::open(&path).unwrap();
+    let timeline = history::list(&store).unwrap();
+    let created: Vec<i64> = timeline.iter().map(|s| s.created_at).collect();
+    assert_eq!(created, [300, 200, 100]);
+    assert_eq!(timeline[1].changes.instructors_changed, 1);
+    assert_eq!(timeline[0].changes.added, 1);
+    assert_eq!(timeline[0].changes.removed, 2);
+
+    let edits = timeline[1].id;
+    let preview = history::preview(&store, edits).unwrap();
+    assert_eq!(preview.changes().added, 2);
+    assert_eq!(preview.removed, upload);
+
+    assert_eq!(history::restore(&store, edits, 400).unwrap(), edited);
+    assert_eq!(drafts::load(&store).unwrap(), edited);
+    // La restauración es un snapshot más y el Excel que se reemplazó queda marcado:
+    // se puede deshacer
+    let timeline = history::list(&store).unwrap();
+    assert_eq!(timeline[0].created_at, 400);
+    assert_eq!(timeline[1].created_at, 300);
+    assert_eq!(timeline[1].reason, SnapshotReason::Restore);
+
+    assert!(history::restore(&store, 9999, 500).is_err());
+    drop(store);
+    fs::remove_dir_all(&dir).unwrap();
+}
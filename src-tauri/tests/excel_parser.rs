@@ -0,0 +1,72 @@
+use minerva_lib::excel::parser::{parse_workbook, SheetError};
+use minerva_lib::schedule::Schedule;
+
+/// Libro con una hoja por instructor, una hoja exportada y una hoja con la fecha inválida
+const FIXTURE: &[u8] = include_bytes!("fixtures/schedules.xlsx");
+
+fn parsed() -> Vec<Schedule> {
+    parse_workbook(FIXTURE).unwrap().schedules
+}
+
+fn times(schedule: &Schedule) -> (String, String) {
+    (
+        schedule.start_time.to_string(),
+        schedule.end_time.to_string(),
+    )
+}
+
+#[test]
+fn instructor_sheet_reads_header_and_counts_group_units() {
+    let schedules = parsed();
+    let sheet: Vec<&Schedule> = schedules.iter().filter(|s| s.code == "P001").collect();
+
+    // La fila con el bloque "@Corp" se omite, "BLOCK B" reemplaza al grupo vacío
+    let programs: Vec<&str> = sheet.iter().map(|s| s.program.as_str()).collect();
+    assert_eq!(programs, ["GRP-A", "GRP-A", "BLOCK B"]);
+
+    let first = sheet[0];
+    assert_eq!(first.date.to_string(), "01/01/2024");
+    assert_eq!(first.instructor, "DOE, JOHN");
+    assert_eq!(first.branch, "LA MOLINA/KIDS");
+    assert_eq!(first.shift, "P. ZUÑIGA");
+    assert_eq!(times(first), ("08:00".into(), "08:45".into()));
+    assert_eq!(first.minutes, "45");
+    assert_eq!(first.units, 2);
+
+    let afternoon = sheet[1];
+    assert_eq!(afternoon.branch, "LA MOLINA");
+    assert_eq!(afternoon.shift, "H. GARCIA");
+    assert_eq!(afternoon.minutes, "30");
+    assert_eq!(sheet[2].units, 0);
+}
+
+#[test]
+fn exported_sheet_is_read_by_header_names() {
+    let schedules = parsed();
+    let exported = schedules.iter().find(|s| s.code == "P002").unwrap();
+
+    assert_eq!(exported.date.to_string(), "15/03/2024");
+    assert_eq!(times(exported), ("14:00".into(), "15:00".into()));
+    assert_eq!(exported.branch, "HUB");
+    assert_eq!(exported.program, "GRP-B");
+    assert_eq!(exported.units, 2);
+}
+
+#[test]
+fn invalid_dates_are_reported_instead_of_dropped() {
+    let errors = parse_workbook(FIXTURE).unwrap().errors;
+    let located: Vec<(&str, usize, &str)> = errors
+        .iter()
+        .map(|SheetError { sheet, error }| (sheet.as_str(), error.row, error.field.as_str()))
+        .collect();
+
+    // Fila 3 de la hoja exportada y fecha del encabezado (O2) de la hoja original
+    assert_eq!(located, [("Schedule", 2, "date"), ("BAD DATE", 1, "date")]);
+    assert!(errors[0].error.message.contains("not a date"));
+    assert!(parsed().iter().all(|s| s.code != "P003"));
+}
+
+#[test]
+fn invalid_workbook_is_an_error() {
+    assert!(parse_workbook(b"not a workbook").is_err());
+}
@@ -0,0 +1,190 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use minerva_lib::error::AppError;
+use minerva_lib::files::{
+    enforce_extension, read_opened_files, resolve_save_path, FileFilter, OpenMode, OpenedContent,
+    SavePath, SaveUploads, MAX_UPLOAD_SIZE, UPLOAD_TIMEOUT,
+};
+
+#[test]
+fn io_errors_map_to_actionable_codes() {
+    let path = Path::new("/exports/report.xlsx");
+    let code = |kind: io::ErrorKind| AppError::io(io::Error::from(kind), path).code();
+
+    assert_eq!(code(io::ErrorKind::PermissionDenied), "PERMISSION_DENIED");
+    assert_eq!(code(io::ErrorKind::ReadOnlyFilesystem), "PERMISSION_DENIED");
+    assert_eq!(code(io::ErrorKind::StorageFull), "DISK_FULL");
+    assert_eq!(code(io::ErrorKind::QuotaExceeded), "DISK_FULL");
+    assert_eq!(code(io::ErrorKind::NotFound), "NOT_FOUND");
+    assert_eq!(code(io::ErrorKind::Interrupted), "IO_ERROR");
+
+    let error =
+        serde_json::to_value(AppError::io(io::Error::from(io::ErrorKind::NotFound), path)).unwrap();
+    assert_eq!(error["messageKey"], "errors.not_found");
+    assert_eq!(error["details"]["path"], "/exports/report.xlsx");
+}
+
+fn excel() -> Vec<FileFilter> {
+    vec![FileFilter::new("Excel Workbook", &["xlsx", "xls"])]
+}
+
+#[test]
+fn extension_is_forced_to_the_first_filter() {
+    let fixed = |path: &str, filters: &[FileFilter]| enforce_extension(path.into(), filters);
+
+    assert_eq!(
+        fixed("reporte.txt", &excel()),
+        PathBuf::from("reporte.txt.xlsx")
+    );
+    assert_eq!(fixed("report", &excel()), PathBuf::from("report.xlsx"));
+    assert_eq!(fixed("report.XLS", &excel()), PathBuf::from("report.XLS"));
+    // Sin filtros o con comodín se acepta cualquier extensión
+    assert_eq!(fixed("reporte.txt", &[]), PathBuf::from("reporte.txt"));
+    let any = [FileFilter::new("All", &["*"])];
+    assert_eq!(fixed("reporte.txt", &any), PathBuf::from("reporte.txt"));
+}
+
+#[test]
+fn corrected_path_that_already_exists_is_not_ready() {
+    let dir = std::env::temp_dir().join(format!("minerva-files-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("reporte.txt.xlsx"), b"previous").unwrap();
+    fs::write(dir.join("confirmed.xlsx"), b"previous").unwrap();
+
+    assert_eq!(
+        resolve_save_path(dir.join("reporte.txt"), &excel()),
+        SavePath::Exists(dir.join("reporte.txt.xlsx"))
+    );
+    assert_eq!(
+        resolve_save_path(dir.join("report"), &excel()),
+        SavePath::Ready(dir.join("report.xlsx"))
+    );
+    // El diálogo ya confirmó el reemplazo del path elegido
+    assert_eq!(
+        resolve_save_path(dir.join("confirmed.xlsx"), &excel()),
+        SavePath::Ready(dir.join("confirmed.xlsx"))
+    );
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn one_invalid_file_does_not_drop_the_rest_of_the_selection() {
+    let dir = std::env::temp_dir().join(format!("minerva-open-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("notes.txt"), b"hello").unwrap();
+    fs::write(dir.join("big.txt"), b"more than ten bytes").unwrap();
+    fs::write(dir.join("data.csv"), b"a,b").unwrap();
+
+    let paths = ["notes.txt", "big.txt", "data.csv", "missing.txt"]
+        .iter()
+        .map(|name| dir.join(name))
+        .collect();
+    let opened = read_opened_files(
+        paths,
+        &[FileFilter::new("Text", &["txt"])],
+        10,
+        OpenMode::Bytes,
+    );
+
+    assert_eq!(opened.files.len(), 1);
+    assert_eq!(opened.files[0].name, "notes.txt");
+    assert!(matches!(
+        &opened.files[0].content,
+        OpenedContent::Bytes { content } if content == b"hello"
+    ));
+    let failed: Vec<(&str, &str)> = opened
+        .failed
+        .iter()
+        .map(|f| (f.name.as_str(), f.error.code()))
+        .collect();
+    assert_eq!(
+        failed,
+        [
+            ("big.txt", "FILE_TOO_LARGE"),
+            ("data.csv", "FILE_TYPE_NOT_ALLOWED"),
+            ("missing.txt", "NOT_FOUND"),
+        ]
+    );
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn chunked_upload_assembles_parts_in_order() {
+    let uploads = SaveUploads::default();
+    let now = Instant::now();
+    let id = uploads.start("export.xlsx".to_string(), now);
+
+    uploads.append(id, b"first ", now).unwrap();
+    uploads.append(id, b"second", now).unwrap();
+    assert_eq!(uploads.finish(id, now).unwrap(), b"first second");
+
+    // Terminada (o cancelada) la subida ya no existe
+    assert!(matches!(
+        uploads.append(id, b"late", now),
+        Err(AppError::InvalidRequest(_))
+    ));
+    let other = uploads.start(String::new(), now);
+    uploads.cancel(other);
+    assert!(uploads.finish(other, now).is_err());
+}
+
+#[test]
+fn upload_over_the_size_cap_is_rejected_and_dropped() {
+    let uploads = SaveUploads::default();
+    let now = Instant::now();
+    let id = uploads.start("huge.xlsx".to_string(), now);
+    let chunk = vec![0u8; (MAX_UPLOAD_SIZE / 2) as usize];
+
+    uploads.append(id, &chunk, now).unwrap();
+    uploads.append(id, &chunk, now).unwrap();
+    let error = uploads.append(id, b"!", now).unwrap_err();
+    assert!(
+        matches!(error, AppError::FileTooLarge { ref name, .. } if name == "huge.xlsx"),
+        "{error:?}"
+    );
+    assert!(uploads.finish(id, now).is_err());
+}
+
+#[test]
+fn starting_an_upload_drops_abandoned_ones() {
+    let uploads = SaveUploads::default();
+    let start = Instant::now();
+    let abandoned = uploads.start(String::new(), start);
+    let active = uploads.start(String::new(), start);
+    uploads
+        .append(active, b"data", start + UPLOAD_TIMEOUT / 2)
+        .unwrap();
+
+    let later = start + UPLOAD_TIMEOUT + Duration::from_secs(1);
+    uploads.start(String::new(), later);
+    assert!(uploads.finish(abandoned, later).is_err());
+    assert_eq!(uploads.finish(active, later).unwrap(), b"data");
+}
+
+#[test]
+fn appending_or_finishing_an_upload_drops_abandoned_ones() {
+    let uploads = SaveUploads::default();
+    let start = Instant::now();
+    let abandoned = uploads.start(String::new(), start);
+    let active = uploads.start(String::new(), start + UPLOAD_TIMEOUT / 2);
+
+    // Sin ninguna subida nueva, la siguiente parte de otra ya descarta la abandonada
+    let later = start + UPLOAD_TIMEOUT + Duration::from_secs(1);
+    uploads.append(active, b"data", later).unwrap();
+    assert!(matches!(
+        uploads.append(abandoned, b"late", later),
+        Err(AppError::InvalidRequest(_))
+    ));
+
+    // Una subida sin actividad por más del límite ya no se puede terminar
+    let much_later = later + UPLOAD_TIMEOUT + Duration::from_secs(1);
+    assert!(matches!(
+        uploads.finish(active, much_later),
+        Err(AppError::InvalidRequest(_))
+    ));
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use common::{hosts, row};
+use minerva_lib::matching::allocation::{plan_hosts, PlanOutcome};
+use minerva_lib::matching::hosts::{check_host_concurrency, HostAssignment, HostWindow};
+
+#[test]
+fn plan_keeps_usual_hosts_and_moves_overlaps_to_free_hosts() {
+    let rows = vec![
+        row("01/01/2024", "09:00", "10:00", "m1", "h1"),
+        row("01/01/2024", "09:30", "10:30", "m2", "h1"),
+        row("01/01/2024", "09:00", "11:00", "m3", "h2"),
+        // Sin habitual
+        row("01/01/2024", "12:00", "13:00", "m4", ""),
+    ];
+
+    let plan = plan_hosts(&rows, &[], &hosts());
+
+    let hosts_by_meeting: Vec<(&str, Option<&str>, PlanOutcome)> = plan
+        .meetings
+        .iter()
+        .map(|m| (m.meeting_id.as_str(), m.host_id.as_deref(), m.outcome))
+        .collect();
+    assert_eq!(
+        hosts_by_meeting,
+        [
+            ("m1", Some("h1"), PlanOutcome::Preferred),
+            // h2 está ocupado por m3: va a h3, el único libre
+            ("m2", Some("h3"), PlanOutcome::Reassigned),
+            ("m3", Some("h2"), PlanOutcome::Preferred),
+            // Todos libres; h2 y h3 tienen un tramo, igual que h1: gana el nombre
+            ("m4", Some("h2"), PlanOutcome::Reassigned),
+        ]
+    );
+    assert_eq!(
+        (plan.preferred, plan.reassigned, plan.unassigned),
+        (2, 2, 0)
+    );
+}
+
+#[test]
+fn meetings_keep_a_single_host_across_rows_and_plan_has_no_conflicts() {
+    let rows = vec![
+        // m1 se repite dos días: las dos filas deben quedar en el mismo anfitrión
+        row("01/01/2024", "09:00", "10:00", "m1", "h1"),
+        row("02/01/2024", "09:00", "10:00", "m1", "h1"),
+        row("02/01/2024", "09:30", "10:30", "m2", "h1"),
+        row("02/01/2024", "09:30", "10:30", "m3", "h2"),
+        row("02/01/2024", "09:45", "10:15", "m4", "h3"),
+        // No cabe en ningún anfitrión
+        row("02/01/2024", "09:00", "11:00", "m5", "h1"),
+    ];
+
+    let plan = plan_hosts(&rows, &[], &hosts());
+
+    assert_eq!(plan.meetings[0].rows, [0, 1]);
+    assert_eq!(plan.meetings[0].host_id.as_deref(), Some("h1"));
+    assert_eq!(plan.unassigned, 2);
+
+    // Aplicar el plan no deja cruces
+    let planned: Vec<HostAssignment> = plan
+        .meetings
+        .iter()
+        .filter_map(|m| m.host_id.as_ref().map(|host| (m, host)))
+        .flat_map(|(m, host)| {
+            m.rows.iter().map(|&i| HostAssignment {
+                host_id: host.clone(),
+                ..rows[i].clone()
+            })
+        })
+        .collect();
+    let report = check_host_concurrency(&planned, &hosts(), HostWindow::default());
+    assert!(report.conflicts.is_empty());
+}
+
+#[test]
+fn fixed_rows_occupy_their_hosts() {
+    let rows = vec![row("01/01/2024", "09:00", "10:00", "m1", "h1")];
+    let fixed = vec![
+        row("01/01/2024", "09:30", "10:30", "m2", "h1"),
+        row("01/01/2024", "08:00", "12:00", "m3", "h2"),
+    ];
+
+    let plan = plan_hosts(&rows, &fixed, &hosts());
+
+    assert_eq!(plan.meetings.len(), 1);
+    assert_eq!(plan.meetings[0].preferred_host_id.as_deref(), Some("h1"));
+    assert_eq!(plan.meetings[0].host_id.as_deref(), Some("h3"));
+    assert_eq!(plan.meetings[0].outcome, PlanOutcome::Reassigned);
+}
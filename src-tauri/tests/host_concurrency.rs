@@ -0,0 +1,57 @@
+mod common;
+
+use common::{hosts, row};
+use minerva_lib::matching::hosts::{check_host_concurrency, HostWindow};
+
+#[test]
+fn overlapping_meetings_on_same_host_are_reported_with_free_hosts() {
+    let rows = [
+        row("01/01/2024", "09:00", "10:00", "m1", "h1"),
+        row("01/01/2024", "09:30", "10:30", "m2", "h1"),
+        // Encadenada con m2: mismo grupo aunque no cruza con m1
+        row("01/01/2024", "10:15", "11:00", "m3", "h1"),
+        // Ocupa a h2 durante el tramo
+        row("01/01/2024", "10:45", "11:30", "m4", "h2"),
+        // Consecutiva (sin cruce) y en otro día
+        row("01/01/2024", "11:00", "12:00", "m5", "h1"),
+        row("02/01/2024", "09:00", "10:00", "m6", "h1"),
+    ];
+
+    let report = check_host_concurrency(&rows, &hosts(), HostWindow::default());
+
+    assert_eq!(report.conflicts.len(), 1);
+    assert_eq!(report.conflict_count, 3);
+    let conflict = &report.conflicts[0];
+    assert_eq!(conflict.host_name, "Juan Perez");
+    assert_eq!(conflict.start_time.to_string(), "09:00");
+    assert_eq!(conflict.end_time.to_string(), "11:00");
+    let rows: Vec<usize> = conflict.rows.iter().map(|r| r.row).collect();
+    assert_eq!(rows, [0, 1, 2]);
+    let free: Vec<&str> = conflict.free_hosts.iter().map(|h| h.id.as_str()).collect();
+    assert_eq!(free, ["h3"]);
+}
+
+#[test]
+fn same_meeting_and_rows_outside_window_are_not_conflicts() {
+    let rows = [
+        // Mismo meeting en dos filas: no es concurrencia
+        row("01/01/2024", "09:00", "10:00", "m1", "h1"),
+        row("01/01/2024", "09:00", "10:00", "m1", "h1"),
+        // Cruce real, pero fuera de la ventana
+        row("05/01/2024", "09:00", "10:00", "m2", "h1"),
+        row("05/01/2024", "09:30", "10:30", "m3", "h1"),
+        // Sin anfitrión
+        row("01/01/2024", "09:00", "10:00", "m4", ""),
+    ];
+    let window = HostWindow {
+        from: Some("01/01/2024".parse().unwrap()),
+        to: Some("04/01/2024".parse().unwrap()),
+    };
+
+    let report = check_host_concurrency(&rows, &hosts(), window);
+    assert!(report.conflicts.is_empty());
+
+    let report = check_host_concurrency(&rows, &hosts(), HostWindow::default());
+    assert_eq!(report.conflicts.len(), 1);
+    assert_eq!(report.conflicts[0].rows[0].meeting_id, "m2");
+}
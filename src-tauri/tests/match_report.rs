@@ -0,0 +1,85 @@
+mod common;
+
+use common::{matcher, meeting, schedule};
+use minerva_lib::matching::report::{compare, to_csv, ZoomExport};
+use minerva_lib::matching::{MatchResult, MatchStatus};
+use serde_json::json;
+
+fn results() -> Vec<MatchResult> {
+    let service = matcher(
+        &[
+            meeting("m1", "TRIO TECHCORP L4 (ONLINE)", "h1"),
+            meeting("m2", "CH 3 ACME L2 (ONLINE)", "h2"),
+        ],
+        &[],
+    );
+    service.match_all(&[
+        schedule("TRIO TECHCORP L4 (ONLINE)", "Juan Perez"),
+        schedule("CH 3 ACME L2 (ONLINE)", "Ana Gomez"),
+    ])
+}
+
+#[test]
+fn unchanged_results_have_no_regressions() {
+    let results = results();
+    let expected: Vec<MatchResult> =
+        serde_json::from_str(&serde_json::to_string(&results).unwrap()).unwrap();
+    assert!(compare(&expected, &results).is_empty());
+}
+
+#[test]
+fn reports_changed_and_missing_rows() {
+    let actual = results();
+    let mut expected = actual.clone();
+    expected[0].outcome.meeting_id = Some("m_old".to_string());
+
+    let regressions = compare(&expected, &actual[1..]);
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].actual_status, None);
+
+    let regressions = compare(&expected, &actual);
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].expected_meeting_id.as_deref(), Some("m_old"));
+    assert_eq!(regressions[0].actual_status, Some(MatchStatus::Assigned));
+    assert_eq!(
+        regressions[0].to_string(),
+        "01/01/2023|09:00|10:00|Juan Perez|TRIO TECHCORP L4 (ONLINE): \
+         expected assigned (m_old), got assigned (m1)"
+    );
+}
+
+#[test]
+fn csv_quotes_fields_with_separators() {
+    let mut results = results();
+    results[0].outcome.reason = "Level mismatch, \"L4\"".to_string();
+
+    let csv = to_csv(&results);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(
+        lines[0],
+        "date,start_time,end_time,instructor,program,status,meeting_id,score,reason"
+    );
+    assert_eq!(
+        lines[1],
+        "01/01/2023,09:00,10:00,Juan Perez,TRIO TECHCORP L4 (ONLINE),assigned,m1,100,\
+         \"Level mismatch, \"\"L4\"\"\""
+    );
+    assert_eq!(lines.len(), 3);
+}
+
+#[test]
+fn zoom_export_accepts_list_or_object() {
+    let list: ZoomExport =
+        serde_json::from_value(json!([{ "meeting_id": "m1", "topic": "A", "host_id": "h1" }]))
+            .unwrap();
+    assert_eq!(list.into_parts().0.len(), 1);
+
+    let full: ZoomExport = serde_json::from_value(json!({
+        "meetings": [],
+        "users": [{ "id": "u1", "email": null, "first_name": "Ana", "last_name": "Gomez", "display_name": "Ana Gomez" }]
+    }))
+    .unwrap();
+    let (meetings, users) = full.into_parts();
+    assert!(meetings.is_empty());
+    assert_eq!(users[0].first_name, "Ana");
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use common::{matcher, meeting, schedule};
+use minerva_lib::matching::index::Retrieval;
+use minerva_lib::matching::scorer::{Confidence, Decision};
+use minerva_lib::matching::{MatchOptions, MatchStatus};
+
+const TRACED: MatchOptions = MatchOptions {
+    ignore_level_mismatch: false,
+    trace: true,
+};
+
+#[test]
+fn trace_is_only_returned_when_requested() {
+    let service = matcher(&[meeting("m1", "TRIO TECHCORP L4 (ONLINE)", "h1")], &[]);
+    let row = schedule("TRIO TECHCORP L4 (ONLINE)", "Juan Perez");
+
+    assert!(service
+        .find_match(&row, MatchOptions::default())
+        .trace
+        .is_none());
+
+    let trace = service.find_match(&row, TRACED).trace.unwrap();
+    assert_eq!(trace.decision, Decision::Assigned);
+    assert_eq!(trace.confidence, Confidence::High);
+    assert_eq!(trace.candidates.len(), 1);
+    assert_eq!(trace.candidates[0].retrieval, Retrieval::Exact);
+    assert!(trace.candidates[0].penalties.is_empty());
+}
+
+#[test]
+fn trace_explains_ambiguous_candidates() {
+    let service = matcher(
+        &[
+            meeting("m1", "CH 1 ACME L2 (ONLINE)", "h1"),
+            meeting("m2", "CH 3 ACME L2 (ONLINE)", "h2"),
+        ],
+        &[],
+    );
+    let outcome = service.find_match(&schedule("CH ACME L2 (ONLINE)", "Juan Perez"), TRACED);
+    assert_eq!(outcome.status, MatchStatus::Ambiguous);
+
+    let trace = outcome.trace.unwrap();
+    assert_eq!(trace.normalized_query, "ch acme l2");
+    assert_eq!(trace.decision, Decision::Ambiguous);
+    assert_eq!(trace.confidence, Confidence::Low);
+    assert_eq!(trace.candidates.len(), 2);
+
+    // Cada candidato lleva las penalizaciones que explican su score
+    for candidate in &trace.candidates {
+        assert_eq!(candidate.retrieval, Retrieval::TokenSet);
+        assert_eq!(candidate.penalties[0].name, "ORPHAN_NUMBER_WITH_SIBLINGS");
+        let points: i32 = candidate.penalties.iter().map(|p| p.points).sum();
+        assert_eq!(
+            candidate.final_score,
+            (candidate.base_score + points).max(0)
+        );
+    }
+}
+
+#[test]
+fn trace_without_candidates() {
+    let service = matcher(&[meeting("m1", "TRIO TECHCORP L4 (ONLINE)", "h1")], &[]);
+    let trace = service
+        .find_match(&schedule("XYZ", "Juan Perez"), TRACED)
+        .trace
+        .unwrap();
+
+    assert_eq!(trace.normalized_query, "xyz");
+    assert!(trace.candidates.is_empty());
+    assert_eq!(trace.decision, Decision::NotFound);
+    assert_eq!(trace.confidence, Confidence::None);
+}
@@ -0,0 +1,401 @@
+mod common;
+
+use common::{matcher, meeting, schedule, score};
+use minerva_lib::matching::{MatchOptions, MatchStatus, ZoomMeetingCandidate};
+
+const RELAXED: MatchOptions = MatchOptions {
+    ignore_level_mismatch: true,
+    trace: false,
+};
+
+fn mock_meetings() -> Vec<ZoomMeetingCandidate> {
+    vec![
+        meeting("m1", "BVP - JUAN ALBERTO RIVERA - L9 (ONLINE)", "h1"),
+        meeting("m2", "BVP - ANA MARTINEZ GOMEZ - L7 (ONLINE)", "h2"),
+        meeting(
+            "m3",
+            "BVP - DANIEL SANCHEZ TORRES - TRUE BEGINNER (ONLINE)",
+            "h3",
+        ),
+        meeting("m4", "VANESSA LOPEZ DE LOS RIOS - L5 (ONLINE)", "h4"),
+        meeting(
+            "m5",
+            "BVP - MIGUEL DE LA CRUZ FERNANDEZ - L5 (HIBRIDO)",
+            "h5",
+        ),
+        meeting("m6", "BVP-CARMEN MENDOZA L10 (ONLINE)", "h6"),
+        meeting("m7", "PHOENIX (7 - 11) LOOK 1 (F2F_PER) 18/10", "h7"),
+        meeting("m8", "RAINBOW (4-6) LOOK & SEE 1 (PREMIUM - ONLINE)", "h8"),
+        meeting("m9", "STARLIGHT L5 (PREMIUM - ONLINE)", "h9"),
+        meeting("m10", "TRIO TECHCORP L4 (ONLINE)", "h10"),
+        meeting("m11", "DUO SILVA - PEREZ L10 (ONLINE)", "h11"),
+        meeting("m12", "BVD SILVA - PEREZ - L10 (ONLINE)", "h12"),
+        meeting("m13", "BVD UNIQUE TOKEN (ONLINE)", "h13"),
+        meeting("m14", "SUNSET DRIVE L3 (ONLINE)", "h14"),
+        meeting("m15", "AURORA (16 - 17) IMPACT 4 (PREMIUM - ONLINE)", "h15"),
+        meeting("m16", "CH GLOBALTECH N8 (PRESENCIAL-TRAVEL)", "h16"),
+        meeting("m17", "CH 3 ACME L2 (ONLINE)", "h17"),
+        // Candidatos para debug de ambigüedad
+        meeting(
+            "m_persona1",
+            "BVP - MARIA TORRES FLORES - L1 (ONLINE)",
+            "h_persona1",
+        ),
+        meeting(
+            "m_persona2",
+            "BVP - MARIA FERNANDA RUIZ VEGA - L4 (CRASH-ONLINE)",
+            "h_persona2",
+        ),
+        // Persona con segundo nombre extra en query
+        meeting(
+            "m_castillo",
+            "RICARDO DEL VALLE MORENO - KEYNOTES ADVANCED (ONLINE)",
+            "h_castillo",
+        ),
+    ]
+}
+
+/// meeting_id asignado para el programa (sin usuarios cargados)
+fn matched_id(program: &str) -> Option<String> {
+    matcher(&mock_meetings(), &[])
+        .find_match(&schedule(program, "Any"), MatchOptions::default())
+        .meeting_id
+}
+
+fn with_extra(extra: Vec<ZoomMeetingCandidate>) -> Vec<ZoomMeetingCandidate> {
+    let mut meetings = mock_meetings();
+    meetings.extend(extra);
+    meetings
+}
+
+#[test]
+fn matches_person_topics() {
+    let cases = [
+        ("Rivera Iriarte (Per)(ONLINE), Juan Alberto", "m1"),
+        ("Martinez Gomez (Per)(ONLINE), Ana Gabriela", "m2"),
+        ("Sanchez Torres (PER)(ONLINE), Daniel Alcides", "m3"),
+        ("López de los Rios (PER) (ONLINE), Vanessa Ofelia", "m4"),
+        ("Mendoza Vallejos (PER) (ONLINE), Carmen Fiorela", "m6"),
+    ];
+    for (program, expected) in cases {
+        assert_eq!(
+            matched_id(program).as_deref(),
+            Some(expected),
+            "{}",
+            program
+        );
+    }
+}
+
+#[test]
+fn rejects_unrelated_topics() {
+    let cases = [
+        "Canales de la Cruz (PRESENCIAL), Manuel",
+        "CAMACHO - TITAN (7 - 11) LOOK (SUMMER F2F)",
+        "SUNSHINE (4 - 6) LOOK & SEE (SUMMER F2F)",
+        "MOONBEAM L5 - PREMIUM (ONLINE)",
+        "TRIO TECHCORP L3 (ONLINE)",
+        "OCEAN SIDE (4 - 6) LOOK AND SEE 1 (F2F)",
+        "ECLIPSE (16 - 17) IMPACT 3 (PREMIUM - ONLINE)",
+    ];
+    for program in cases {
+        assert_eq!(matched_id(program), None, "{}", program);
+    }
+}
+
+#[test]
+fn weak_match_guardrail_rejects_distinct_company() {
+    let result = matcher(&mock_meetings(), &[]).find_match(
+        &schedule("TRIO NOVA L4 (NOVA)(PRESENCIAL-TRAVEL)", "Any"),
+        MatchOptions::default(),
+    );
+    assert_eq!(result.meeting_id, None);
+    assert_eq!(result.status, MatchStatus::NotFound);
+}
+
+#[test]
+fn critical_token_mismatch_is_not_found() {
+    for program in [
+        "DUO TECHCORP L4 (ONLINE)",
+        "TRIO GLOBALTECH N8 (PRESENCIAL-TRAVEL)",
+    ] {
+        let result = matcher(&mock_meetings(), &[])
+            .find_match(&schedule(program, "Any"), MatchOptions::default());
+        assert_eq!(result.meeting_id, None, "{}", program);
+        assert_eq!(result.status, MatchStatus::NotFound, "{}", program);
+    }
+}
+
+#[test]
+fn matches_duo_and_bvd_synonyms() {
+    assert_eq!(
+        matched_id("DUO SILVA - PEREZ L10 (ONLINE)").as_deref(),
+        Some("m11")
+    );
+    assert_eq!(
+        matched_id("DUO UNIQUE TOKEN (ONLINE)").as_deref(),
+        Some("m13")
+    );
+}
+
+#[test]
+fn level_and_group_conflicts_are_ambiguous() {
+    for program in ["CH ACME L3 (ONLINE)", "CH 1 ACME L2 (ONLINE)"] {
+        let result = matcher(&mock_meetings(), &[])
+            .find_match(&schedule(program, "Any"), MatchOptions::default());
+        assert_eq!(result.meeting_id, None, "{}", program);
+        assert_eq!(result.status, MatchStatus::Ambiguous, "{}", program);
+    }
+}
+
+#[test]
+fn company_query_does_not_match_unrelated_person() {
+    let meetings = with_extra(vec![meeting(
+        "company_false",
+        "CARLOS ANDRES RODRIGUEZ VEGA (ACME)(ONLINE) - ENG L3",
+        "h99",
+    )]);
+    let result = matcher(&meetings, &[]).find_match(
+        &schedule("GLOBEX CORP - ENG L3", "Any"),
+        MatchOptions::default(),
+    );
+    assert_eq!(result.meeting_id, None);
+}
+
+#[test]
+fn ignore_level_mismatch_matches_different_level() {
+    let meetings = with_extra(vec![meeting(
+        "l7_match",
+        "BVP - AIDA CALDERON - L7 (ONLINE)",
+        "h1",
+    )]);
+    let service = matcher(&meetings, &[]);
+
+    let relaxed = service.find_match_by_topic("BVP - AIDA CALDERON - L8 (ONLINE)", RELAXED);
+    assert_eq!(relaxed.status, MatchStatus::Assigned);
+    assert_eq!(relaxed.meeting_id.as_deref(), Some("l7_match"));
+    assert!(relaxed.score.unwrap() > 80);
+
+    // Modo estricto por defecto (Assign Link)
+    let strict =
+        service.find_match_by_topic("BVP - AIDA CALDERON - L8 (ONLINE)", MatchOptions::default());
+    assert_ne!(strict.status, MatchStatus::Assigned);
+    assert_eq!(strict.meeting_id, None);
+}
+
+#[test]
+fn relaxed_mode_matches_renamed_topics() {
+    let meetings = with_extra(vec![
+        meeting(
+            "luis_match",
+            "LUIS VELASQUEZ DEL AGUILA NIVELACION TEAM ZONE 1 (ONLINE)",
+            "h1",
+        ),
+        meeting("trio_match", "TRIO GRUPO A - L3 (TRAMARSA)(ONLINE)", "h1"),
+    ]);
+    let service = matcher(&meetings, &[]);
+
+    let luis = service.find_match_by_topic(
+        "BVP KIDS - LUIS VELASQUEZ DEL AGUILA - TIME ZONE 3 (ONLINE)",
+        RELAXED,
+    );
+    assert_eq!(luis.status, MatchStatus::Assigned);
+    assert_eq!(luis.meeting_id.as_deref(), Some("luis_match"));
+
+    let trio = service.find_match_by_topic("TRIO GRUPO A - L4 (TRAMARSA)(ONLINE)", RELAXED);
+    assert_eq!(trio.status, MatchStatus::Assigned);
+    assert_eq!(trio.meeting_id.as_deref(), Some("trio_match"));
+}
+
+#[test]
+fn duplicates_are_ambiguous() {
+    let meetings = with_extra(vec![
+        meeting(
+            "luis_1",
+            "LUIS VELASQUEZ DEL AGUILA NIVELACION TEAM ZONE 1 (ONLINE)",
+            "h1",
+        ),
+        meeting(
+            "luis_2",
+            "LUIS VELASQUEZ DEL AGUILA - OLD MEETING (ONLINE)",
+            "h1",
+        ),
+    ]);
+    let result = matcher(&meetings, &[]).find_match_by_topic(
+        "BVP KIDS - LUIS VELASQUEZ DEL AGUILA - TIME ZONE 3 (ONLINE)",
+        RELAXED,
+    );
+    assert_eq!(result.status, MatchStatus::Ambiguous);
+    assert!(result.ambiguous_candidates.is_some());
+    assert_eq!(
+        result.best_match.map(|m| m.meeting_id).as_deref(),
+        Some("luis_1")
+    );
+}
+
+#[test]
+fn name_mismatch_penalty_prefers_right_person() {
+    let meetings = with_extra(vec![
+        meeting(
+            "luis",
+            "LUIS VELASQUEZ DEL AGUILA NIVELACION TEAM ZONE 1 (ONLINE)",
+            "h1",
+        ),
+        meeting("diana", "BVP - DIANA DEL AGUILA - L5 ( BECA ONLINE)", "h1"),
+    ]);
+    let result = matcher(&meetings, &[]).find_match_by_topic(
+        "BVP KIDS - LUIS VELASQUEZ DEL AGUILA - TIME ZONE 3 (ONLINE)",
+        RELAXED,
+    );
+    assert_eq!(result.status, MatchStatus::Assigned);
+    assert_eq!(result.meeting_id.as_deref(), Some("luis"));
+}
+
+#[test]
+fn generic_workshop_query_is_not_assigned() {
+    let meetings = with_extra(vec![
+        meeting("w1", "[WORKSHOP] Club Intermedio + Avanzado", "h1"),
+        meeting("w2", "Workshop/Training", "h1"),
+        meeting("w3", "[WORKSHOP] Club Basic", "h1"),
+        meeting("w4", "WORKSHOP UPER INTERMEDIO (AFP INTEGRA)", "h1"),
+    ]);
+    let result = matcher(&meetings, &[]).find_match_by_topic("WORKSHOP", RELAXED);
+    assert!(matches!(
+        result.status,
+        MatchStatus::Ambiguous | MatchStatus::NotFound
+    ));
+
+    let single = with_extra(vec![meeting("w2", "Workshop/Training", "h1")]);
+    let result = matcher(&single, &[]).find_match_by_topic("WORKSHOP", RELAXED);
+    assert_ne!(result.status, MatchStatus::Assigned);
+
+    let exact = with_extra(vec![meeting("w_exact", "WORKSHOP", "h1")]);
+    let result = matcher(&exact, &[]).find_match_by_topic("WORKSHOP", RELAXED);
+    assert_eq!(result.status, MatchStatus::Assigned);
+    assert!(result.score.unwrap() > 90);
+}
+
+// ========== CASOS DE AMBIGÜEDAD ==========
+
+fn ambiguous_meetings() -> Vec<ZoomMeetingCandidate> {
+    vec![
+        // Familia CH ACME
+        meeting("a1", "CH 3 ACME L2 (ONLINE)", "h1"),
+        meeting("a2", "CH 2 ACME L5 (ONLINE)", "h2"),
+        meeting("a3", "CH 3 ACME L3 (ONLINE)", "h3"),
+        meeting("a4", "CH ACME L1 (ONLINE)", "h4"),
+        meeting("a5", "CH ACME L6 (ONLINE)", "h5"),
+        meeting("a9", "CH 1 ACME L2 (ONLINE)", "h9"),
+        meeting("a10", "CH 2 ACME L2 (ONLINE)", "h10"),
+        // Familia TECHCORP
+        meeting("a6", "TRIO TECHCORP L4 (ONLINE)", "h6"),
+        meeting("a7", "DUO TECHCORP L4 (ONLINE)", "h7"),
+        meeting("a8", "TRIO TECHCORP L2 (ONLINE)", "h8"),
+        meeting(
+            "m_persona1",
+            "BVP - MARIA TORRES FLORES - L1 (ONLINE)",
+            "h_persona1",
+        ),
+        meeting(
+            "m_persona2",
+            "BVP - MARIA FERNANDA RUIZ VEGA - L4 (CRASH-ONLINE)",
+            "h_persona2",
+        ),
+    ]
+}
+
+#[test]
+fn underspecified_queries_are_ambiguous() {
+    let service = matcher(&ambiguous_meetings(), &[]);
+    for program in ["CH ACME (ONLINE)", "TECHCORP L4 (ONLINE)"] {
+        let result = service.find_match(&schedule(program, "Any"), MatchOptions::default());
+        assert_eq!(result.status, MatchStatus::Ambiguous, "{}", program);
+        assert!(
+            result.ambiguous_candidates.unwrap().len() >= 2,
+            "{}",
+            program
+        );
+    }
+
+    let result = service.find_match(
+        &schedule("CH ACME L2 (ONLINE)", "Any"),
+        MatchOptions::default(),
+    );
+    assert_eq!(result.status, MatchStatus::Ambiguous);
+}
+
+#[test]
+fn specific_queries_match_exactly() {
+    let service = matcher(&ambiguous_meetings(), &[]);
+
+    let duo = service.find_match(
+        &schedule("DUO TECHCORP L4 (ONLINE)", "Any"),
+        MatchOptions::default(),
+    );
+    assert_eq!(duo.meeting_id.as_deref(), Some("a7"));
+    assert_ne!(duo.status, MatchStatus::Ambiguous);
+
+    let person = service.find_match(
+        &schedule("Torres Flores (PER)(ONLINE), Maria Fernanda", "Any"),
+        MatchOptions::default(),
+    );
+    assert_eq!(person.meeting_id.as_deref(), Some("m_persona1"));
+    assert_eq!(person.status, MatchStatus::Assigned);
+
+    // Segundo nombre extra: si se encuentra candidato, debe ser el correcto
+    let middle_name = service.find_match(
+        &schedule("Del Valle Moreno (Per)(Online), Ricardo David", "Any"),
+        MatchOptions::default(),
+    );
+    if let Some(id) = middle_name.meeting_id {
+        assert_eq!(id, "m_castillo");
+        assert_eq!(middle_name.status, MatchStatus::Assigned);
+    }
+}
+
+// ========== QUERIES CON PREFIJO BVP ==========
+
+#[test]
+fn bvp_prefix_queries_match_plain_topics() {
+    let meetings = vec![
+        meeting("bvp1", "HECTOR RAFAEL MAIDANA - L5 (ONLINE)", "h1"),
+        meeting("bvp2", "AIDA CALDERON - L7 (ONLINE)", "h2"),
+        meeting("bvp3", "GUIDO MORENO - L1 (HIBRIDO)", "h3"),
+    ];
+    let service = matcher(&meetings, &[]);
+    let cases = [
+        ("BVP - HECTOR RAFAEL MAIDANA - L5 (ONLINE)", "bvp1"),
+        ("BVP - AIDA CALDERON - L7 (ONLINE)", "bvp2"),
+        ("BVP - GUIDO MORENO - L1 (HIBRIDO)", "bvp3"),
+    ];
+    for (program, expected) in cases {
+        let result = service.find_match(&schedule(program, "Any"), MatchOptions::default());
+        assert_eq!(result.meeting_id.as_deref(), Some(expected), "{}", program);
+    }
+}
+
+// ========== COMPAÑÍA VS PERSONA ==========
+
+#[test]
+fn different_companies_are_not_found() {
+    let hayduk = meeting(
+        "hayduk1",
+        "LUIS ENRIQUE GONZALEZ ESPEJO (HAYDUK)(ONLINE) - ENG L3",
+        "h1",
+    );
+    let result = matcher(&[hayduk], &[])
+        .find_match_by_topic("SCOTIABANK O - ENG L3", MatchOptions::default());
+    assert_eq!(result.status, MatchStatus::NotFound);
+}
+
+#[test]
+fn person_names_are_not_company_conflicts() {
+    let repsol = meeting("repsol1", "JUAN ESPINOZA (REPSOL) - ENG L3", "h1");
+    let (_, penalties) = score("ESPINOZA", &repsol);
+    assert!(penalties.iter().all(|p| p.name != "COMPANY_CONFLICT"));
+
+    // "Mejía" no debe tokenizarse como "MEJ" y chocar con "CRASH"
+    let crash = meeting("crash1", "BVP - MAYRA MEJIA ORA - L2 (CRASH-ONLINE)", "h1");
+    let (_, penalties) = score("Mejía Ora (PER)(ONLINE), Mayra Kasandra", &crash);
+    assert!(penalties.iter().all(|p| p.name != "COMPANY_CONFLICT"));
+}
@@ -0,0 +1,64 @@
+mod common;
+
+use common::{matcher, meeting, schedule, user};
+use minerva_lib::matching::{MatchOptions, MatchStatus};
+
+#[test]
+fn assigns_schedules_with_host() {
+    let users = [
+        user("u1", "Steve", "Miller", "Steve Miller"),
+        user("u2", "Diana", "Cooper", "Diana Cooper"),
+    ];
+    let meetings = [
+        meeting("m1", "BVP - JUAN ALBERTO RIVERA - L9 (ONLINE)", "u1"),
+        meeting("m2", "BVP - ANA MARTINEZ GOMEZ - L7 (ONLINE)", "u1"),
+        meeting(
+            "m3",
+            "BVP - DANIEL SANCHEZ TORRES - TRUE BEGINNER (ONLINE)",
+            "u2",
+        ),
+        meeting("m4", "VANESSA LOPEZ DE LOS RIOS - L5 (ONLINE)", "u2"),
+    ];
+    let service = matcher(&meetings, &users);
+
+    let cases = [
+        (
+            "Rivera Iriarte (Per)(ONLINE), Juan Alberto",
+            "Steve Miller",
+            "m1",
+            "u1",
+        ),
+        (
+            "Martinez Gomez (Per)(ONLINE), Ana Gabriela",
+            "Steve Miller",
+            "m2",
+            "u1",
+        ),
+        (
+            "Sanchez Torres (PER)(ONLINE), Daniel Alcides",
+            "Diana Cooper",
+            "m3",
+            "u2",
+        ),
+        (
+            "López de los Rios (PER) (ONLINE), Vanessa Ofelia",
+            "Diana Cooper",
+            "m4",
+            "u2",
+        ),
+    ];
+    for (program, instructor, meeting_id, user_id) in cases {
+        let result = service.find_match(&schedule(program, instructor), MatchOptions::default());
+        assert_eq!(
+            result.meeting_id.as_deref(),
+            Some(meeting_id),
+            "{}",
+            program
+        );
+        assert_eq!(
+            result.found_instructor.map(|i| i.id).as_deref(),
+            Some(user_id)
+        );
+        assert_eq!(result.status, MatchStatus::Assigned, "{}", program);
+    }
+}
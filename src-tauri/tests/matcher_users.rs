@@ -0,0 +1,66 @@
+mod common;
+
+use common::{matcher, meeting, schedule, user};
+use minerva_lib::matching::{MatchOptions, ZoomUserCandidate};
+
+fn mock_users() -> Vec<ZoomUserCandidate> {
+    vec![
+        user("u1", "Laura Maria", "Torres Mendez", "Laura Torres Mendez"),
+        user("u2", "Carlos", "Ramos", "Carlos Angel"),
+        user(
+            "u3",
+            "Pablo Luis",
+            "Vargas Chen",
+            "Pablo Luis Vargas Chen 陳",
+        ),
+        user("u4", "Sofia", "Morales", "Sofia Morales"),
+    ]
+}
+
+fn found_instructor(users: &[ZoomUserCandidate], instructor: &str) -> Option<String> {
+    let meetings = [meeting("dummy", "Any Program", "h0")];
+    matcher(&meetings, users)
+        .find_match(
+            &schedule("Any Program", instructor),
+            MatchOptions::default(),
+        )
+        .found_instructor
+        .map(|i| i.id)
+}
+
+#[test]
+fn matches_instructors() {
+    let cases = [
+        ("Laura Maria Torres", "u1"),
+        ("CARLOS ANGEL RAMOS SILVA", "u2"),
+        ("Pablo Luis Vargas", "u3"),
+        ("SOFIA DEL CARMEN MORALES VEGA", "u4"),
+    ];
+    for (instructor, expected) in cases {
+        assert_eq!(
+            found_instructor(&mock_users(), instructor).as_deref(),
+            Some(expected),
+            "{}",
+            instructor
+        );
+    }
+}
+
+#[test]
+fn shared_first_or_last_name_is_not_enough() {
+    for instructor in [
+        "Juan Garcia Lopez",
+        "Eduardo Antonio Ramos",
+        "Sofia Patricia Rodriguez",
+    ] {
+        assert_eq!(
+            found_instructor(&mock_users(), instructor),
+            None,
+            "{}",
+            instructor
+        );
+    }
+
+    let fiorela = [user("fg1", "Fiorela", "Garcia", "Fiorela Garcia")];
+    assert_eq!(found_instructor(&fiorela, "Ana Garcia"), None);
+}
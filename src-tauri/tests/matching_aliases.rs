@@ -0,0 +1,178 @@
+mod common;
+
+use common::{matcher, meeting, schedule, user};
+use minerva_lib::matching::aliases::{load, save, AliasTable, AliasTarget};
+use minerva_lib::matching::dictionary::UserDictionary;
+use minerva_lib::matching::index::Retrieval;
+use minerva_lib::matching::{MatchOptions, MatchStatus, MatchingRules};
+
+const TRACED: MatchOptions = MatchOptions {
+    ignore_level_mismatch: false,
+    trace: true,
+};
+
+fn learned(program: &str, instructor: &str, target: AliasTarget) -> AliasTable {
+    let mut table = AliasTable::default();
+    table.learn(
+        &MatchingRules::bundled(),
+        program,
+        instructor,
+        target,
+        1_000,
+    );
+    table
+}
+
+#[test]
+fn alias_resolves_ambiguous_row() {
+    let service = matcher(
+        &[
+            meeting("m1", "CH 1 ACME L2 (ONLINE)", "h1"),
+            meeting("m2", "CH 3 ACME L2 (ONLINE)", "h2"),
+        ],
+        &[user("h2", "Ana", "Gomez", "Ana Gomez")],
+    );
+    let row = schedule("CH ACME L2 (ONLINE)", "Ana Gomez");
+    assert_eq!(
+        service.find_match(&row, MatchOptions::default()).status,
+        MatchStatus::Ambiguous
+    );
+
+    let aliases = learned(
+        "CH ACME L2 (ONLINE)",
+        "Ana Gomez",
+        AliasTarget::MeetingId("m2".to_string()),
+    );
+    let outcome = service.find_match_with_aliases(&row, TRACED, &aliases, 2_000);
+    assert_eq!(outcome.status, MatchStatus::Assigned);
+    assert_eq!(outcome.meeting_id.as_deref(), Some("m2"));
+    assert_eq!(outcome.reason, "Learned alias");
+    assert_eq!(
+        outcome.trace.unwrap().candidates[0].retrieval,
+        Retrieval::Alias
+    );
+
+    // Otro instructor no usa el alias
+    let other = schedule("CH ACME L2 (ONLINE)", "Luis Vega");
+    assert_ne!(
+        service
+            .find_match_with_aliases(&other, MatchOptions::default(), &aliases, 2_000)
+            .status,
+        MatchStatus::Assigned
+    );
+}
+
+#[test]
+fn expired_or_dangling_alias_falls_back_to_search() {
+    let service = matcher(
+        &[
+            meeting("m1", "CH 1 ACME L2 (ONLINE)", "h1"),
+            meeting("m2", "CH 3 ACME L2 (ONLINE)", "h2"),
+        ],
+        &[],
+    );
+    let row = schedule("CH ACME L2 (ONLINE)", "Ana Gomez");
+
+    // Alias genérico (sin instructor) por topic
+    let mut aliases = learned(
+        "CH ACME L2 (ONLINE)",
+        "",
+        AliasTarget::Topic("CH 1 ACME L2 (ONLINE)".to_string()),
+    );
+    let outcome = service.find_match_with_aliases(&row, MatchOptions::default(), &aliases, 2_000);
+    assert_eq!(outcome.meeting_id.as_deref(), Some("m1"));
+
+    let id = aliases.aliases()[0].id;
+    aliases.expire(id, Some(1_500), 1_200);
+    let outcome = service.find_match_with_aliases(&row, MatchOptions::default(), &aliases, 2_000);
+    assert_eq!(outcome.status, MatchStatus::Ambiguous);
+
+    // Meeting eliminado de Zoom: se ignora el alias
+    let aliases = learned(
+        "CH ACME L2 (ONLINE)",
+        "",
+        AliasTarget::MeetingId("gone".to_string()),
+    );
+    let outcome = service.find_match_with_aliases(&row, MatchOptions::default(), &aliases, 2_000);
+    assert_eq!(outcome.status, MatchStatus::Ambiguous);
+}
+
+#[test]
+fn learn_replaces_and_table_round_trips() {
+    let mut table = learned("APP L1", "Ana", AliasTarget::MeetingId("m1".to_string()));
+    let again = table.learn(
+        &MatchingRules::bundled(),
+        "app l1",
+        "ana",
+        AliasTarget::MeetingId("m2".to_string()),
+        5_000,
+    );
+    assert_eq!(table.aliases().len(), 1);
+    assert_eq!(again.alias.target, AliasTarget::MeetingId("m2".to_string()));
+    assert_eq!(again.alias.created_at, 1_000);
+    // El alias reemplazado vuelve para poder deshacer la selección
+    let previous = again.previous.unwrap();
+    assert_eq!(previous.target, AliasTarget::MeetingId("m1".to_string()));
+    assert_eq!(previous.updated_at, 1_000);
+
+    let path = std::env::temp_dir().join(format!("minerva-aliases-{}.json", std::process::id()));
+    save(&table, &path).unwrap();
+    assert_eq!(load(&path).unwrap(), table);
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(table.forget("app l1", "ana"));
+    assert!(table.aliases().is_empty());
+}
+
+#[test]
+fn dictionary_change_rekeys_learned_aliases() {
+    let bundled = MatchingRules::bundled();
+    let mut table = learned(
+        "3 PAX ACME L2 (ONLINE)",
+        "Ana Gomez",
+        AliasTarget::MeetingId("m2".to_string()),
+    );
+    table.learn(
+        &bundled,
+        "TRIO ACME L2 (ONLINE)",
+        "Ana Gomez",
+        AliasTarget::MeetingId("m3".to_string()),
+        2_000,
+    );
+    assert_eq!(table.aliases().len(), 2);
+    assert!(!table.rekey(&bundled));
+
+    // Con "trio" = "3 pax" ambos alias quedan con la misma clave: gana el más reciente
+    let rules = bundled
+        .with_dictionary(&UserDictionary {
+            synonyms: vec![vec!["trio".to_string(), "3 pax".to_string()]],
+            ..Default::default()
+        })
+        .unwrap();
+    assert!(table.rekey(&rules));
+    assert_eq!(table.aliases().len(), 1);
+    let alias = table
+        .lookup(
+            &rules.normalize("3 PAX ACME L2 (ONLINE)"),
+            &rules.normalize("Ana Gomez"),
+            3_000,
+        )
+        .unwrap();
+    assert_eq!(alias.target, AliasTarget::MeetingId("m3".to_string()));
+
+    // Alias guardado sin el texto original: se vuelve a normalizar su clave
+    let mut legacy: AliasTable = serde_json::from_value(serde_json::json!({
+        "nextId": 1,
+        "aliases": [{
+            "id": 1,
+            "program": "ch acme l2",
+            "instructor": "",
+            "target": { "meetingId": "m1" },
+            "createdAt": 1000,
+            "updatedAt": 1000
+        }]
+    }))
+    .unwrap();
+    legacy.rekey(&rules);
+    assert_eq!(legacy.aliases()[0].program, rules.normalize("ch acme l2"));
+}
@@ -0,0 +1,132 @@
+mod common;
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use common::{matcher, meeting, schedule, user};
+use minerva_lib::matching::aliases::AliasTable;
+use minerva_lib::matching::{match_rows, MatchOptions, MatchStatus, MatchingService};
+use minerva_lib::schedule::Schedule;
+
+fn fixture() -> (MatchingService, Vec<Schedule>) {
+    let service = matcher(
+        &[
+            meeting("m1", "TRIO TECHCORP L4 (ONLINE)", "h1"),
+            meeting("m2", "CH 1 ACME L2 (ONLINE)", "h2"),
+            meeting("m3", "CH 3 ACME L2 (ONLINE)", "h2"),
+            meeting("m4", "DUO GLOBEX L1 (PRESENCIAL)", "h1"),
+        ],
+        &[
+            user("h1", "Juan", "Perez", "Juan Perez"),
+            user("h2", "Ana", "Lopez", "Ana Lopez"),
+        ],
+    );
+    let programs = [
+        "TRIO TECHCORP L4 (ONLINE)",
+        "CH ACME L2 (ONLINE)",
+        "DUO GLOBEX L1 (PRESENCIAL)",
+        "INITECH L9",
+    ];
+    let schedules = (0..250)
+        .map(|i| schedule(programs[i % programs.len()], "Juan Perez"))
+        .collect();
+    (service, schedules)
+}
+
+#[test]
+fn batch_matches_sequential_results_in_order() {
+    let (service, schedules) = fixture();
+    let progress = Mutex::new(Vec::new());
+
+    let outcomes = service
+        .match_batch(
+            &schedules,
+            MatchOptions::default(),
+            &AliasTable::default(),
+            0,
+            &AtomicBool::new(false),
+            |done, total| progress.lock().unwrap().push((done, total)),
+        )
+        .unwrap();
+
+    let sequential: Vec<_> = schedules
+        .iter()
+        .map(|s| service.find_match(s, MatchOptions::default()))
+        .collect();
+    assert_eq!(outcomes, sequential);
+
+    // 250 horarios: un evento cada 2 completados, el último con done == total
+    let progress = progress.into_inner().unwrap();
+    assert_eq!(progress.len(), 125);
+    assert!(progress.iter().all(|&(_, total)| total == 250));
+    assert_eq!(progress.iter().map(|&(done, _)| done).max(), Some(250));
+}
+
+#[test]
+fn cancelled_batch_returns_none() {
+    let (service, schedules) = fixture();
+    let cancel = AtomicBool::new(false);
+    let processed = AtomicUsize::new(0);
+
+    let outcomes = service.match_batch(
+        &schedules,
+        MatchOptions::default(),
+        &AliasTable::default(),
+        0,
+        &cancel,
+        |_, _| {
+            processed.fetch_add(1, Ordering::Relaxed);
+            cancel.store(true, Ordering::Relaxed);
+        },
+    );
+
+    assert!(outcomes.is_none());
+    assert!(processed.load(Ordering::Relaxed) < 125);
+}
+
+#[test]
+fn empty_batch_reports_no_progress() {
+    let (service, _) = fixture();
+    let outcomes = service.match_batch(
+        &[],
+        MatchOptions::default(),
+        &AliasTable::default(),
+        0,
+        &AtomicBool::new(false),
+        |_, _| panic!("no progress expected"),
+    );
+    assert_eq!(outcomes, Some(Vec::new()));
+}
+
+#[test]
+fn invalid_rows_do_not_stop_the_batch() {
+    let (service, schedules) = fixture();
+    let mut rows: Vec<_> = schedules[..4]
+        .iter()
+        .map(|s| serde_json::to_value(s).unwrap())
+        .collect();
+    rows[1]["start_time"] = "25:00".into();
+
+    let outcomes = match_rows(
+        &service,
+        rows,
+        MatchOptions::default(),
+        &AliasTable::default(),
+        0,
+        &AtomicBool::new(false),
+        |_, _| {},
+    )
+    .unwrap();
+
+    assert_eq!(outcomes.len(), 4);
+    assert_eq!(outcomes[1].status, MatchStatus::NotFound);
+    assert_eq!(outcomes[1].reason, "Invalid row");
+    assert!(outcomes[1]
+        .detailed_reason
+        .as_deref()
+        .is_some_and(|r| r.contains("start_time")));
+    for i in [0, 2, 3] {
+        let expected = service.find_match(&schedules[i], MatchOptions::default());
+        assert_eq!(outcomes[i], expected, "row {i}");
+    }
+}
@@ -0,0 +1,59 @@
+use minerva_lib::matching::config::ConfigIssue;
+use minerva_lib::matching::loader::{bundled_config, load_rules, validate, validate_override};
+use serde_json::json;
+
+fn pointers(issues: &[ConfigIssue]) -> Vec<&str> {
+    issues.iter().map(|i| i.pointer.as_str()).collect()
+}
+
+#[test]
+fn bundled_config_matches_schema() {
+    assert!(validate(&bundled_config()).is_ok());
+}
+
+#[test]
+fn override_replaces_only_given_values() {
+    let rules = validate_override(json!({
+        "scoring": { "penalties": { "WEAK_MATCH": -70 } }
+    }))
+    .unwrap();
+    assert_eq!(rules.penalties().weak_match, -70);
+    assert_eq!(rules.penalties().level_conflict, -100);
+}
+
+#[test]
+fn reports_schema_errors_with_pointer() {
+    let issues = validate_override(json!({
+        "scoring": {
+            "penalties": { "WEAK_MATCH": "-70", "WEEK_MATCH": -70 },
+            "thresholds": { "MINIMUM": null }
+        }
+    }))
+    .unwrap_err();
+
+    let pointers = pointers(&issues);
+    assert!(pointers.contains(&"/scoring/penalties/WEAK_MATCH"));
+    assert!(pointers.contains(&"/scoring/penalties"));
+    assert!(pointers.contains(&"/scoring/thresholds/MINIMUM"));
+}
+
+#[test]
+fn reports_type_and_regex_errors_with_pointer() {
+    let issues = validate_override(json!({
+        "scoring": { "penalties": { "WEAK_MATCH": -12.5 } }
+    }))
+    .unwrap_err();
+    assert_eq!(pointers(&issues), ["/scoring/penalties/WEAK_MATCH"]);
+
+    let issues = validate_override(json!({
+        "personDetection": { "patterns": ["^ok$", "(unclosed"] }
+    }))
+    .unwrap_err();
+    assert_eq!(pointers(&issues), ["/personDetection/patterns/1"]);
+}
+
+#[test]
+fn missing_override_uses_bundled_config() {
+    let rules = load_rules(&std::env::temp_dir().join("minerva-missing-override.json")).unwrap();
+    assert_eq!(rules.config, validate(&bundled_config()).unwrap().config);
+}
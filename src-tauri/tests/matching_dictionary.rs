@@ -0,0 +1,129 @@
+mod common;
+
+use common::{meeting, schedule};
+use minerva_lib::matching::dictionary::{load, save, UserDictionary};
+use minerva_lib::matching::{MatchOptions, MatchStatus, MatchingRules, MatchingService};
+use std::sync::Arc;
+
+fn dictionary() -> UserDictionary {
+    UserDictionary {
+        version: 4,
+        irrelevant_words: vec!["Sede Norte".to_string()],
+        synonyms: vec![vec!["trio".to_string(), "3 pax".to_string()]],
+        structural: vec!["Quad".to_string()],
+        ..Default::default()
+    }
+}
+
+fn rules() -> MatchingRules {
+    MatchingRules::bundled()
+        .with_dictionary(&dictionary())
+        .unwrap()
+}
+
+#[test]
+fn dictionary_extends_normalization() {
+    let rules = rules();
+    assert_eq!(rules.normalize("3 PAX TECHCORP L4"), "trio techcorp l4");
+    assert_eq!(rules.normalize("TECHCORP SEDE NORTE L4"), "techcorp l4");
+    // Las listas incluidas se conservan
+    assert_eq!(
+        rules.normalize("TECHCORP L4 (ONLINE)"),
+        MatchingRules::bundled().normalize("TECHCORP L4 (ONLINE)")
+    );
+    assert_eq!(rules.dictionary_version, 4);
+    assert_eq!(MatchingRules::bundled().dictionary_version, 0);
+}
+
+#[test]
+fn results_record_dictionary_version() {
+    let service = MatchingService::new(
+        vec![meeting("m1", "TRIO TECHCORP L4 (ONLINE)", "h1")],
+        Vec::new(),
+        Arc::new(rules()),
+    );
+    let outcome = service.find_match(
+        &schedule("3 PAX TECHCORP L4 (ONLINE)", "Juan Perez"),
+        MatchOptions::default(),
+    );
+    assert_eq!(outcome.status, MatchStatus::Assigned);
+    assert_eq!(outcome.meeting_id.as_deref(), Some("m1"));
+    assert_eq!(outcome.dictionary_version, 4);
+}
+
+#[test]
+fn reindexing_applies_new_rules_to_the_same_meetings() {
+    let bundled = MatchingService::new(
+        vec![meeting("m1", "TRIO TECHCORP L4 (ONLINE)", "h1")],
+        Vec::new(),
+        MatchingRules::bundled(),
+    );
+    let row = schedule("3 PAX TECHCORP L4 (ONLINE)", "Juan Perez");
+    let before = bundled.find_match(&row, MatchOptions::default());
+    assert_eq!(before.dictionary_version, 0);
+
+    let rules = Arc::new(rules());
+    let service = bundled.with_rules(rules.clone());
+    assert!(service.uses_rules(&rules));
+    assert!(!bundled.uses_rules(&rules));
+    let after = service.find_match(&row, MatchOptions::default());
+    assert_eq!(after.status, MatchStatus::Assigned);
+    assert_eq!(after.meeting_id.as_deref(), Some("m1"));
+    assert_eq!(after.dictionary_version, 4);
+}
+
+#[test]
+fn user_structural_tokens_are_required() {
+    let service = MatchingService::new(
+        vec![meeting("m1", "TECHCORP L4 (ONLINE)", "h1")],
+        Vec::new(),
+        Arc::new(rules()),
+    );
+    let outcome = service.find_match(
+        &schedule("QUAD TECHCORP L4 (ONLINE)", "Juan Perez"),
+        MatchOptions {
+            trace: true,
+            ..Default::default()
+        },
+    );
+    let trace = outcome.trace.unwrap();
+    assert!(trace.candidates[0]
+        .penalties
+        .iter()
+        .any(|p| p.name == "STRUCTURAL_TOKEN_MISSING"));
+}
+
+#[test]
+fn invalid_entries_are_reported_with_pointer() {
+    let invalid = UserDictionary {
+        irrelevant_words: vec![" ".to_string()],
+        synonyms: vec![
+            vec!["trio".to_string()],
+            vec!["duo".to_string(), "--".to_string()],
+        ],
+        structural: vec!["two words".to_string()],
+        ..Default::default()
+    };
+    let issues = MatchingRules::bundled()
+        .with_dictionary(&invalid)
+        .unwrap_err();
+    let pointers: Vec<&str> = issues.iter().map(|i| i.pointer.as_str()).collect();
+    assert_eq!(
+        pointers,
+        [
+            "/irrelevantWords/0",
+            "/synonyms/0",
+            "/synonyms/1/1",
+            "/structural/0"
+        ]
+    );
+}
+
+#[test]
+fn dictionary_round_trips() {
+    let path = std::env::temp_dir().join(format!("minerva-dictionary-{}.json", std::process::id()));
+    assert_eq!(load(&path).unwrap(), UserDictionary::default());
+    save(&dictionary(), &path).unwrap();
+    assert_eq!(load(&path).unwrap(), dictionary());
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use common::schedule;
+use minerva_lib::schedule::overlap::detect_overlaps;
+use minerva_lib::schedule::Schedule;
+
+fn class(instructor: &str, program: &str, start: &str, end: &str) -> Schedule {
+    Schedule {
+        start_time: start.parse().unwrap(),
+        end_time: end.parse().unwrap(),
+        ..schedule(program, instructor)
+    }
+}
+
+/// (fila, filas con conflicto de tiempo, filas con la clase duplicada)
+fn conflicts(schedules: &[Schedule]) -> Vec<(usize, Vec<usize>, Vec<usize>)> {
+    detect_overlaps(schedules)
+        .rows
+        .into_iter()
+        .map(|r| (r.row, r.time_conflict_with, r.duplicate_class_with))
+        .collect()
+}
+
+#[test]
+fn sweep_finds_nested_and_chained_overlaps_but_not_adjacent_classes() {
+    let schedules = [
+        class("DOE", "A", "09:00", "12:00"),
+        class("DOE", "B", "10:00", "10:30"), // Dentro de la fila 0
+        class("DOE", "C", "11:45", "13:00"), // Cruza el final de la fila 0
+        class("DOE", "D", "13:00", "14:00"), // Empieza justo cuando termina la fila 2
+        class("ROE", "E", "09:00", "12:00"), // Otro instructor
+    ];
+
+    assert_eq!(
+        conflicts(&schedules),
+        [
+            (0, vec![1, 2], vec![]),
+            (1, vec![0], vec![]),
+            (2, vec![0], vec![]),
+        ]
+    );
+}
+
+#[test]
+fn zero_length_intervals_only_conflict_when_strictly_inside_another_class() {
+    let schedules = [
+        class("DOE", "A", "09:00", "10:00"),
+        class("DOE", "B", "09:30", "09:30"), // Dentro de la fila 0
+        class("DOE", "C", "10:00", "10:00"), // En el borde de la fila 0
+        class("DOE", "D", "10:00", "10:00"), // Igual a la fila 2
+        class("DOE", "E", "11:00", "10:30"), // Fin antes del inicio
+    ];
+
+    assert_eq!(
+        conflicts(&schedules),
+        [(0, vec![1], vec![]), (1, vec![0], vec![])]
+    );
+}
+
+#[test]
+fn same_class_with_different_instructors_is_a_duplicate() {
+    let schedules = [
+        class("DOE", "A", "09:00", "10:00"),
+        class("ROE", "A", "09:00", "10:00"),
+        class("DOE", "A", "09:00", "10:00"), // Mismo instructor: conflicto de tiempo
+    ];
+
+    let report = detect_overlaps(&schedules);
+    assert_eq!(
+        conflicts(&schedules),
+        [
+            (0, vec![2], vec![1]),
+            (1, vec![], vec![0, 2]),
+            (2, vec![0], vec![1]),
+        ]
+    );
+    // Las filas 0 y 2 comparten key
+    assert_eq!(report.overlap_count, 2);
+    assert_eq!(report.duplicate_classes.len(), 2);
+}
@@ -0,0 +1,170 @@
+mod common;
+
+use common::{meeting, with_context};
+use minerva_lib::matching::penalties::{
+    company_conflict, critical_token_mismatch, group_number_conflict, level_conflict,
+    numeric_conflict, orphan_level_with_siblings, orphan_number_with_siblings, program_vs_person,
+    structural_token_missing, weak_match, PenaltyFunction,
+};
+use minerva_lib::matching::{MatchOptions, ZoomMeetingCandidate};
+
+const RELAXED: MatchOptions = MatchOptions {
+    ignore_level_mismatch: true,
+    trace: false,
+};
+
+/// Nombre de la penalización aplicada por `rule` (None si no aplica)
+fn apply(
+    rule: PenaltyFunction,
+    program: &str,
+    topic: &str,
+    options: MatchOptions,
+) -> Option<String> {
+    let candidate = meeting("m1", topic, "h1");
+    with_context(
+        program,
+        &candidate,
+        std::slice::from_ref(&candidate),
+        options,
+        |ctx| rule(ctx).map(|p| p.name),
+    )
+}
+
+fn apply_with_siblings(
+    rule: PenaltyFunction,
+    program: &str,
+    candidates: &[ZoomMeetingCandidate],
+) -> Option<String> {
+    with_context(
+        program,
+        &candidates[0],
+        candidates,
+        MatchOptions::default(),
+        |ctx| rule(ctx).map(|p| p.name),
+    )
+}
+
+fn strict(rule: PenaltyFunction, program: &str, topic: &str) -> Option<String> {
+    apply(rule, program, topic, MatchOptions::default())
+}
+
+#[test]
+fn critical_token_mismatch_detects_exclusive_program_types() {
+    assert_eq!(
+        strict(critical_token_mismatch, "TRIO APP", "DUO APP").as_deref(),
+        Some("CRITICAL_TOKEN_MISMATCH")
+    );
+    assert_eq!(
+        strict(critical_token_mismatch, "TRIO APP", "TRIO APP"),
+        None
+    );
+    assert_eq!(strict(critical_token_mismatch, "APP L1", "APP L1"), None);
+}
+
+#[test]
+fn level_conflict_detects_different_levels() {
+    assert_eq!(
+        strict(level_conflict, "APP L2", "APP L3").as_deref(),
+        Some("LEVEL_CONFLICT")
+    );
+    assert_eq!(
+        apply(level_conflict, "APP L2", "APP L3", RELAXED).as_deref(),
+        Some("LEVEL_MISMATCH_IGNORED")
+    );
+    assert_eq!(strict(level_conflict, "APP L2", "APP L2"), None);
+}
+
+#[test]
+fn company_conflict_compares_companies_in_parentheses() {
+    assert_eq!(
+        strict(company_conflict, "SCOTIABANK APP", "APP (HAYDUK)").as_deref(),
+        Some("COMPANY_CONFLICT")
+    );
+    assert_eq!(
+        strict(company_conflict, "SCOTIABANK APP", "APP (SCOTIABANK)"),
+        None
+    );
+    // "ESPINOZA" es parte del nombre de la persona, no una compañía
+    assert_eq!(
+        strict(company_conflict, "ESPINOZA", "JUAN ESPINOZA (OTHER)"),
+        None
+    );
+}
+
+#[test]
+fn program_vs_person_detects_person_topics() {
+    assert_eq!(
+        strict(
+            program_vs_person,
+            "TRIO APP",
+            "JUAN GARCIA LOPEZ - KEYNOTES (ONLINE)"
+        )
+        .as_deref(),
+        Some("PROGRAM_VS_PERSON")
+    );
+    assert_eq!(
+        strict(program_vs_person, "TRIO APP", "TRIO JUAN PEREZ (ONLINE)"),
+        None
+    );
+}
+
+#[test]
+fn structural_token_missing_is_skipped_in_relaxed_mode() {
+    assert_eq!(
+        strict(structural_token_missing, "TRIO APP", "APP ONLY").as_deref(),
+        Some("STRUCTURAL_TOKEN_MISSING")
+    );
+    assert_eq!(
+        apply(structural_token_missing, "TRIO APP", "APP ONLY", RELAXED),
+        None
+    );
+}
+
+#[test]
+fn number_conflicts() {
+    assert_eq!(
+        strict(group_number_conflict, "CH 1 APP", "CH 2 APP").as_deref(),
+        Some("GROUP_NUMBER_CONFLICT")
+    );
+    assert_eq!(strict(group_number_conflict, "CH 1 APP", "CH 1 APP"), None);
+    assert_eq!(
+        strict(numeric_conflict, "APP 123", "APP 456").as_deref(),
+        Some("NUMERIC_CONFLICT")
+    );
+}
+
+#[test]
+fn orphan_penalties_require_siblings() {
+    let main = meeting("m1", "APP 1", "h1");
+    let sibling = meeting("m2", "APP 2", "h1");
+    assert_eq!(
+        apply_with_siblings(orphan_number_with_siblings, "APP", &[main.clone(), sibling])
+            .as_deref(),
+        Some("ORPHAN_NUMBER_WITH_SIBLINGS")
+    );
+    assert_eq!(
+        apply_with_siblings(orphan_number_with_siblings, "APP", &[main]),
+        None
+    );
+
+    let main = meeting("m1", "APP L2", "h1");
+    let sibling = meeting("m2", "APP L3", "h1");
+    assert_eq!(
+        apply_with_siblings(orphan_level_with_siblings, "APP", &[main.clone(), sibling]).as_deref(),
+        Some("ORPHAN_LEVEL_WITH_SIBLINGS")
+    );
+    assert_eq!(
+        apply_with_siblings(orphan_level_with_siblings, "APP", &[main]),
+        None
+    );
+}
+
+#[test]
+fn weak_match_checks_distinctive_tokens() {
+    assert_eq!(
+        strict(weak_match, "ABC", "XYZ").as_deref(),
+        Some("WEAK_MATCH")
+    );
+    assert!(strict(weak_match, "ABC DEF", "ABC").is_some());
+    assert_eq!(strict(weak_match, "ABC", "ABC"), None);
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use common::schedule;
+use minerva_lib::schedule::{from_json_rows, Schedule, ScheduleDate, ScheduleTime};
+use serde_json::json;
+
+#[test]
+fn dates_accept_ui_iso_and_excel_serial_formats() {
+    let expected = "15/03/2024";
+    for input in ["15/03/2024", "2024/03/15", "2024-03-15", " 45366 "] {
+        let date: ScheduleDate = input.parse().unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+    assert_eq!(
+        ScheduleDate::from_excel_serial(45366.75)
+            .unwrap()
+            .to_string(),
+        expected
+    );
+
+    for input in ["", "31/02/2024", "15/03", "sometime"] {
+        assert!(input.parse::<ScheduleDate>().is_err(), "{input}");
+    }
+}
+
+#[test]
+fn times_parse_strict_lenient_and_serial_values() {
+    let time = |value: &str| value.parse::<ScheduleTime>().map(|t| t.to_string());
+    assert_eq!(time("9:05").unwrap(), "09:05");
+    assert_eq!(time("14:30:00").unwrap(), "14:30");
+    assert_eq!(time("12:00 AM").unwrap(), "00:00");
+    assert_eq!(time("12:15 p.m.").unwrap(), "12:15");
+    assert!(time("24:00").is_err());
+    assert!(time("(08:00 AM)").is_err());
+
+    // El parseo tolerante busca la hora dentro del texto y usa 00:00 si no la encuentra
+    assert_eq!(
+        ScheduleTime::parse_lenient("(02:30 PM)").to_string(),
+        "14:30"
+    );
+    assert_eq!(ScheduleTime::parse_lenient("TBD").to_string(), "00:00");
+    assert_eq!(ScheduleTime::from_excel_serial(0.75).to_string(), "18:00");
+    assert_eq!(
+        ScheduleTime::from_excel_serial(0.5).format_12h(),
+        "12:00 PM"
+    );
+}
+
+#[test]
+fn keys_use_the_frontend_format() {
+    let row = schedule("GRP-A", "DOE, JOHN");
+    assert_eq!(
+        row.key().to_string(),
+        "01/01/2023|09:00|10:00|DOE, JOHN|GRP-A"
+    );
+    assert_eq!(
+        serde_json::to_value(row.key()).unwrap(),
+        json!("01/01/2023|09:00|10:00|DOE, JOHN|GRP-A")
+    );
+}
+
+#[test]
+fn invalid_rows_report_every_field_error() {
+    let valid = serde_json::to_value(schedule("GRP-A", "DOE, JOHN")).unwrap();
+    let mut invalid = valid.clone();
+    invalid["start_time"] = json!("25:00");
+
+    let errors = from_json_rows::<Schedule>(vec![valid, invalid, json!("row")]).unwrap_err();
+    let located: Vec<(usize, &str)> = errors.iter().map(|e| (e.row, e.field.as_str())).collect();
+    assert_eq!(located, [(1, "start_time"), (2, "")]);
+}
@@ -0,0 +1,74 @@
+use minerva_lib::error::AppError;
+use minerva_lib::store::settings::{self, AppSettings, Theme, SETTINGS_VERSION};
+use minerva_lib::store::Store;
+use serde_json::json;
+
+fn stored(value: serde_json::Value) -> settings::StoredSettings {
+    value.as_object().unwrap().clone()
+}
+
+#[test]
+fn unversioned_settings_are_migrated_and_invalid_values_fall_back() {
+    let store = Store::open_in_memory().unwrap();
+    assert_eq!(settings::get(&store).unwrap(), AppSettings::default());
+
+    // Ajustes escritos por el frontend antes de tener versión
+    settings::save(
+        &store,
+        &stored(json!({
+            "autoSave": false,
+            "theme": "dark",
+            "openAfterExport": "yes",
+            "sidebarOpen": true,
+        })),
+    )
+    .unwrap();
+
+    let loaded = settings::get(&store).unwrap();
+    assert_eq!(loaded.version, SETTINGS_VERSION);
+    assert!(!loaded.auto_save);
+    assert_eq!(loaded.theme, Theme::Dark);
+    assert!(loaded.open_after_export);
+
+    // Lo guardado quedó migrado y sin las claves inválidas
+    let saved = settings::load(&store).unwrap().unwrap();
+    assert_eq!(saved["version"], json!(SETTINGS_VERSION));
+    assert_eq!(saved["openAfterExport"], json!(true));
+    assert!(!saved.contains_key("sidebarOpen"));
+}
+
+#[test]
+fn update_applies_partial_changes_or_rejects_them_all() {
+    let store = Store::open_in_memory().unwrap();
+    let updated = settings::update(&store, stored(json!({ "theme": "light" }))).unwrap();
+    assert_eq!(updated.theme, Theme::Light);
+    assert!(updated.auto_save);
+
+    let err = settings::update(
+        &store,
+        stored(json!({ "autoSave": false, "theme": "sepia", "fontSize": 14 })),
+    )
+    .unwrap_err();
+    let AppError::InvalidSettings(issues) = err else {
+        panic!("expected InvalidSettings, got {:?}", err);
+    };
+    let pointers: Vec<&str> = issues.iter().map(|i| i.pointer.as_str()).collect();
+    assert_eq!(pointers, ["/fontSize", "/theme"]);
+
+    // Nada se guardó
+    assert_eq!(settings::get(&store).unwrap(), updated);
+}
+
+#[test]
+fn settings_from_a_newer_version_are_not_overwritten() {
+    let store = Store::open_in_memory().unwrap();
+    let newer = stored(json!({
+        "version": SETTINGS_VERSION + 1,
+        "autoSave": false,
+        "compactRows": true,
+    }));
+    settings::save(&store, &newer).unwrap();
+
+    assert!(!settings::get(&store).unwrap().auto_save);
+    assert_eq!(settings::load(&store).unwrap(), Some(newer));
+}
@@ -0,0 +1,374 @@
+mod common;
+
+use std::fs;
+
+use common::schedule;
+use indexmap::IndexMap;
+use minerva_lib::store::drafts::{DraftSource, SnapshotReason, DRAFT_SNAPSHOTS};
+use minerva_lib::store::import::{
+    import_json_files, import_legacy_files, ImportOutcome, DRAFT_FILE, LEGACY_DRAFT_FILE,
+    LEGACY_INCIDENCES_FILE, LEGACY_SETTINGS_FILE, SETTINGS_FILE,
+};
+use minerva_lib::store::migrations::{migrate, SCHEMA_VERSION};
+use minerva_lib::store::mirror::ExcelMirror;
+use minerva_lib::store::{drafts, history, incidences, mirror, settings, Store, DB_FILE};
+use serde_json::json;
+
+#[test]
+fn migrations_run_once_and_data_survives_reopening() {
+    let dir = std::env::temp_dir().join(format!("minerva-store-{}", std::process::id()));
+    let path = dir.join(DB_FILE);
+    let _ = fs::remove_dir_all(&dir);
+
+    let store = Store::open(&path).unwrap();
+    assert_eq!(settings::load(&store).unwrap(), None);
+    let saved = json!({ "autoSave": false, "theme": "dark" });
+    settings::save(&store, saved.as_object().unwrap()).unwrap();
+    drop(store);
+
+    let store = Store::open(&path).unwrap();
+    assert_eq!(
+        settings::load(&store)
+            .unwrap()
+            .map(serde_json::Value::Object),
+        Some(saved)
+    );
+    drop(store);
+
+    // Ya migrada: una segunda pasada no aplica nada
+    let mut conn = rusqlite::Connection::open(&path).unwrap();
+    assert_eq!(migrate(&mut conn).unwrap(), SCHEMA_VERSION);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn corrupt_database_is_moved_aside_and_recreated() {
+    let dir = std::env::temp_dir().join(format!("minerva-corrupt-{}", std::process::id()));
+    let path = dir.join(DB_FILE);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(&path, b"not a sqlite database, just garbage bytes").unwrap();
+
+    let (store, recovered) = Store::open_or_recover(&path, 42).unwrap();
+    let recovered = recovered.unwrap();
+    assert_eq!(
+        recovered.backup,
+        dir.join(format!("{}.corrupt-42", DB_FILE))
+    );
+    assert_eq!(
+        fs::read(&recovered.backup).unwrap(),
+        b"not a sqlite database, just garbage bytes"
+    );
+
+    // La base nueva funciona y al reabrirla ya no se recupera nada
+    drafts::save(&store, &[schedule("A", "DOE")], 1).unwrap();
+    drop(store);
+    let (store, recovered) = Store::open_or_recover(&path, 43).unwrap();
+    assert!(recovered.is_none());
+    assert_eq!(drafts::load(&store).unwrap().len(), 1);
+    drop(store);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn other_open_errors_leave_the_database_in_place() {
+    let dir = std::env::temp_dir().join(format!("minerva-unopenable-{}", std::process::id()));
+    let path = dir.join(DB_FILE);
+    let _ = fs::remove_dir_all(&dir);
+    // Un directorio en lugar de la base: SQLite no la puede abrir, pero no está dañada
+    fs::create_dir_all(&path).unwrap();
+
+    let error = Store::open_or_recover(&path, 42).err().unwrap();
+    assert_eq!(error.code(), "DATABASE");
+    assert!(path.is_dir());
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn json_files_are_imported_once_without_overwriting() {
+    let dir = std::env::temp_dir().join(format!("minerva-import-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let draft = [schedule("TRIO TECHCORP L4", "Juan Perez")];
+    fs::write(dir.join(DRAFT_FILE), serde_json::to_string(&draft).unwrap()).unwrap();
+    fs::write(dir.join(SETTINGS_FILE), r#"{ "autoSave": false }"#).unwrap();
+
+    let store = Store::open_in_memory().unwrap();
+    // La base ya tiene ajustes: el archivo no los pisa
+    let current = json!({ "autoSave": true });
+    settings::save(&store, current.as_object().unwrap()).unwrap();
+
+    let outcomes = import_json_files(&store, &dir);
+    let outcomes: Vec<_> = outcomes
+        .into_iter()
+        .map(|(file, outcome)| (file, outcome.unwrap()))
+        .collect();
+    assert!(outcomes.contains(&(SETTINGS_FILE, ImportOutcome::Skipped)));
+    assert!(outcomes.contains(&(DRAFT_FILE, ImportOutcome::Imported)));
+    assert_eq!(drafts::load(&store).unwrap(), draft);
+    assert_eq!(
+        settings::load(&store)
+            .unwrap()
+            .map(serde_json::Value::Object),
+        Some(current)
+    );
+
+    // Los archivos quedan archivados: una segunda pasada no encuentra nada
+    assert!(dir.join(format!("{}.imported", DRAFT_FILE)).exists());
+    assert!(import_json_files(&store, &dir)
+        .into_iter()
+        .all(|(_, outcome)| outcome.unwrap() == ImportOutcome::Missing));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn legacy_files_fill_gaps_after_current_files() {
+    let dir = std::env::temp_dir().join(format!("minerva-legacy-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let current = [schedule("TRIO TECHCORP L4", "Juan Perez")];
+    let legacy = [schedule("CH ACME L2", "Ana Lopez")];
+    fs::write(
+        dir.join(DRAFT_FILE),
+        serde_json::to_string(&current).unwrap(),
+    )
+    .unwrap();
+    fs::write(
+        dir.join(LEGACY_DRAFT_FILE),
+        serde_json::to_string(&legacy).unwrap(),
+    )
+    .unwrap();
+    fs::write(
+        dir.join(LEGACY_INCIDENCES_FILE),
+        serde_json::to_string(&legacy).unwrap(),
+    )
+    .unwrap();
+    fs::write(dir.join(SETTINGS_FILE), r#"{ "autoSave": false }"#).unwrap();
+    fs::write(
+        dir.join(LEGACY_SETTINGS_FILE),
+        r#"{ "autoSave": true, "openAfterExport": false }"#,
+    )
+    .unwrap();
+
+    let store = Store::open_in_memory().unwrap();
+    import_json_files(&store, &dir);
+    let outcomes: Vec<_> = import_legacy_files(&store, &dir)
+        .into_iter()
+        .map(|(file, outcome)| (file, outcome.unwrap()))
+        .collect();
+    assert!(outcomes.contains(&(LEGACY_DRAFT_FILE, ImportOutcome::Skipped)));
+    assert!(outcomes.contains(&(LEGACY_INCIDENCES_FILE, ImportOutcome::Imported)));
+    assert!(outcomes.contains(&(LEGACY_SETTINGS_FILE, ImportOutcome::Merged)));
+
+    // El borrador actual gana y el legacy queda en el historial; los ajustes legacy solo
+    // completan claves
+    assert_eq!(drafts::load(&store).unwrap(), current);
+    let timeline = history::list(&store).unwrap();
+    assert_eq!(timeline.len(), 2);
+    assert_eq!(timeline[0].reason, SnapshotReason::Import);
+    assert_eq!(
+        history::load_snapshot(&store, timeline[0].id).unwrap(),
+        legacy
+    );
+    // Si el borrador se daña, se recupera el último autoguardado, no el importado
+    store
+        .write(|tx| {
+            tx.execute("UPDATE schedules_draft SET data = '{\"date\":'", [])?;
+            Ok(())
+        })
+        .unwrap();
+    let recovered = drafts::load_or_recover(&store).unwrap();
+    assert_eq!(recovered.source, DraftSource::Snapshot);
+    assert_eq!(recovered.schedules, current);
+    assert_eq!(incidences::load(&store).unwrap().len(), 1);
+    assert_eq!(
+        settings::load(&store)
+            .unwrap()
+            .map(serde_json::Value::Object),
+        Some(json!({ "autoSave": false, "openAfterExport": false }))
+    );
+
+    // Idempotente: una segunda pasada no encuentra nada
+    assert!(import_legacy_files(&store, &dir)
+        .into_iter()
+        .all(|(_, outcome)| outcome.unwrap() == ImportOutcome::Missing));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn draft_rows_are_replaced_in_order() {
+    let store = Store::open_in_memory().unwrap();
+    let rows = [
+        schedule("TRIO TECHCORP L4", "Juan Perez"),
+        schedule("CH ACME L2", "Ana Lopez"),
+        schedule("DUO GLOBEX L1", "Carla Diaz"),
+    ];
+
+    drafts::save(&store, &rows, 1).unwrap();
+    assert_eq!(drafts::load(&store).unwrap(), rows);
+
+    // Menos filas: las sobrantes se eliminan
+    drafts::save(&store, &rows[1..], 2).unwrap();
+    assert_eq!(drafts::load(&store).unwrap(), rows[1..]);
+
+    drafts::save(&store, &[], 3).unwrap();
+    assert!(drafts::load(&store).unwrap().is_empty());
+}
+
+#[test]
+fn unreadable_draft_falls_back_to_newest_valid_snapshot() {
+    let store = Store::open_in_memory().unwrap();
+    let first = [schedule("TRIO TECHCORP L4", "Juan Perez")];
+    let second = [
+        schedule("TRIO TECHCORP L4", "Juan Perez"),
+        schedule("CH ACME L2", "Ana Lopez"),
+    ];
+    drafts::save(&store, &first, 100).unwrap();
+    drafts::save(&store, &second, 200).unwrap();
+
+    let loaded = drafts::load_or_recover(&store).unwrap();
+    assert_eq!(loaded.source, DraftSource::Draft);
+    assert_eq!(loaded.schedules, second);
+
+    // Borrador truncado y el último snapshot inválido: se usa el anterior
+    store
+        .write(|tx| {
+            tx.execute(
+                "UPDATE schedules_draft SET data = '{\"date\":' WHERE position = 1",
+                [],
+            )?;
+            tx.execute(
+                "UPDATE draft_snapshots SET data = '[{}]' WHERE created_at = 200",
+                [],
+            )?;
+            Ok(())
+        })
+        .unwrap();
+    let loaded = drafts::load_or_recover(&store).unwrap();
+    assert_eq!(loaded.source, DraftSource::Snapshot);
+    assert_eq!(loaded.snapshot_at, Some(100));
+    assert_eq!(loaded.skipped, 1);
+    assert_eq!(loaded.schedules, first);
+
+    // El borrador quedó reparado
+    assert_eq!(drafts::load(&store).unwrap(), first);
+}
+
+#[test]
+fn only_the_last_snapshots_are_kept() {
+    let store = Store::open_in_memory().unwrap();
+    let rows = [schedule("TRIO TECHCORP L4", "Juan Perez")];
+    // Guardar el mismo contenido no agrega snapshots
+    drafts::save(&store, &rows, 1).unwrap();
+    drafts::save(&store, &rows, 2).unwrap();
+    let count = |store: &Store| -> i64 {
+        store
+            .read(|conn| {
+                Ok(conn.query_row("SELECT COUNT(*) FROM draft_snapshots", [], |row| row.get(0))?)
+            })
+            .unwrap()
+    };
+    assert_eq!(count(&store), 1);
+
+    for i in 0..DRAFT_SNAPSHOTS + 5 {
+        let mut row = rows[0].clone();
+        row.branch = format!("BRANCH {}", i);
+        drafts::save(&store, &[row], 10 + i as i64).unwrap();
+    }
+    assert_eq!(count(&store), DRAFT_SNAPSHOTS as i64);
+}
+
+#[test]
+fn pre_upload_state_survives_autosaves() {
+    let store = Store::open_in_memory().unwrap();
+    let before = [schedule("TRIO TECHCORP L4", "Juan Perez")];
+    drafts::save(&store, &before, 1).unwrap();
+
+    // Un Excel nuevo reemplaza el borrador y luego muchas ediciones se autoguardan
+    let upload = [schedule("CH ACME L2", "Ana Lopez")];
+    drafts::save_as(&store, &upload, SnapshotReason::Upload, 2).unwrap();
+    for i in 0..DRAFT_SNAPSHOTS * 2 {
+        let mut row = upload[0].clone();
+        row.branch = format!("BRANCH {}", i);
+        drafts::save(&store, &[row], 10 + i as i64).unwrap();
+    }
+
+    let timeline = history::list(&store).unwrap();
+    assert_eq!(timeline.len(), DRAFT_SNAPSHOTS + 1);
+    let pinned: Vec<_> = timeline
+        .iter()
+        .filter(|s| s.reason != SnapshotReason::Autosave)
+        .collect();
+    assert_eq!(pinned.len(), 1);
+    assert_eq!(pinned[0].reason, SnapshotReason::Upload);
+    assert_eq!(pinned[0].created_at, 1);
+    assert_eq!(
+        history::load_snapshot(&store, pinned[0].id).unwrap(),
+        before
+    );
+}
+
+#[test]
+fn mirror_round_trips_and_drops_removed_sheets() {
+    let store = Store::open_in_memory().unwrap();
+    assert_eq!(mirror::load(&store).unwrap(), None);
+
+    let mut data = IndexMap::new();
+    data.insert(
+        "Enero".to_string(),
+        vec![json!({ "a": 1 }), json!({ "a": 2 })],
+    );
+    data.insert("Febrero".to_string(), vec![json!({ "a": 3 })]);
+    let mut excel = ExcelMirror {
+        file_id: "file-1".to_string(),
+        file_name: "Incidencias.xlsx".to_string(),
+        timestamp: 1_700_000_000_000,
+        sheets: vec![json!({ "name": "Enero" }), json!({ "name": "Febrero" })],
+        data,
+    };
+    mirror::save(&store, &excel).unwrap();
+    let loaded = mirror::load(&store).unwrap().unwrap();
+    assert_eq!(loaded.file_id, "file-1");
+    assert_eq!(loaded.data["Enero"], excel.data["Enero"]);
+    assert_eq!(loaded.data["Febrero"], excel.data["Febrero"]);
+
+    excel.data.shift_remove("Febrero");
+    excel.data["Enero"].truncate(1);
+    mirror::save(&store, &excel).unwrap();
+    let loaded = mirror::load(&store).unwrap().unwrap();
+    assert_eq!(loaded.data.len(), 1);
+    assert_eq!(loaded.data["Enero"], [json!({ "a": 1 })]);
+
+    assert!(mirror::clear(&store).unwrap());
+    assert_eq!(mirror::load(&store).unwrap(), None);
+    assert!(!mirror::clear(&store).unwrap());
+}
+
+#[test]
+fn mirror_keeps_workbook_order_and_empty_sheets() {
+    let store = Store::open_in_memory().unwrap();
+    let data: IndexMap<String, Vec<_>> = [
+        ("Marzo", vec![json!({ "a": 1 })]),
+        ("Abril", Vec::new()),
+        ("Enero", vec![json!({ "a": 2 })]),
+    ]
+    .into_iter()
+    .map(|(sheet, rows)| (sheet.to_string(), rows))
+    .collect();
+    let excel = ExcelMirror {
+        file_id: "file-1".to_string(),
+        file_name: "Incidencias.xlsx".to_string(),
+        timestamp: 1_700_000_000_000,
+        sheets: Vec::new(),
+        data,
+    };
+
+    mirror::save(&store, &excel).unwrap();
+    let loaded = mirror::load(&store).unwrap().unwrap();
+    assert_eq!(
+        loaded.data.keys().collect::<Vec<_>>(),
+        ["Marzo", "Abril", "Enero"]
+    );
+    assert_eq!(loaded, excel);
+}
@@ -1,9 +1,11 @@
-import { createContext, useContext, useEffect, useState, useRef, type ReactNode } from "react";
-import { readTextFile, writeTextFile, exists, BaseDirectory } from "@tauri-apps/plugin-fs";
+import { createContext, useContext, useEffect, useState, type ReactNode } from "react";
+import { listen } from "@tauri-apps/api/event";
 
-import { STORAGE_FILES } from "@/lib/constants";
+import { getSettings, updateSettings, SETTINGS_CHANGED_EVENT } from "@/lib/local-store";
 
+// Mismos campos que `AppSettings` en src-tauri/src/store/settings.rs
 interface AppSettings {
+    version: number; // Asignada por Rust
     actionsRespectFilters: boolean;
     autoSave: boolean;
     theme: "light" | "dark" | "system";
@@ -11,13 +13,17 @@ interface AppSettings {
     clearScheduleOnLoad: boolean;
 }
 
+type EditableSetting = Exclude<keyof AppSettings, "version">;
+
 interface SettingsContextType {
     settings: AppSettings;
-    updateSetting: <K extends keyof AppSettings>(key: K, value: AppSettings[K]) => void;
+    updateSetting: <K extends EditableSetting>(key: K, value: AppSettings[K]) => void;
     isLoading: boolean;
 }
 
+// Valores mientras carga (los definitivos vienen de Rust)
 const defaultSettings: AppSettings = {
+    version: 1,
     actionsRespectFilters: false,
     autoSave: true,
     theme: "system",
@@ -30,50 +36,35 @@ const SettingsContext = createContext<SettingsContextType | undefined>(undefined
 export function SettingsProvider({ children }: { children: ReactNode }) {
     const [settings, setSettings] = useState<AppSettings>(defaultSettings);
     const [isLoading, setIsLoading] = useState(true);
-    const hasLoaded = useRef(false);
 
-    // Load settings from file on mount
+    // Load settings on mount and follow changes from any window
     useEffect(() => {
-        if (hasLoaded.current) return;
-        hasLoaded.current = true;
+        let disposed = false;
 
-        const loadSettings = async () => {
-            try {
-                const fileExists = await exists(STORAGE_FILES.APP_SETTINGS, { baseDir: BaseDirectory.AppLocalData });
-                if (fileExists) {
-                    const content = await readTextFile(STORAGE_FILES.APP_SETTINGS, { baseDir: BaseDirectory.AppLocalData });
-                    const parsed = JSON.parse(content);
-                    setSettings({ ...defaultSettings, ...parsed });
-                }
-            } catch (e) {
-                console.error("Failed to load settings from file:", e);
-            } finally {
-                setIsLoading(false);
-            }
-        };
+        getSettings<AppSettings>()
+            .then((loaded) => {
+                if (!disposed) setSettings(loaded);
+            })
+            .catch((e) => console.error("Failed to load settings:", e))
+            .finally(() => {
+                if (!disposed) setIsLoading(false);
+            });
 
-        loadSettings();
-    }, []);
+        const unlisten = listen<AppSettings>(SETTINGS_CHANGED_EVENT, ({ payload }) => setSettings(payload));
 
-    // Save settings to file whenever they change (after initial load)
-    useEffect(() => {
-        if (isLoading) return; // Don't save while still loading
-
-        const saveSettings = async () => {
-            try {
-                await writeTextFile(STORAGE_FILES.APP_SETTINGS, JSON.stringify(settings, null, 2), {
-                    baseDir: BaseDirectory.AppLocalData,
-                });
-            } catch (e) {
-                console.error("Failed to save settings to file:", e);
-            }
+        return () => {
+            disposed = true;
+            unlisten.then((stop) => stop());
         };
+    }, []);
 
-        saveSettings();
-    }, [settings, isLoading]);
-
-    const updateSetting = <K extends keyof AppSettings>(key: K, value: AppSettings[K]) => {
+    const updateSetting = <K extends EditableSetting>(key: K, value: AppSettings[K]) => {
+        // Optimista; el evento trae los ajustes guardados
         setSettings((prev) => ({ ...prev, [key]: value }));
+        updateSettings<AppSettings>({ [key]: value } as Partial<AppSettings>).catch(async (e) => {
+            console.error("Failed to save settings:", e);
+            setSettings(await getSettings<AppSettings>());
+        });
     };
 
     return (
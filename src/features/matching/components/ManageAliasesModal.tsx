@@ -0,0 +1,291 @@
+import { useEffect, useMemo, useState } from "react";
+import {
+    Dialog,
+    DialogContent,
+    DialogDescription,
+    DialogHeader,
+    DialogTitle,
+} from "@/components/ui/dialog";
+import {
+    AlertDialog,
+    AlertDialogAction,
+    AlertDialogCancel,
+    AlertDialogContent,
+    AlertDialogDescription,
+    AlertDialogFooter,
+    AlertDialogHeader,
+    AlertDialogTitle,
+} from "@/components/ui/alert-dialog";
+import {
+    Select,
+    SelectContent,
+    SelectItem,
+    SelectTrigger,
+    SelectValue,
+} from "@/components/ui/select";
+import { Button } from "@/components/ui/button";
+import { Badge } from "@/components/ui/badge";
+import { Input } from "@/components/ui/input";
+import { InputGroup, InputGroupAddon, InputGroupInput } from "@/components/ui/input-group";
+import { Check, Clock, Loader2, Pencil, RotateCcw, Search, Trash2, X } from "lucide-react";
+import { toast } from "sonner";
+import { describeAppError } from "@/lib/app-error";
+import {
+    deleteAlias,
+    expireAlias,
+    listAliases,
+    updateAlias,
+    type AliasTarget,
+    type MatchingAlias,
+} from "@/features/matching/services/aliases";
+
+interface ManageAliasesModalProps {
+    open: boolean;
+    onOpenChange: (open: boolean) => void;
+}
+
+type TargetKind = "meetingId" | "topic";
+
+const targetKind = (target: AliasTarget): TargetKind => ("meetingId" in target ? "meetingId" : "topic");
+const targetValue = (target: AliasTarget) => ("meetingId" in target ? target.meetingId : target.topic);
+
+// Los alias antiguos no guardaban el texto original: se muestra la clave normalizada
+const programOf = (alias: MatchingAlias) => alias.sourceProgram || alias.program;
+const instructorOf = (alias: MatchingAlias) => alias.sourceInstructor || alias.instructor;
+
+const showError = (title: string, error: unknown) => {
+    const { message, fix } = describeAppError(error);
+    toast.error(title, { description: fix ? `${message} ${fix}` : message });
+};
+
+export function ManageAliasesModal({ open, onOpenChange }: ManageAliasesModalProps) {
+    const [aliases, setAliases] = useState<MatchingAlias[]>([]);
+    const [isLoading, setIsLoading] = useState(false);
+    const [searchQuery, setSearchQuery] = useState("");
+    const [busyId, setBusyId] = useState<number | null>(null);
+    const [editingId, setEditingId] = useState<number | null>(null);
+    const [editingKind, setEditingKind] = useState<TargetKind>("meetingId");
+    const [editingValue, setEditingValue] = useState("");
+    const [pendingDelete, setPendingDelete] = useState<MatchingAlias | null>(null);
+
+    useEffect(() => {
+        if (!open) {
+            setEditingId(null);
+            setSearchQuery("");
+            return;
+        }
+        setIsLoading(true);
+        listAliases()
+            .then(setAliases)
+            .catch((error) => showError("Failed to load aliases", error))
+            .finally(() => setIsLoading(false));
+    }, [open]);
+
+    const filteredAliases = useMemo(() => {
+        const query = searchQuery.trim().toLowerCase();
+        const sorted = [...aliases].sort((a, b) => b.updatedAt - a.updatedAt);
+        if (!query) return sorted;
+        return sorted.filter((alias) =>
+            [programOf(alias), instructorOf(alias), targetValue(alias.target)].some((text) =>
+                text.toLowerCase().includes(query)
+            )
+        );
+    }, [aliases, searchQuery]);
+
+    const replace = (updated: MatchingAlias) =>
+        setAliases((current) => current.map((alias) => (alias.id === updated.id ? updated : alias)));
+
+    // Aplica un cambio y reemplaza el alias en la lista con el que retorna el backend
+    const run = async (alias: MatchingAlias, title: string, change: () => Promise<MatchingAlias>) => {
+        setBusyId(alias.id);
+        try {
+            replace(await change());
+            return true;
+        } catch (error) {
+            showError(title, error);
+            return false;
+        } finally {
+            setBusyId(null);
+        }
+    };
+
+    const handleStartEdit = (alias: MatchingAlias) => {
+        setEditingId(alias.id);
+        setEditingKind(targetKind(alias.target));
+        setEditingValue(targetValue(alias.target));
+    };
+
+    const handleSaveEdit = async (alias: MatchingAlias) => {
+        const value = editingValue.trim();
+        if (!value) return;
+        const target: AliasTarget = editingKind === "meetingId" ? { meetingId: value } : { topic: value };
+        if (await run(alias, "Failed to update alias", () => updateAlias(alias.id, target, alias.expiresAt))) {
+            setEditingId(null);
+        }
+    };
+
+    const handleExpire = (alias: MatchingAlias) =>
+        run(alias, "Failed to expire alias", () => expireAlias(alias.id));
+
+    // Quitar el vencimiento vuelve a activar el alias
+    const handleReactivate = (alias: MatchingAlias) =>
+        run(alias, "Failed to reactivate alias", () => updateAlias(alias.id, alias.target));
+
+    const handleDelete = async () => {
+        if (!pendingDelete) return;
+        const { id } = pendingDelete;
+        setPendingDelete(null);
+        setBusyId(id);
+        try {
+            await deleteAlias(id);
+            setAliases((current) => current.filter((alias) => alias.id !== id));
+            toast.success("Alias deleted");
+        } catch (error) {
+            showError("Failed to delete alias", error);
+        } finally {
+            setBusyId(null);
+        }
+    };
+
+    const now = Date.now();
+
+    return (
+        <>
+            <Dialog open={open} onOpenChange={onOpenChange}>
+                <DialogContent className="sm:max-w-[720px]">
+                    <DialogHeader>
+                        <DialogTitle>Learned Aliases</DialogTitle>
+                        <DialogDescription>
+                            Meetings chosen when resolving ambiguous rows. Matching uses them before searching, until they expire.
+                        </DialogDescription>
+                    </DialogHeader>
+
+                    <div className="space-y-4">
+                        <InputGroup>
+                            <InputGroupAddon>
+                                <Search className="size-4 text-muted-foreground" />
+                            </InputGroupAddon>
+                            <InputGroupInput
+                                placeholder="Search by program, instructor or meeting..."
+                                value={searchQuery}
+                                onChange={(e) => setSearchQuery(e.target.value)}
+                            />
+                        </InputGroup>
+
+                        {isLoading ? (
+                            <div className="flex items-center justify-center py-8">
+                                <Loader2 className="size-6 animate-spin text-muted-foreground" />
+                            </div>
+                        ) : filteredAliases.length === 0 ? (
+                            <p className="py-8 text-center text-sm text-muted-foreground">
+                                {aliases.length === 0 ? "No aliases learned yet." : "No aliases match the search."}
+                            </p>
+                        ) : (
+                            <div className="border rounded-lg divide-y max-h-[420px] overflow-y-auto">
+                                {filteredAliases.map((alias) => {
+                                    const active = alias.expiresAt === undefined || alias.expiresAt > now;
+                                    const busy = busyId === alias.id;
+                                    return (
+                                        <div key={alias.id} className="group flex items-center justify-between gap-3 p-3 px-4 hover:bg-muted/50">
+                                            <div className="space-y-1 min-w-0 flex-1">
+                                                <div className="flex items-center gap-2">
+                                                    <p className="font-medium text-sm truncate">{programOf(alias)}</p>
+                                                    {!active ? (
+                                                        <Badge variant="secondary" className="text-xs">Expired</Badge>
+                                                    ) : alias.expiresAt !== undefined && (
+                                                        <Badge variant="outline" className="text-xs">
+                                                            Expires {new Date(alias.expiresAt).toLocaleDateString()}
+                                                        </Badge>
+                                                    )}
+                                                </div>
+                                                <p className="text-xs text-muted-foreground truncate">
+                                                    {instructorOf(alias) || "Any instructor"}
+                                                </p>
+                                                {editingId === alias.id ? (
+                                                    <div className="flex items-center gap-1">
+                                                        <Select value={editingKind} onValueChange={(value: TargetKind) => setEditingKind(value)}>
+                                                            <SelectTrigger className="w-[120px] h-7" size="sm">
+                                                                <SelectValue />
+                                                            </SelectTrigger>
+                                                            <SelectContent>
+                                                                <SelectItem value="meetingId">Meeting ID</SelectItem>
+                                                                <SelectItem value="topic">Topic</SelectItem>
+                                                            </SelectContent>
+                                                        </Select>
+                                                        <Input
+                                                            value={editingValue}
+                                                            onChange={(e) => setEditingValue(e.target.value)}
+                                                            className="h-7 text-sm"
+                                                            autoFocus
+                                                            disabled={busy}
+                                                            onKeyDown={(e) => {
+                                                                if (e.key === "Enter") handleSaveEdit(alias);
+                                                                if (e.key === "Escape") setEditingId(null);
+                                                            }}
+                                                        />
+                                                        <Button variant="ghost" size="icon-sm" onClick={() => handleSaveEdit(alias)} disabled={busy || !editingValue.trim()}>
+                                                            {busy ? <Loader2 className="animate-spin" /> : <Check className="text-green-600" />}
+                                                        </Button>
+                                                        <Button variant="ghost" size="icon-sm" onClick={() => setEditingId(null)} disabled={busy}>
+                                                            <X className="text-muted-foreground" />
+                                                        </Button>
+                                                    </div>
+                                                ) : (
+                                                    <div className="flex items-center gap-1 text-xs">
+                                                        <span className="text-muted-foreground">
+                                                            {targetKind(alias.target) === "meetingId" ? "Meeting" : "Topic"}:
+                                                        </span>
+                                                        <span className="font-mono truncate">{targetValue(alias.target)}</span>
+                                                        <Button
+                                                            variant="ghost"
+                                                            size="icon-sm"
+                                                            className="h-5 w-5 opacity-0 group-hover:opacity-100 hover:opacity-100"
+                                                            onClick={() => handleStartEdit(alias)}
+                                                        >
+                                                            <Pencil className="h-3 w-3" />
+                                                        </Button>
+                                                    </div>
+                                                )}
+                                            </div>
+                                            <div className="flex items-center gap-2 shrink-0">
+                                                {active ? (
+                                                    <Button variant="outline" size="sm" onClick={() => handleExpire(alias)} disabled={busy}>
+                                                        <Clock />
+                                                        Expire
+                                                    </Button>
+                                                ) : (
+                                                    <Button variant="outline" size="sm" onClick={() => handleReactivate(alias)} disabled={busy}>
+                                                        <RotateCcw />
+                                                        Reactivate
+                                                    </Button>
+                                                )}
+                                                <Button variant="secondary" size="icon-sm" onClick={() => setPendingDelete(alias)} disabled={busy}>
+                                                    <Trash2 />
+                                                </Button>
+                                            </div>
+                                        </div>
+                                    );
+                                })}
+                            </div>
+                        )}
+                    </div>
+                </DialogContent>
+            </Dialog>
+
+            <AlertDialog open={pendingDelete !== null} onOpenChange={(open) => !open && setPendingDelete(null)}>
+                <AlertDialogContent>
+                    <AlertDialogHeader>
+                        <AlertDialogTitle>Delete alias?</AlertDialogTitle>
+                        <AlertDialogDescription>
+                            {pendingDelete && `${programOf(pendingDelete)} will be matched by search again. Expire it instead to keep it in the list.`}
+                        </AlertDialogDescription>
+                    </AlertDialogHeader>
+                    <AlertDialogFooter>
+                        <AlertDialogCancel>Cancel</AlertDialogCancel>
+                        <AlertDialogAction onClick={handleDelete}>Delete</AlertDialogAction>
+                    </AlertDialogFooter>
+                </AlertDialogContent>
+            </AlertDialog>
+        </>
+    );
+}
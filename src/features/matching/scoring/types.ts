@@ -22,6 +22,7 @@ export interface ScoringContext {
  */
 export interface MatchOptions {
     ignoreLevelMismatch?: boolean; // Si true, los conflictos de nivel no descartan (para detección de duplicados)
+    trace?: boolean; // Si true, el resultado incluye la traza del matching
 }
 
 /**
@@ -62,3 +63,27 @@ export interface MatchEvaluation {
  * Función de penalización - retorna puntos a restar (negativo) o 0
  */
 export type PenaltyFunction = (ctx: ScoringContext) => AppliedPenalty | null;
+
+/**
+ * Traza de un candidato evaluado (`run_matching` con `trace: true`)
+ */
+export interface CandidateTrace {
+    meetingId: string;
+    topic: string;
+    normalizedTopic: string;
+    retrieval: 'alias' | 'exact' | 'fuzzy' | 'token_set'; // Estrategia que encontró el candidato
+    baseScore: number;
+    finalScore: number;
+    disqualified: boolean;
+    penalties: AppliedPenalty[];
+}
+
+/**
+ * Traza del matching de un horario: por qué ganó o perdió cada candidato
+ */
+export interface MatchTrace {
+    normalizedQuery: string;
+    candidates: CandidateTrace[]; // Ordenados por score descendente
+    decision: 'assigned' | 'ambiguous' | 'not_found';
+    confidence: 'high' | 'medium' | 'low' | 'none';
+}
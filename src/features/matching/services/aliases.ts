@@ -0,0 +1,56 @@
+import { invoke } from '@tauri-apps/api/core';
+import { Schedule } from '@/features/schedules/utils/excel-parser';
+import { ZoomMeetingCandidate } from './matcher';
+
+/**
+ * Alias aprendidos de resoluciones manuales (ver src-tauri/src/matching/aliases.rs).
+ * El matcher los consulta antes de la búsqueda difusa en cada `run_matching`.
+ */
+
+export type AliasTarget = { meetingId: string } | { topic: string };
+
+export interface MatchingAlias {
+    id: number;
+    program: string; // Programa normalizado
+    instructor: string; // Instructor normalizado; vacío = cualquier instructor
+    sourceProgram: string; // Sin normalizar, como se aprendió (vacío en alias antiguos)
+    sourceInstructor: string;
+    target: AliasTarget;
+    createdAt: number; // ms desde epoch
+    updatedAt: number;
+    expiresAt?: number;
+}
+
+/** Alias aprendido y el que reemplazó (de una sesión anterior), si había */
+export interface LearnedAlias {
+    alias: MatchingAlias;
+    previous: MatchingAlias | null;
+}
+
+export const listAliases = () => invoke<MatchingAlias[]>('list_aliases');
+
+/** Recuerda el meeting elegido por el operador para el programa + instructor del horario */
+export const learnAlias = (schedule: Schedule, meeting: ZoomMeetingCandidate) =>
+    invoke<LearnedAlias>('learn_alias', {
+        program: schedule.program,
+        instructor: schedule.instructor,
+        target: { meetingId: meeting.meeting_id },
+    });
+
+export const updateAlias = (id: number, target: AliasTarget, expiresAt?: number) =>
+    invoke<MatchingAlias>('update_alias', { id, target, expiresAt });
+
+/** Vence el alias en `at` (ms desde epoch) o inmediatamente */
+export const expireAlias = (id: number, at?: number) =>
+    invoke<MatchingAlias>('expire_alias', { id, at });
+
+export const deleteAlias = (id: number) => invoke<void>('delete_alias', { id });
+
+/**
+ * Deshace un `learnAlias` (ej: el operador deselecciona el candidato): elimina el alias si
+ * lo creó esa selección, o restaura el que reemplazó
+ */
+export const undoLearnedAlias = ({ alias, previous }: LearnedAlias) =>
+    previous
+        ? updateAlias(previous.id, previous.target, previous.expiresAt).then(() => undefined)
+        : deleteAlias(alias.id);
@@ -0,0 +1,22 @@
+import { invoke } from '@tauri-apps/api/core';
+
+/**
+ * Diccionario de normalización del usuario (ver src-tauri/src/matching/dictionary.rs).
+ * Se combina con las listas incluidas en matching.config.json.
+ */
+export interface MatchingDictionary {
+    version: number; // Versión leída; se incrementa con cada guardado
+    updatedAt?: number; // ms desde epoch
+    irrelevantWords: string[]; // Texto literal a eliminar al normalizar
+    synonyms: string[][]; // El primer término de cada grupo es la forma canónica
+    structural: string[]; // Tokens estructurales adicionales (CH/DUO/TRIO...)
+}
+
+export const getMatchingDictionary = () => invoke<MatchingDictionary>('get_matching_dictionary');
+
+/**
+ * Guarda el diccionario y recompila las reglas del matcher (hay que volver a correr el matching).
+ * Falla con INVALID_REQUEST si otro guardado cambió la versión, o INVALID_CONFIG si una entrada es inválida.
+ */
+export const saveMatchingDictionary = (dictionary: MatchingDictionary) =>
+    invoke<MatchingDictionary>('save_matching_dictionary', { dictionary });
@@ -0,0 +1,66 @@
+import { invoke } from '@tauri-apps/api/core';
+import { Schedule } from '@/features/schedules/utils/excel-parser';
+
+/**
+ * Concurrencia de anfitriones (ver src-tauri/src/matching/hosts.rs).
+ * Una licencia de Zoom solo hospeda un meeting a la vez.
+ */
+
+/** Horario con el meeting asignado y el anfitrión (usuario de Zoom) que lo hospedará */
+export type HostAssignment = Schedule & { meeting_id: string; host_id: string };
+
+/** Rango de fechas a verificar (DD/MM/YYYY, inclusivo) */
+export interface HostWindow {
+    from?: string;
+    to?: string;
+}
+
+export interface HostConflict {
+    hostId: string;
+    hostName: string;
+    date: string;
+    startTime: string; // Tramo que cubre todas las filas del grupo
+    endTime: string;
+    rows: { row: number; key: string; meetingId: string }[]; // row = índice en `rows`
+    freeHosts: { id: string; name: string }[]; // Libres durante todo el tramo
+}
+
+export interface HostConcurrencyReport {
+    conflicts: HostConflict[];
+    conflictCount: number;
+}
+
+/** Busca anfitriones con meetings superpuestos; `hostMap` es el de `useHostMap` */
+export const checkHostConflicts = (rows: HostAssignment[], hostMap: Map<string, string>, window?: HostWindow) =>
+    invoke<HostConcurrencyReport>('check_host_conflicts', {
+        rows,
+        hosts: Object.fromEntries(hostMap),
+        window,
+    });
+
+export interface PlannedMeeting {
+    meetingId: string;
+    rows: number[]; // Índices en `rows`
+    preferredHostId?: string;
+    hostId?: string;
+    hostName?: string;
+    outcome: 'preferred' | 'reassigned' | 'unassigned';
+}
+
+export interface HostPlan {
+    meetings: PlannedMeeting[];
+    preferred: number;
+    reassigned: number;
+    unassigned: number;
+}
+
+/**
+ * Propone un anfitrión para cada meeting de `rows` (con el anfitrión habitual del instructor
+ * en `host_id`) sin cruces; `fixed` son filas que conservan su anfitrión. No modifica Zoom.
+ */
+export const planHostAllocation = (rows: HostAssignment[], fixed: HostAssignment[], hostMap: Map<string, string>) =>
+    invoke<HostPlan>('plan_host_allocation', {
+        rows,
+        fixed,
+        hosts: Object.fromEntries(hostMap),
+    });
@@ -4,7 +4,7 @@ import { normalizeString } from '../utils/normalizer';
 import { evaluateMatch } from '../scoring/scorer';
 import { clearLevenshteinCache } from '../scoring/penalties';
 import { THRESHOLDS } from '../config/matching.config';
-import type { MatchOptions } from '../scoring/types';
+import type { MatchOptions, MatchTrace } from '../scoring/types';
 
 export interface ZoomMeetingCandidate {
     meeting_id: string;
@@ -40,6 +40,8 @@ export interface MatchResult {
     ambiguousCandidates?: ZoomMeetingCandidate[];
     matchedCandidate?: ZoomMeetingCandidate;
     score?: number;
+    trace?: MatchTrace; // Traza del matching (candidatos, penalizaciones y decisión)
+    dictionaryVersion?: number; // Versión del diccionario del usuario (0 = ninguno)
     manualMode?: boolean; // Habilita edición manual de checkbox e instructor
 }
 
@@ -1,8 +1,21 @@
 import { create } from 'zustand';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { supabase } from '@/lib/supabase';
 import { ZoomMeetingCandidate, MatchResult } from '../services/matcher';
 import { Schedule } from '@/features/schedules/utils/excel-parser';
 import { logger } from '@/lib/logger';
+import { isAppError } from '@/lib/app-error';
+import { learnAlias } from '../services/aliases';
+
+// Resultado de `run_matching` (mismo orden que los horarios enviados, sin el horario)
+type MatchOutcome = Omit<MatchResult, 'schedule' | 'originalState'>;
+
+// Payload del evento `matching://progress`
+interface MatchingProgress {
+    done: number;
+    total: number;
+}
 
 interface ZoomUser {
     id: string;
@@ -32,14 +45,12 @@ interface ZoomState {
     // Estado de Ejecución de Asignaciones
     isExecuting: boolean;
 
-    // Instancia del Worker
-    worker: Worker | null,
-
     // Acciones
     fetchZoomData: (options?: { force?: boolean; silent?: boolean }) => Promise<void>;
     fetchActiveMeetings: () => Promise<void>;
     triggerSync: () => Promise<void>;
     runMatching: (schedules: Schedule[]) => Promise<void>;
+    cancelMatching: () => Promise<void>;
     resolveConflict: (schedule: Schedule, selectedMeeting: ZoomMeetingCandidate) => void;
     createMeetings: (items: (string | { topic: string; startTime?: string })[], options?: { dailyOnly?: boolean }) => Promise<{ succeeded: number; failed: number; errors: string[] }>;
     updateMatchings: (updates: { meeting_id: string; topic?: string; schedule_for?: string }[]) => Promise<{ succeeded: number; failed: number; errors: string[] }>;
@@ -50,8 +61,8 @@ interface ZoomState {
     // Promesa de fetch activa para deduplicación
     _activeFetchPromise: Promise<void> | null;
 
-    // Método interno para inicializar el worker
-    _initWorker: (meetings: ZoomMeetingCandidate[], users: ZoomUser[]) => void;
+    // Método interno para inicializar el matcher nativo
+    _initMatcher: (meetings: ZoomMeetingCandidate[], users: ZoomUser[]) => Promise<void>;
 }
 
 export const useZoomStore = create<ZoomState>((set, get) => ({
@@ -66,10 +77,9 @@ export const useZoomStore = create<ZoomState>((set, get) => ({
     isLoadingData: false,
     isInitialized: false,
     isExecuting: false,
-    worker: null,
     _activeFetchPromise: null,
 
-    // Cache interno eliminado a favor del worker
+    // El índice de matching vive en el backend (comando init_matching)
 
     fetchZoomData: async (options = {}) => {
         const { force = false, silent = false } = options;
@@ -137,8 +147,8 @@ export const useZoomStore = create<ZoomState>((set, get) => ({
                     users: allUsers,
                 });
 
-                // Inicializar worker con los nuevos datos
-                get()._initWorker(allMeetings, allUsers);
+                // Inicializar matcher nativo con los nuevos datos
+                await get()._initMatcher(allMeetings, allUsers);
 
             } catch (error) {
                 console.error("Error fetching Zoom data:", error);
@@ -165,28 +175,10 @@ export const useZoomStore = create<ZoomState>((set, get) => ({
         }
     },
 
-    _initWorker: (meetings: ZoomMeetingCandidate[], users: ZoomUser[]) => {
-        const currentWorker = get().worker;
-        if (currentWorker) {
-            currentWorker.terminate();
-        }
-
-        // Crear nuevo worker
-        const worker = new Worker(new URL('../workers/match.worker.ts', import.meta.url), {
-            type: 'module'
-        });
-
-        worker.onmessage = (e) => {
-            if (e.data.type === 'READY') {
-                logger.debug('Matching Worker Ready');
-            } else if (e.data.type === 'ERROR') {
-                console.error('Matching Worker Error:', e.data.error);
-            }
-        };
-
-        // Enviar datos de inicialización
-        worker.postMessage({ type: 'INIT', meetings, users });
-        set({ worker });
+    _initMatcher: async (meetings: ZoomMeetingCandidate[], users: ZoomUser[]) => {
+        // Indexación costosa (normalización + índices difusos) ocurre en Rust
+        await invoke('init_matching', { meetings, users });
+        logger.debug('Native matcher ready');
     },
 
     triggerSync: async () => {
@@ -242,42 +234,60 @@ export const useZoomStore = create<ZoomState>((set, get) => ({
     },
 
     runMatching: async (schedules: Schedule[]) => {
-        const { worker, meetings, users } = get();
-        let activeWorker = worker;
-
-        // Si no hay worker (ej: recarga live), intentar revivirlo
-        if (!activeWorker) {
-            console.warn("Worker not found, re-initializing...");
-            get()._initWorker(meetings, users);
-            activeWorker = get().worker;
-            // Pequeña espera para asegurar que INIT se procese antes de MATCH (aunque postMessage garantiza orden)
-        }
+        const { meetings, users } = get();
 
-        if (!activeWorker) {
-            console.error("Failed to initialize worker for matching");
-            return;
-        }
+        // Progreso real del backend (los eventos pueden llegar desordenados entre hilos)
+        set({ syncProgress: 0 });
+        const unlisten = await listen<MatchingProgress>('matching://progress', ({ payload }) => {
+            const progress = Math.round((payload.done / payload.total) * 100);
+            if (progress > get().syncProgress) set({ syncProgress: progress });
+        });
 
-        return new Promise<void>((resolve, reject) => {
-            // Configurar listener temporal para esta ejecución
-            // Nota: En una app más compleja, usaríamos IDs de mensaje para correlacionar respuestas
-            const handleMessage = (e: MessageEvent) => {
-                if (e.data.type === 'MATCH_RESULT') {
-                    set({ matchResults: e.data.results });
-                    activeWorker?.removeEventListener('message', handleMessage);
-                    resolve();
-                } else if (e.data.type === 'ERROR') {
-                    console.error("Worker matching error:", e.data.error);
-                    activeWorker?.removeEventListener('message', handleMessage);
-                    reject(new Error(e.data.error));
-                }
-            };
+        // Sin traza: solo las filas ambiguas la necesitan y se piden aparte, así un mes
+        // completo no serializa todos los candidatos de cada fila
+        const run = (rows: Schedule[], trace: boolean) =>
+            invoke<MatchOutcome[]>('run_matching', { schedules: rows, options: { trace } });
+        const matchAll = async () => {
+            const results = await run(schedules, false);
+            const ambiguous = results.flatMap((outcome, i) => (outcome.status === 'ambiguous' ? [i] : []));
+            if (ambiguous.length > 0) {
+                const traced = await run(ambiguous.map((i) => schedules[i]), true);
+                ambiguous.forEach((rowIndex, j) => {
+                    results[rowIndex] = traced[j];
+                });
+            }
+            return results;
+        };
+
+        let outcomes: MatchOutcome[];
+        try {
+            outcomes = await matchAll();
+        } catch (error) {
+            // Cancelado (el usuario salió de la vista): se conservan los resultados anteriores
+            if (isAppError(error) && error.code === 'CANCELLED') {
+                logger.debug('Matching cancelled');
+                return;
+            }
+            // Si el matcher no está inicializado (ej: recarga live), re-indexar y reintentar
+            if (!isAppError(error) || error.code !== 'MATCHER_NOT_INITIALIZED') throw error;
+            console.warn("Matcher not initialized, re-initializing...");
+            await get()._initMatcher(meetings, users);
+            outcomes = await matchAll();
+        } finally {
+            unlisten();
+        }
 
-            activeWorker.addEventListener('message', handleMessage);
-            activeWorker.postMessage({ type: 'MATCH', schedules });
+        // El backend devuelve los resultados en el mismo orden que los horarios recibidos;
+        // se conservan las referencias originales (resolveConflict compara por identidad)
+        set({
+            matchResults: outcomes.map((outcome, i) => ({ ...outcome, schedule: schedules[i] })),
         });
     },
 
+    cancelMatching: async () => {
+        await invoke<boolean>('cancel_matching');
+    },
+
     resolveConflict: (schedule: Schedule, selectedMeeting: ZoomMeetingCandidate) => {
         const results = get().matchResults.map(r => {
             if (r.schedule === schedule) {
@@ -293,6 +303,11 @@ export const useZoomStore = create<ZoomState>((set, get) => ({
             return r;
         });
         set({ matchResults: results });
+
+        // Recordar la elección para las próximas ejecuciones del matching
+        learnAlias(schedule, selectedMeeting).catch(error => {
+            logger.warn('Could not save matching alias:', error);
+        });
     },
 
     executeAssignments: async (schedules?: Schedule[]) => {
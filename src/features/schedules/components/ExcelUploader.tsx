@@ -19,7 +19,7 @@ export function ExcelUploader() {
             const results = await Promise.all(promises);
 
             // Flatten arrays
-            const allSchedules = results.flat();
+            const allSchedules = results.flatMap((r) => r.schedules);
 
             setSchedules(allSchedules);
         } catch (error) {
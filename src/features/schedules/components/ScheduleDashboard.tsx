@@ -4,21 +4,22 @@ import { ScheduleDataTable } from "@schedules/components/table/ScheduleDataTable
 import { getScheduleColumns } from "@schedules/components/table/columns";
 import { Schedule } from "@schedules/utils/excel-parser";
 import { getUniqueScheduleKey } from "@schedules/utils/overlap-utils";
-import { BaseDirectory, exists, readTextFile, remove, writeTextFile } from "@tauri-apps/plugin-fs";
 import { toast } from "sonner";
 import { Button } from "@/components/ui/button";
 import { useSettings } from "@/components/settings-provider";
 import { RequirePermission } from "@/components/RequirePermission";
-import { AUTOSAVE_DEBOUNCE_MS, STORAGE_FILES } from "@/lib/constants";
-import { Bot, CalendarPlus, CalendarSearch } from "lucide-react";
+import { AUTOSAVE_DEBOUNCE_MS } from "@/lib/constants";
+import { clearDraft, getStorageRecovery, loadDraft, loadIncidences, saveDraft, saveIncidences } from "@/lib/local-store";
+import { Bot, CalendarPlus, CalendarSearch, History } from "lucide-react";
 import { SearchLinkModal } from "./modals/SearchLinkModal";
 import { CreateLinkModal } from "./modals/CreateLinkModal";
 import { AssignLinkModal } from "./modals/AssignLinkModal";
 import { useZoomStore } from "@/features/matching/stores/useZoomStore";
 import { MatchingService } from "@/features/matching/services/matcher";
-import { useScheduleStore } from "@/features/schedules/stores/useScheduleStore";
+import { useScheduleStore, type DailyIncidence } from "@/features/schedules/stores/useScheduleStore";
 import { ScheduleUpdateBanner } from "./ScheduleUpdateBanner";
 import { PublishToDbModal } from "./modals/PublishToDbModal";
+import { DraftHistoryModal } from "./modals/DraftHistoryModal";
 
 export function ScheduleDashboard() {
     const [isUploadModalOpen, setIsUploadModalOpen] = useState(false);
@@ -26,6 +27,7 @@ export function ScheduleDashboard() {
     const [isCreateModalOpen, setIsCreateModalOpen] = useState(false);
     const [isAssignModalOpen, setIsAssignModalOpen] = useState(false);
     const [isPublishModalOpen, setIsPublishModalOpen] = useState(false);
+    const [isHistoryModalOpen, setIsHistoryModalOpen] = useState(false);
 
     // Global Store
     const {
@@ -71,30 +73,35 @@ export function ScheduleDashboard() {
 
         const loadAutosave = async () => {
             try {
+                // La base local no se pudo abrir y se empezó con una nueva
+                const recovered = await getStorageRecovery();
+                if (recovered) {
+                    toast.warning("Local data could not be opened", {
+                        description: `Started with empty local data. The previous file was kept at ${recovered.backup}.`,
+                        duration: Infinity,
+                    });
+                }
+
                 // Load Base Schedules
-                const schedExists = await exists(STORAGE_FILES.SCHEDULES_DRAFT, { baseDir: BaseDirectory.AppLocalData });
-                if (schedExists) {
-                    const content = await readTextFile(STORAGE_FILES.SCHEDULES_DRAFT, { baseDir: BaseDirectory.AppLocalData });
-                    const parsedData = JSON.parse(content);
-                    if (Array.isArray(parsedData) && parsedData.length > 0) {
-                        setBaseSchedules(parsedData);
-                        if (parsedData.length > 0 && parsedData[0].date) {
-                            setActiveDate(parsedData[0].date);
-                        }
+                const draft = await loadDraft<Schedule>();
+                if (draft.schedules.length > 0) {
+                    setBaseSchedules(draft.schedules);
+                    if (draft.schedules[0].date) {
+                        setActiveDate(draft.schedules[0].date);
+                    }
+                    if (draft.source === "snapshot" && draft.snapshotAt) {
+                        toast.warning("Schedule recovered from backup", {
+                            description: `The last autosave was unreadable. Restored the copy from ${new Date(draft.snapshotAt).toLocaleString()}.`,
+                        });
+                    } else {
                         toast.success("Schedule restored");
                     }
                 }
 
                 // Load Incidences
-                const incExists = await exists(STORAGE_FILES.INCIDENCES_LOG, { baseDir: BaseDirectory.AppLocalData });
-                if (incExists) {
-                    const content = await readTextFile(STORAGE_FILES.INCIDENCES_LOG, { baseDir: BaseDirectory.AppLocalData });
-                    const parsedData = JSON.parse(content);
-                    if (Array.isArray(parsedData)) {
-                        setIncidences(parsedData);
-                        // Ensure we don't toast twice if both succeed, maybe consolidate?
-                        // toast.success("Incidences restored"); 
-                    }
+                const storedIncidences = await loadIncidences<DailyIncidence>();
+                if (storedIncidences.length > 0) {
+                    setIncidences(storedIncidences);
                 }
             } catch (error) {
                 console.error("Failed to load autosave:", error);
@@ -117,14 +124,9 @@ export function ScheduleDashboard() {
         autoSaveTimeout.current = setTimeout(async () => {
             try {
                 if (baseSchedules.length > 0) {
-                    await writeTextFile(STORAGE_FILES.SCHEDULES_DRAFT, JSON.stringify(baseSchedules, null, 2), {
-                        baseDir: BaseDirectory.AppLocalData,
-                    });
+                    await saveDraft(baseSchedules);
                 } else {
-                    const fileExists = await exists(STORAGE_FILES.SCHEDULES_DRAFT, { baseDir: BaseDirectory.AppLocalData });
-                    if (fileExists) {
-                        await remove(STORAGE_FILES.SCHEDULES_DRAFT, { baseDir: BaseDirectory.AppLocalData });
-                    }
+                    await clearDraft();
                 }
             } catch (error) {
                 console.error("Auto-save failed:", error);
@@ -155,9 +157,7 @@ export function ScheduleDashboard() {
 
         incidencesSaveTimeout.current = setTimeout(async () => {
             try {
-                await writeTextFile(STORAGE_FILES.INCIDENCES_LOG, JSON.stringify(incidences, null, 2), {
-                    baseDir: BaseDirectory.AppLocalData,
-                });
+                await saveIncidences(incidences);
             } catch (error) {
                 console.error("Incidences save failed:", error);
             }
@@ -231,6 +231,15 @@ export function ScheduleDashboard() {
         }
     }, [meetings, users, schedules, fetchActiveMeetings]);
 
+    // Guarda la carga de inmediato: el borrador anterior queda en el historial como
+    // snapshot de la carga, sin que los autoguardados siguientes lo desplacen
+    const keepPreviousDraft = (next: Schedule[]) => {
+        if (!settings.autoSave || next.length === 0) return;
+        saveDraft(next, "upload").catch((error) => {
+            console.error("Failed to save uploaded schedules:", error);
+        });
+    };
+
     const handleUploadComplete = (newData: Schedule[]) => {
         // ... (Logic adapted to setBaseSchedules)
         const internalKeys = new Set<string>();
@@ -249,6 +258,7 @@ export function ScheduleDashboard() {
 
         if (settings.clearScheduleOnLoad) {
             setBaseSchedules(deduplicatedNewData);
+            keepPreviousDraft(deduplicatedNewData);
             const msg = internalDuplicates > 0
                 ? `Loaded ${deduplicatedNewData.length} schedules (${internalDuplicates} internal duplicates removed)`
                 : `Loaded ${deduplicatedNewData.length} schedules`;
@@ -271,7 +281,9 @@ export function ScheduleDashboard() {
             return;
         }
 
-        setBaseSchedules([...baseSchedules, ...uniqueNewData]);
+        const merged = [...baseSchedules, ...uniqueNewData];
+        setBaseSchedules(merged);
+        keepPreviousDraft(merged);
         toast.success(`Added ${uniqueNewData.length} new schedules`);
     };
 
@@ -283,15 +295,18 @@ export function ScheduleDashboard() {
         });
     };
 
+    const handleRestoreDraft = (restored: Schedule[]) => {
+        setBaseSchedules(restored);
+        setActiveDate(restored.length > 0 && restored[0].date ? restored[0].date : null);
+        useZoomStore.setState({ matchResults: [] });
+    };
+
     const handleClearSchedule = async () => {
         try {
             setBaseSchedules([]);
             setActiveDate(null); // Reset active date
             useZoomStore.setState({ matchResults: [] });
-            const fileExists = await exists(STORAGE_FILES.SCHEDULES_DRAFT, { baseDir: BaseDirectory.AppLocalData });
-            if (fileExists) {
-                await remove(STORAGE_FILES.SCHEDULES_DRAFT, { baseDir: BaseDirectory.AppLocalData });
-            }
+            await clearDraft();
             toast.success("Schedule cleared");
         } catch (error) {
             console.error("Error clearing schedule:", error);
@@ -319,8 +334,14 @@ export function ScheduleDashboard() {
                     </div>
                 </div>
                 <div className="flex gap-2">
-
-
+                    <Button
+                        size="sm"
+                        variant="outline"
+                        onClick={() => setIsHistoryModalOpen(true)}
+                    >
+                        <History />
+                        History
+                    </Button>
 
                     <RequirePermission permission="meetings.search">
                         <Button
@@ -388,6 +409,12 @@ export function ScheduleDashboard() {
                 onOpenChange={setIsPublishModalOpen}
             />
 
+            <DraftHistoryModal
+                open={isHistoryModalOpen}
+                onOpenChange={setIsHistoryModalOpen}
+                onRestore={handleRestoreDraft}
+            />
+
             {/* Feature Modals */}
             <SearchLinkModal
                 open={isSearchModalOpen}
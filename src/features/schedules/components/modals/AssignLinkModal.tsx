@@ -7,6 +7,9 @@ import { ScheduleDataTable } from "@schedules/components/table/ScheduleDataTable
 import { getAssignmentColumns, AssignmentRow } from "@schedules/components/table/assignment-columns";
 import { Schedule } from "@schedules/utils/excel-parser";
 import { useZoomStore } from "@/features/matching/stores/useZoomStore";
+import { learnAlias, undoLearnedAlias, type LearnedAlias } from "@/features/matching/services/aliases";
+import { checkHostConflicts, planHostAllocation, HostAssignment } from "@/features/matching/services/hosts";
+import { logger } from "@/lib/logger";
 import { useInstructors } from "@/features/schedules/hooks/useInstructors";
 import { useHostMap } from "@/features/schedules/hooks/useHostMap";
 import { Loader2 } from "lucide-react";
@@ -21,12 +24,14 @@ interface AssignLinkModalProps {
 }
 
 export function AssignLinkModal({ open, onOpenChange, schedules }: AssignLinkModalProps) {
-    const { fetchZoomData, runMatching, matchResults, meetings, isLoadingData, executeAssignments, isExecuting } = useZoomStore();
+    const { fetchZoomData, runMatching, cancelMatching, syncProgress, matchResults, meetings, isLoadingData, executeAssignments, isExecuting } = useZoomStore();
     const instructorsList = useInstructors();
     const [isMatching, setIsMatching] = useState(false);
     const [rowSelection, setRowSelection] = useState<Record<string, boolean>>({});
     const [includeAssigned, setIncludeAssigned] = useState(false);
     const prevIncludeAssigned = useRef(false);
+    // Alias aprendidos por las selecciones de esta sesión, por fila (para deshacerlas)
+    const learnedAliases = useRef(new Map<string, Promise<LearnedAlias | null>>());
 
     // Limpiar selección y resetear switch cuando el modal se cierra
     useEffect(() => {
@@ -62,6 +67,14 @@ export function AssignLinkModal({ open, onOpenChange, schedules }: AssignLinkMod
         }
     }, [open]);
 
+    // Cancelar el matching en curso al cerrar el modal o salir de la vista
+    useEffect(() => {
+        if (!open) return;
+        return () => {
+            cancelMatching().catch(error => console.error("Cancel matching failed:", error));
+        };
+    }, [open, cancelMatching]);
+
     // Función para refrescar los datos y re-ejecutar el matching
     const handleRefresh = async () => {
         setIsMatching(true);
@@ -178,6 +191,23 @@ export function AssignLinkModal({ open, onOpenChange, schedules }: AssignLinkMod
             return r;
         });
         useZoomStore.setState({ matchResults: updatedResults });
+
+        // Recordar la elección como alias para las próximas ejecuciones. Si la fila ya tenía
+        // una selección en esta sesión, se conserva el alias que había antes de la primera.
+        const selected = updatedResults.find(r => getRowId(r.schedule) === rowId);
+        if (selected) {
+            const earlier = learnedAliases.current.get(rowId);
+            const learning = learnAlias(selected.schedule, candidate)
+                .then(async (learned) => {
+                    const first = earlier ? await earlier : null;
+                    return first ? { alias: learned.alias, previous: first.previous } : learned;
+                })
+                .catch(error => {
+                    logger.warn('Could not save matching alias:', error);
+                    return null;
+                });
+            learnedAliases.current.set(rowId, learning);
+        }
     };
 
     // Handler para deseleccionar un candidato (volver a ambiguous)
@@ -208,6 +238,17 @@ export function AssignLinkModal({ open, onOpenChange, schedules }: AssignLinkMod
             return r;
         });
         useZoomStore.setState({ matchResults: updatedResults });
+
+        // Solo se deshace el alias que creó (o reemplazó) una selección de esta sesión
+        const learning = learnedAliases.current.get(rowId);
+        if (learning) {
+            learnedAliases.current.delete(rowId);
+            learning
+                .then(learned => learned && undoLearnedAlias(learned))
+                .catch(error => {
+                    logger.warn('Could not remove matching alias:', error);
+                });
+        }
     };
 
     // Handler para resetear fila
@@ -256,6 +297,7 @@ export function AssignLinkModal({ open, onOpenChange, schedules }: AssignLinkMod
                 originalSchedule: r.schedule,
                 matchedCandidate: r.matchedCandidate,
                 ambiguousCandidates: r.ambiguousCandidates,
+                trace: r.trace,
                 manualMode: r.manualMode,
                 found_instructor: r.found_instructor
             };
@@ -323,6 +365,76 @@ export function AssignLinkModal({ open, onOpenChange, schedules }: AssignLinkMod
         row.found_instructor
     );
 
+    // Filas con meeting y anfitrión: las seleccionadas pasan al instructor encontrado,
+    // el resto conserva el anfitrión actual del meeting
+    const getHostRows = () => {
+        const eligibleIds = new Set(eligibleRows.map(row => row.id));
+        const toAssignment = (row: AssignmentRow, hostId: string): HostAssignment => ({
+            ...row.originalSchedule,
+            meeting_id: row.meetingId,
+            host_id: hostId,
+        });
+        const movableRows = eligibleRows.filter(row => row.found_instructor);
+        const fixedRows = tableData.filter(row =>
+            !eligibleIds.has(row.id) && row.meetingId && row.meetingId !== '-' && row.matchedCandidate?.host_id
+        );
+        return {
+            movableRows,
+            movable: movableRows.map(row => toAssignment(row, row.found_instructor!.id)),
+            fixed: fixedRows.map(row => toAssignment(row, row.matchedCandidate!.host_id)),
+        };
+    };
+
+    // Proponer anfitriones sin cruces para las filas seleccionadas; las reasignaciones se
+    // aplican como cambios manuales para que el operador las revise antes de ejecutar
+    const handlePlanHosts = async () => {
+        const { movableRows, movable, fixed } = getHostRows();
+        if (movable.length === 0) {
+            toast.error('No eligible meetings selected.');
+            return;
+        }
+
+        try {
+            const plan = await planHostAllocation(movable, fixed, hostMap);
+            const zoomUsers = useZoomStore.getState().users;
+            const plannedHosts = new Map<string, NonNullable<MatchResult['found_instructor']>>();
+            for (const meeting of plan.meetings) {
+                if (meeting.outcome !== 'reassigned') continue;
+                const user = zoomUsers.find(u => u.id === meeting.hostId);
+                if (!user) continue;
+                for (const index of meeting.rows) {
+                    plannedHosts.set(movableRows[index].id, {
+                        id: user.id,
+                        email: user.email,
+                        display_name: user.display_name || `${user.first_name} ${user.last_name}`
+                    });
+                }
+            }
+
+            const updatedResults = useZoomStore.getState().matchResults.map(r => {
+                const host = plannedHosts.get(getRowId(r.schedule));
+                if (!host) return r;
+                const { originalState: existingBackup, ...currentState } = r;
+                return {
+                    ...r,
+                    originalState: existingBackup || currentState,
+                    found_instructor: host,
+                    status: 'manual' as const,
+                    manualMode: true,
+                    reason: `Host planned: ${host.display_name}`
+                };
+            });
+            useZoomStore.setState({ matchResults: updatedResults });
+
+            toast.info(
+                `Host plan: ${plan.preferred} kept, ${plan.reassigned} reassigned, ${plan.unassigned} without a free host. Review the rows before executing.`
+            );
+        } catch (error) {
+            logger.error('Host planning failed:', error);
+            toast.error('Could not plan hosts.');
+        }
+    };
+
     return (
         <Dialog open={open} onOpenChange={onOpenChange}>
             <DialogContent className="max-w-7xl! max-h-[85vh] flex flex-col">
@@ -344,7 +456,7 @@ export function AssignLinkModal({ open, onOpenChange, schedules }: AssignLinkMod
                                     {isLoadingData ? "Loading Zoom data..." : "Matching schedules..."}
                                 </p>
                                 <p className="text-xs text-muted-foreground">
-                                    {isLoadingData ? "Fetching meetings and users" : "Analyzing and matching meetings"}
+                                    {isLoadingData ? "Fetching meetings and users" : `Analyzing and matching meetings (${syncProgress}%)`}
                                 </p>
                             </div>
                         </div>
@@ -442,6 +554,13 @@ export function AssignLinkModal({ open, onOpenChange, schedules }: AssignLinkMod
                         <Button variant="outline" onClick={() => onOpenChange(false)} disabled={isExecuting}>
                             Cancel
                         </Button>
+                        <Button
+                            variant="outline"
+                            onClick={handlePlanHosts}
+                            disabled={isExecuting || isLoadingData || isMatching || eligibleRows.length === 0}
+                        >
+                            Plan hosts
+                        </Button>
                         <Button
                             onClick={async () => {
                                 const schedules = eligibleRows.map(row => row.originalSchedule);
@@ -451,6 +570,26 @@ export function AssignLinkModal({ open, onOpenChange, schedules }: AssignLinkMod
                                     return;
                                 }
 
+                                // Verificar que ningún anfitrión quede con dos meetings a la vez
+                                const { movable, fixed } = getHostRows();
+                                try {
+                                    const report = await checkHostConflicts([...movable, ...fixed], hostMap);
+                                    const blocking = report.conflicts.filter(c =>
+                                        c.rows.some(r => r.row < movable.length)
+                                    );
+                                    if (blocking.length > 0) {
+                                        const [first] = blocking;
+                                        const free = first.freeHosts.slice(0, 3).map(h => h.name).join(', ');
+                                        toast.error(
+                                            `${blocking.length} host conflict(s): ${first.hostName} has ${first.rows.length} overlapping meetings on ${first.date} (${first.startTime}-${first.endTime}).` +
+                                            (free ? ` Free hosts: ${free}.` : ' No free hosts in that time range.')
+                                        );
+                                        return;
+                                    }
+                                } catch (error) {
+                                    logger.warn('Host conflict check failed:', error);
+                                }
+
                                 const result = await executeAssignments(schedules);
                                 if (result.succeeded > 0) {
                                     toast.success(`${result.succeeded} meetings updated successfully`);
@@ -0,0 +1,205 @@
+import { useEffect, useState } from "react";
+import { Dialog, DialogContent, DialogDescription, DialogFooter, DialogHeader, DialogTitle } from "@/components/ui/dialog";
+import { Button } from "@/components/ui/button";
+import { Badge } from "@/components/ui/badge";
+import { Loader2, RotateCcw } from "lucide-react";
+import { toast } from "sonner";
+import { cn } from "@/lib/utils";
+import { Schedule } from "@schedules/utils/excel-parser";
+import {
+    listDraftHistory,
+    previewDraftSnapshot,
+    restoreDraftSnapshot,
+    type DraftChanges,
+    type DraftDiff,
+    type DraftSnapshot,
+    type SnapshotReason,
+} from "@/lib/local-store";
+
+interface DraftHistoryModalProps {
+    open: boolean;
+    onOpenChange: (open: boolean) => void;
+    onRestore: (schedules: Schedule[]) => void;
+}
+
+// Filas de cada grupo que se muestran en la vista previa
+const PREVIEW_LIMIT = 5;
+
+// Los autoguardados no llevan etiqueta
+const REASON_LABELS: Partial<Record<SnapshotReason, string>> = {
+    upload: "Before upload",
+    restore: "Before restore",
+    manual: "Saved",
+    import: "Imported",
+};
+
+const describeRow = (s: Schedule) => `${s.date} ${s.start_time}-${s.end_time} · ${s.program} · ${s.instructor || "No instructor"}`;
+
+function ChangeBadges({ changes }: { changes: DraftChanges }) {
+    const items = [
+        { label: "added", value: changes.added },
+        { label: "removed", value: changes.removed },
+        { label: "instructors", value: changes.instructorsChanged },
+        { label: "edited", value: changes.modified },
+    ].filter((item) => item.value > 0);
+
+    if (items.length === 0) return <span className="text-xs text-muted-foreground">No changes</span>;
+    return (
+        <div className="flex flex-wrap gap-1">
+            {items.map((item) => (
+                <Badge key={item.label} variant="secondary" className="text-xs">
+                    {item.value} {item.label}
+                </Badge>
+            ))}
+        </div>
+    );
+}
+
+function PreviewGroup({ title, rows }: { title: string; rows: string[] }) {
+    if (rows.length === 0) return null;
+    return (
+        <div className="space-y-1">
+            <p className="text-sm font-medium">{title} ({rows.length})</p>
+            <ul className="text-xs text-muted-foreground space-y-0.5">
+                {rows.slice(0, PREVIEW_LIMIT).map((row, i) => (
+                    <li key={i} className="truncate">{row}</li>
+                ))}
+                {rows.length > PREVIEW_LIMIT && <li>and {rows.length - PREVIEW_LIMIT} more...</li>}
+            </ul>
+        </div>
+    );
+}
+
+export function DraftHistoryModal({ open, onOpenChange, onRestore }: DraftHistoryModalProps) {
+    const [snapshots, setSnapshots] = useState<DraftSnapshot[]>([]);
+    const [selectedId, setSelectedId] = useState<number | null>(null);
+    const [preview, setPreview] = useState<DraftDiff<Schedule> | null>(null);
+    const [isLoading, setIsLoading] = useState(false);
+    const [isRestoring, setIsRestoring] = useState(false);
+
+    // Cargar la línea de tiempo al abrir
+    useEffect(() => {
+        if (!open) return;
+        setSelectedId(null);
+        setPreview(null);
+        setIsLoading(true);
+        listDraftHistory()
+            .then(setSnapshots)
+            .catch((error) => {
+                console.error("Failed to load draft history", error);
+                toast.error("Failed to load draft history");
+            })
+            .finally(() => setIsLoading(false));
+    }, [open]);
+
+    // Vista previa contra el borrador actual
+    useEffect(() => {
+        if (selectedId === null) return;
+        setPreview(null);
+        previewDraftSnapshot<Schedule>(selectedId)
+            .then(setPreview)
+            .catch((error) => {
+                console.error("Failed to preview snapshot", error);
+                toast.error("Failed to preview snapshot");
+            });
+    }, [selectedId]);
+
+    const handleRestore = async () => {
+        if (selectedId === null) return;
+        setIsRestoring(true);
+        try {
+            const restored = await restoreDraftSnapshot<Schedule>(selectedId);
+            onRestore(restored);
+            toast.success("Schedule restored", {
+                description: `${restored.length} rows. The previous version stays in the history.`,
+            });
+            onOpenChange(false);
+        } catch (error) {
+            console.error("Failed to restore snapshot", error);
+            toast.error("Failed to restore snapshot");
+        } finally {
+            setIsRestoring(false);
+        }
+    };
+
+    const isUnchanged = preview !== null
+        && preview.added.length + preview.removed.length + preview.instructorChanges.length + preview.modified.length === 0;
+
+    return (
+        <Dialog open={open} onOpenChange={onOpenChange}>
+            <DialogContent className="max-w-4xl! max-h-[85vh] flex flex-col">
+                <DialogHeader>
+                    <DialogTitle>Schedule History</DialogTitle>
+                    <DialogDescription>
+                        Autosaved versions of the schedule, plus the ones kept before an upload or restore and manual saves. Restoring replaces the current schedule.
+                    </DialogDescription>
+                </DialogHeader>
+
+                <div className="flex-1 grid grid-cols-2 gap-4 min-h-[360px] overflow-hidden">
+                    <div className="overflow-auto border rounded-lg">
+                        {isLoading ? (
+                            <div className="flex items-center justify-center h-full">
+                                <Loader2 className="h-5 w-5 animate-spin" />
+                            </div>
+                        ) : snapshots.length === 0 ? (
+                            <p className="p-4 text-sm text-muted-foreground">No saved versions yet.</p>
+                        ) : (
+                            snapshots.map((snapshot) => (
+                                <button
+                                    key={snapshot.id}
+                                    type="button"
+                                    onClick={() => setSelectedId(snapshot.id)}
+                                    className={cn(
+                                        "w-full text-left px-3 py-2 border-b last:border-b-0 space-y-1 hover:bg-muted/50",
+                                        selectedId === snapshot.id && "bg-muted"
+                                    )}
+                                >
+                                    <div className="flex justify-between text-sm">
+                                        <span className="font-medium">
+                                            {new Date(snapshot.createdAt).toLocaleString()}
+                                            {REASON_LABELS[snapshot.reason] && (
+                                                <Badge variant="outline" className="ml-2 text-xs">{REASON_LABELS[snapshot.reason]}</Badge>
+                                            )}
+                                        </span>
+                                        <span className="text-muted-foreground">{snapshot.rowCount} rows</span>
+                                    </div>
+                                    <ChangeBadges changes={snapshot.changes} />
+                                </button>
+                            ))
+                        )}
+                    </div>
+
+                    <div className="overflow-auto border rounded-lg p-3 space-y-3">
+                        {selectedId === null ? (
+                            <p className="text-sm text-muted-foreground">Select a version to see what restoring it would change.</p>
+                        ) : preview === null ? (
+                            <div className="flex items-center justify-center h-full">
+                                <Loader2 className="h-5 w-5 animate-spin" />
+                            </div>
+                        ) : isUnchanged ? (
+                            <p className="text-sm text-muted-foreground">This version matches the current schedule.</p>
+                        ) : (
+                            <>
+                                <PreviewGroup title="Rows restored" rows={preview.added.map(describeRow)} />
+                                <PreviewGroup title="Rows removed" rows={preview.removed.map(describeRow)} />
+                                <PreviewGroup
+                                    title="Instructor changes"
+                                    rows={preview.instructorChanges.map((c) => `${describeRow(c.before)} → ${c.after.instructor || "No instructor"}`)}
+                                />
+                                <PreviewGroup title="Other edits" rows={preview.modified.map((c) => describeRow(c.after))} />
+                            </>
+                        )}
+                    </div>
+                </div>
+
+                <DialogFooter>
+                    <Button variant="outline" onClick={() => onOpenChange(false)}>Close</Button>
+                    <Button onClick={handleRestore} disabled={selectedId === null || isRestoring || isUnchanged}>
+                        {isRestoring ? <Loader2 className="animate-spin" /> : <RotateCcw />}
+                        Restore
+                    </Button>
+                </DialogFooter>
+            </DialogContent>
+        </Dialog>
+    );
+}
@@ -11,11 +11,16 @@ import {
     DialogTitle,
 } from "@/components/ui/dialog";
 import { Button } from "@/components/ui/button";
-import { parseExcelFile, Schedule } from "@schedules/utils/excel-parser";
+import { describeSheetError, parseExcelFile, type ParsedWorkbook, Schedule } from "@schedules/utils/excel-parser";
+import { secureOpenSchedules } from "@/lib/secure-export";
+import { describeAppError } from "@/lib/app-error";
 
+// Archivos arrastrados se parsean al procesar; los elegidos en el diálogo nativo ya vienen parseados
 interface FileInfo {
-    file: File;
     name: string;
+    size: number;
+    file?: File;
+    parsed?: ParsedWorkbook;
 }
 
 interface UploadModalProps {
@@ -46,7 +51,7 @@ export function UploadModal({
 
     // Validate and add files to selection
     const addFiles = useCallback(
-        (newFiles: File[]) => {
+        (newFiles: FileInfo[]) => {
             const existingNames = new Set(selectedFiles.map((f) => f.name));
             let duplicateCount = 0;
             let invalidCount = 0;
@@ -62,7 +67,7 @@ export function UploadModal({
                     invalidCount++;
                     continue;
                 }
-                validNewFiles.push({ file, name: file.name });
+                validNewFiles.push(file);
             }
 
             if (duplicateCount > 0) {
@@ -124,30 +129,33 @@ export function UploadModal({
 
             const files = Array.from(e.dataTransfer.files);
             if (files.length > 0) {
-                addFiles(files);
+                addFiles(files.map((file) => ({ name: file.name, size: file.size, file })));
             }
         },
         [isProcessing, addFiles]
     );
 
-    // Select files via input dialog
-    const handleSelectFiles = useCallback(() => {
+    // Select files via native dialog (Rust validates type/size and parses them)
+    const handleSelectFiles = useCallback(async () => {
         if (selectedFiles.length >= MAX_FILES) {
             toast.error(`Maximum ${MAX_FILES} files allowed`);
             return;
         }
 
-        const input = document.createElement("input");
-        input.type = "file";
-        input.accept = ".xlsx";
-        input.multiple = true;
-        input.onchange = (e) => {
-            const target = e.target as HTMLInputElement;
-            if (target.files) {
-                addFiles(Array.from(target.files));
+        setIsProcessing(true);
+        try {
+            const opened = await secureOpenSchedules({
+                title: "Select Excel Files",
+                filters: [{ name: "Excel Workbook", extensions: ["xlsx"] }],
+            });
+            if (opened.length > 0) {
+                addFiles(opened.map(({ name, size, schedules, errors }) => ({ name, size, parsed: { schedules, errors } })));
             }
-        };
-        input.click();
+        } catch {
+            // secureOpenSchedules ya mostró el error
+        } finally {
+            setIsProcessing(false);
+        }
     }, [selectedFiles.length, addFiles]);
 
     // Process files when Done is clicked
@@ -159,21 +167,31 @@ export function UploadModal({
 
         setIsProcessing(true);
         try {
-            // Process all files in parallel
-            const promises = selectedFiles.map((f) => parseExcelFile(f.file));
+            // Parse dropped files in parallel (in Rust, off the UI thread)
+            const promises = selectedFiles.map((f) => f.parsed ?? parseExcelFile(f.file!));
             const results = await Promise.all(promises);
 
             // Flatten arrays
-            const allSchedules = results.flat();
+            const allSchedules = results.flatMap((r) => r.schedules);
+            const errors = results.flatMap((r, i) =>
+                r.errors.map((e) => `${selectedFiles[i].name}: ${describeSheetError(e)}`)
+            );
 
             toast.success(`Successfully parsed ${allSchedules.length} schedules`);
+            if (errors.length > 0) {
+                console.warn("Rows skipped while parsing:", errors);
+                toast.warning(`${errors.length} row(s) or sheet(s) could not be read`, {
+                    description: errors.slice(0, 3).join("\n") + (errors.length > 3 ? `\nand ${errors.length - 3} more...` : ""),
+                });
+            }
 
             onUploadComplete(allSchedules);
             setSelectedFiles([]);
             onOpenChange(false);
         } catch (error) {
             console.error("Error processing files:", error);
-            toast.error("Error processing files. Check console.");
+            const { message, fix } = describeAppError(error);
+            toast.error("Error processing files: " + message, { description: fix });
         } finally {
             setIsProcessing(false);
         }
@@ -272,7 +290,7 @@ export function UploadModal({
                                             <div className="flex flex-col">
                                                 <span className="truncate text-sm font-medium">{file.name}</span>
                                                 <span className="text-xs text-muted-foreground">
-                                                    {formatBytes(file.file.size)}
+                                                    {formatBytes(file.size)}
                                                 </span>
                                             </div>
                                             <Button
@@ -6,6 +6,7 @@ import { MoreHorizontal, Hand, RotateCcw, XCircle } from "lucide-react";
 import { Button } from "@/components/ui/button";
 import { DropdownMenu, DropdownMenuContent, DropdownMenuItem, DropdownMenuTrigger } from "@/components/ui/dropdown-menu";
 import { ZoomMeetingCandidate } from "@/features/matching/services/matcher";
+import type { MatchTrace } from "@/features/matching/scoring/types";
 import { StatusCell } from "./cells/StatusCell";
 import { InstructorCell } from "./cells/InstructorCell";
 
@@ -23,6 +24,7 @@ export interface AssignmentRow extends Schedule {
     originalSchedule: Schedule; // Mantener referencia a los datos originales
     matchedCandidate?: ZoomMeetingCandidate; // Assigned meeting details
     ambiguousCandidates?: ZoomMeetingCandidate[]; // List of ambiguous options
+    trace?: MatchTrace; // Traza del matching (por qué perdió cada candidato)
     manualMode?: boolean; // Habilita edición manual de checkbox e instructor
     found_instructor?: { id: string; email: string; display_name: string }; // Instructor encontrado en Zoom
 }
@@ -23,6 +23,7 @@ export function StatusCell({
     const status = row.getValue("status") as string;
     const matched = row.original.matchedCandidate;
     const ambiguous = row.original.ambiguousCandidates;
+    const traceById = new Map(row.original.trace?.candidates.map(c => [c.meetingId, c]));
 
     let badge;
     if (status === 'assigned') {
@@ -117,6 +118,7 @@ export function StatusCell({
                                 <div className="space-y-2 max-h-[280px] overflow-y-auto no-scrollbar">
                                     {ambiguous.map((cand, i) => {
                                         const isSelected = matched?.meeting_id === cand.meeting_id;
+                                        const trace = traceById.get(cand.meeting_id);
                                         return (
                                             <div
                                                 key={i}
@@ -140,6 +142,16 @@ export function StatusCell({
                                                             <span className="text-nowrap">ID: {cand.meeting_id}</span>
                                                             <span className="truncate">Host: {hostMap.get(cand.host_id) || cand.host_id}</span>
                                                         </div>
+                                                        {trace && (
+                                                            <div className="text-xs text-muted-foreground mt-1.5 space-y-0.5">
+                                                                <div>Score: {trace.finalScore} ({trace.retrieval.replace('_', ' ')} match)</div>
+                                                                {trace.penalties.map((p, j) => (
+                                                                    <div key={j} className="font-mono" title={p.reason}>
+                                                                        {p.points} {p.name}
+                                                                    </div>
+                                                                ))}
+                                                            </div>
+                                                        )}
                                                     </div>
                                                     {isSelected && (
                                                         <Badge variant="outline" className="border-green-600 text-green-600 bg-green-50 dark:bg-green-950/20 dark:border-green-500 dark:text-green-400">
@@ -1,10 +1,9 @@
 import { useMemo, useState } from "react";
-import { secureSaveFile } from "@/lib/secure-export";
+import { secureExportExcel } from "@/lib/secure-export";
 import { type Table } from "@tanstack/react-table";
 import { Search, X, ChevronDown, User, CalendarCheck, Download, Save, Trash2, XCircle, RefreshCw, BadgeCheckIcon, HelpCircle, Hand, Clock1, Clock2, Clock3, Clock4, Clock5, Clock6, Clock7, Clock8, Clock9, Clock10, Clock11, Clock12, Radio, Loader2, AlertTriangle, CloudUpload } from "lucide-react";
-import { utils, write } from "xlsx";
 import { toast } from "sonner";
-import { writeTextFile, BaseDirectory } from "@tauri-apps/plugin-fs";
+import { saveDraft } from "@/lib/local-store";
 
 import { Button } from "@/components/ui/button";
 import { InputGroup, InputGroupAddon, InputGroupInput } from "@/components/ui/input-group";
@@ -214,52 +213,25 @@ export function DataTableToolbar<TData>({
         try {
             const data = getActionData();
 
-            // Helper to prevent CSV Injection
-            const sanitize = (val: unknown): unknown => {
-                if (typeof val === 'string' && /^[=+\-@]/.test(val)) {
-                    return `'${val}`;
-                }
-                return val;
-            };
-
-            const dataToExport = data.map((item) => {
-                return {
-                    ...item,
-                    instructor: sanitize(item.instructor) as string,
-                    program: sanitize(item.program) as string,
-                    branch: sanitize(item.branch) as string,
-                    start_time: formatTimeTo12Hour(item.start_time),
-                    end_time: formatTimeTo12Hour(item.end_time),
-                };
-            });
-
-            const ws = utils.json_to_sheet(dataToExport);
-            const wb = utils.book_new();
-            utils.book_append_sheet(wb, ws, "Schedule");
-
             const now = new Date();
             const dateStr = now.toISOString().replace(/[-:]/g, "").replace("T", "-").slice(0, 15);
             const defaultName = `schedule-export-${dateStr}.xlsx`;
 
-            // Crear buffer de Excel
-            const excelBuffer = write(wb, { bookType: "xlsx", type: "array" });
-
-            // Exportación Segura (Única vía)
-            const saved = await secureSaveFile({
+            // Rust genera el libro (horas en 12h, celdas saneadas y cruces resaltados)
+            const saved = await secureExportExcel({
                 title: "Guardar Como",
                 defaultName: defaultName,
-                content: new Uint8Array(excelBuffer),
+                kind: "schedule",
+                rows: data,
                 openAfterExport: settings.openAfterExport
             });
 
             if (saved) {
                 toast.success("Schedule exported to Excel successfully");
             }
-
-
         } catch (error) {
+            // secureExportExcel ya mostró el error
             console.error(error);
-            toast.error("Failed to export Excel");
         }
     };
 
@@ -267,15 +239,13 @@ export function DataTableToolbar<TData>({
         try {
             const dataToSave = fullData as Schedule[];
 
-            // Guardar en AppLocalData (Secure Autosave)
-            await writeTextFile("schedule_autosave.json", JSON.stringify(dataToSave, null, 2), {
-                baseDir: BaseDirectory.AppLocalData,
-            });
+            // Guardar como borrador (base de datos local, queda en el historial)
+            await saveDraft(dataToSave, "manual");
 
             toast.success("Schedule saved to internal storage successfully");
         } catch (error) {
             console.error(error);
-            toast.error("Failed to save schedule to AppData");
+            toast.error("Failed to save schedule to internal storage");
         }
     };
 
@@ -1,3 +1,4 @@
+import { useState } from "react";
 import { Label } from "@/components/ui/label";
 import { Switch } from "@/components/ui/switch";
 import { Monitor, Moon, Sun } from "lucide-react";
@@ -23,41 +24,31 @@ import {
 } from "@/components/ui/alert-dialog";
 import { useTheme } from "@/components/theme-provider";
 import { useSettings } from "@/components/settings-provider";
-import { BaseDirectory, exists, remove } from "@tauri-apps/plugin-fs";
 import { toast } from "sonner";
-import { STORAGE_FILES } from "@/lib/constants";
+import { clearAppSettings, clearDraft } from "@/lib/local-store";
 import { useTranslation } from "react-i18next";
+import { ManageAliasesModal } from "@/features/matching/components/ManageAliasesModal";
 
 
 export function SettingsPage() {
     const { t, i18n } = useTranslation();
     const { setTheme } = useTheme();
     const { settings, updateSetting } = useSettings();
+    const [aliasesOpen, setAliasesOpen] = useState(false);
 
     const handleClearCache = async () => {
         try {
             let filesDeleted = 0;
 
             // Eliminar autoguardado de horarios
-            const autosaveExists = await exists(STORAGE_FILES.SCHEDULES_DRAFT, { baseDir: BaseDirectory.AppLocalData });
-            if (autosaveExists) {
-                await remove(STORAGE_FILES.SCHEDULES_DRAFT, { baseDir: BaseDirectory.AppLocalData });
+            if (await clearDraft()) {
                 filesDeleted++;
             }
 
-            // Eliminar archivo de configuración
-            const settingsExists = await exists(STORAGE_FILES.APP_SETTINGS, { baseDir: BaseDirectory.AppLocalData });
-            if (settingsExists) {
-                await remove(STORAGE_FILES.APP_SETTINGS, { baseDir: BaseDirectory.AppLocalData });
+            // Eliminar configuración guardada (settings://changed reinicia los valores en todas las ventanas)
+            if (await clearAppSettings()) {
                 filesDeleted++;
             }
-
-            // Reiniciar configuración a valores por defecto en memoria
-            updateSetting("actionsRespectFilters", false);
-            updateSetting("autoSave", true);
-            updateSetting("theme", "system");
-            updateSetting("openAfterExport", true);
-            updateSetting("clearScheduleOnLoad", false);
             setTheme("system"); // Aplicar reinicio de tema
 
             if (filesDeleted > 0) {
@@ -286,6 +277,29 @@ export function SettingsPage() {
                         </CardContent>
                     </Card>
 
+                    {/* Matching */}
+                    <Card className="shadow-none">
+                        <CardHeader>
+                            <CardTitle>{t("settings.matching.title")}</CardTitle>
+                            <CardDescription>
+                                {t("settings.matching.desc")}
+                            </CardDescription>
+                        </CardHeader>
+                        <CardContent>
+                            <div className="flex items-center justify-between space-x-2">
+                                <div className="space-y-2">
+                                    <Label>{t("settings.matching.aliases")}</Label>
+                                    <p className="text-xs text-muted-foreground">
+                                        {t("settings.matching.aliases_desc")}
+                                    </p>
+                                </div>
+                                <Button variant="outline" size="sm" onClick={() => setAliasesOpen(true)}>
+                                    {t("settings.matching.manage_aliases_btn")}
+                                </Button>
+                            </div>
+                        </CardContent>
+                    </Card>
+                    <ManageAliasesModal open={aliasesOpen} onOpenChange={setAliasesOpen} />
 
                     {/* System (New Block 2) */}
                     <Card className="shadow-none">
@@ -3,7 +3,6 @@ import { openUrl } from '@tauri-apps/plugin-opener';
 import { Button } from "@/components/ui/button";
 import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
 import { Loader2, Unplug, Link2, FileSpreadsheet, FolderOpen, Folder, RefreshCw, X } from "lucide-react";
-import { BaseDirectory, remove, exists } from "@tauri-apps/plugin-fs";
 import { supabase } from "@/lib/supabase";
 import { toast } from "sonner";
 import {
@@ -39,7 +38,7 @@ import { Label } from "@/components/ui/label";
 import { Checkbox } from "@/components/ui/checkbox";
 import { Separator } from "@/components/ui/separator";
 import { Badge } from "@/components/ui/badge";
-import { STORAGE_FILES } from "@/lib/constants";
+import { clearExcelMirror } from "@/lib/local-store";
 
 interface MicrosoftAccount {
     email: string;
@@ -197,9 +196,7 @@ export function MicrosoftIntegration({ onConfigChange }: MicrosoftIntegrationPro
 
             // Clear Cache
             try {
-                if (await exists(STORAGE_FILES.EXCEL_DATA_MIRROR, { baseDir: BaseDirectory.AppLocalData })) {
-                    await remove(STORAGE_FILES.EXCEL_DATA_MIRROR, { baseDir: BaseDirectory.AppLocalData });
-                }
+                await clearExcelMirror();
             } catch (ignore) { console.error("Failed to clear cache", ignore); }
 
             if (onConfigChange) onConfigChange();
@@ -239,9 +236,7 @@ export function MicrosoftIntegration({ onConfigChange }: MicrosoftIntegrationPro
 
             // Clear Cache on Config Change to prevent showing stale data from previous file
             try {
-                if (await exists(STORAGE_FILES.EXCEL_DATA_MIRROR, { baseDir: BaseDirectory.AppLocalData })) {
-                    await remove(STORAGE_FILES.EXCEL_DATA_MIRROR, { baseDir: BaseDirectory.AppLocalData });
-                }
+                await clearExcelMirror();
             } catch (ignore) { console.error("Failed to clear cache", ignore); }
 
             toast.success(`Linked ${configMode === 'schedules_folder' ? 'Folder' : 'File'}: ${item.name}`);
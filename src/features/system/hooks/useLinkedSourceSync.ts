@@ -1,10 +1,9 @@
 import { useState, useEffect, useCallback, useRef } from "react";
 import { supabase } from "@/lib/supabase";
-import { BaseDirectory, readTextFile, writeTextFile, exists } from "@tauri-apps/plugin-fs";
 import { toast } from "sonner";
 import SyncWorker from "../workers/sync-linked-source.worker.ts?worker"; // Importación de worker de Vite
 import type { SyncWorkerMessage, SyncWorkerResponse } from "../workers/sync-linked-source.worker";
-import { STORAGE_FILES } from "@/lib/constants";
+import { loadExcelMirror, saveExcelMirror, type ExcelMirror } from "@/lib/local-store";
 
 export function useLinkedSourceSync() {
     const [isSyncing, setIsSyncing] = useState(false);
@@ -62,10 +61,8 @@ export function useLinkedSourceSync() {
 
                 const currentFileId = config?.account?.incidences_file?.id;
 
-                const fileExists = await exists(STORAGE_FILES.EXCEL_DATA_MIRROR, { baseDir: BaseDirectory.AppLocalData });
-                if (fileExists) {
-                    const content = await readTextFile(STORAGE_FILES.EXCEL_DATA_MIRROR, { baseDir: BaseDirectory.AppLocalData });
-                    const parsed = JSON.parse(content);
+                const parsed = await loadExcelMirror();
+                if (parsed) {
 
                     // VALIDACIÓN CRÍTICA: El caché debe pertenecer al archivo configurado actualmente
                     if (currentFileId && parsed.fileId !== currentFileId) {
@@ -145,7 +142,7 @@ export function useLinkedSourceSync() {
                     // Ya se está sincronizando
                 } else if (type === 'SYNC_SUCCESS') {
                     // Guardar en caché
-                    const payload = {
+                    const payload: ExcelMirror = {
                         timestamp: e.data.timestamp,
                         data: e.data.data,
                         sheets: e.data.sheets,
@@ -154,9 +151,7 @@ export function useLinkedSourceSync() {
                     };
 
                     try {
-                        await writeTextFile(STORAGE_FILES.EXCEL_DATA_MIRROR, JSON.stringify(payload), {
-                            baseDir: BaseDirectory.AppLocalData
-                        });
+                        await saveExcelMirror(payload);
 
                         setCachedData(e.data.data);
                         setCachedSheets(e.data.sheets);
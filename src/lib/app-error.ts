@@ -0,0 +1,43 @@
+import i18n from "@/lib/i18n";
+
+// Error estructurado que retornan los comandos de Rust (ver src-tauri/src/error.rs)
+export interface AppError {
+    code: string;
+    messageKey: string;
+    message: string;
+    details: Record<string, unknown>;
+}
+
+export function isAppError(error: unknown): error is AppError {
+    return (
+        typeof error === "object" &&
+        error !== null &&
+        "code" in error &&
+        "messageKey" in error
+    );
+}
+
+// Sugerencias por código de error (claves de errors.fix.*)
+const ERROR_FIXES: Record<string, string> = {
+    PERMISSION_DENIED: "errors.fix.choose_another_folder",
+    DISK_FULL: "errors.fix.free_space",
+    NOT_FOUND: "errors.fix.reconnect_drive",
+};
+
+/**
+ * Traduce un error de un comando de Rust al idioma actual.
+ * Retorna el mensaje y, si existe, una sugerencia para resolverlo.
+ */
+export function describeAppError(error: unknown): { message: string; fix?: string } {
+    if (!isAppError(error)) {
+        return { message: String(error) };
+    }
+
+    const message = i18n.t(error.messageKey, {
+        ...error.details,
+        defaultValue: error.message,
+    });
+    const fixKey = ERROR_FIXES[error.code];
+
+    return { message, fix: fixKey ? i18n.t(fixKey) : undefined };
+}
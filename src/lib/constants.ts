@@ -1,11 +1,12 @@
 // Centralized constants for file operations
 
 // Archivos Físicos (AppLocalData)
+// Ajustes, borrador, incidencias y espejo del Excel viven en la base de datos (ver lib/local-store).
+// Los JSON anteriores (minerva_*.json y los legacy: minerva-settings.json, schedule_autosave.json,
+// incidences.json, linked_source_cache.json) se importan una vez al iniciar y quedan como *.imported.
 export const STORAGE_FILES = {
-    APP_SETTINGS: "minerva_app_settings.json",
-    EXCEL_DATA_MIRROR: "minerva_excel_data_mirror.json",
-    SCHEDULES_DRAFT: "minerva_schedules_draft.json",
-    INCIDENCES_LOG: "minerva_incidences_log.json",
+    DATABASE: "minerva.db", // Escrito por Rust (store)
+    EXPORT_DIRS: "minerva_export_dirs.json", // Escrito por Rust (save_file)
 };
 
 // Claves de LocalStorage
@@ -26,13 +27,5 @@ export const STORAGE_KEYS = {
     LOCALE: "minerva_locale",
 };
 
-// Legacy constants for gradual migration (or to be removed)
-export const LEGACY_FILES = {
-    SETTINGS: "minerva-settings.json",
-    AUTOSAVE: "schedule_autosave.json",
-    INCIDENCES: "incidences.json",
-    CACHE: "linked_source_cache.json"
-};
-
 // Debounce delay for auto-save (ms)
 export const AUTOSAVE_DEBOUNCE_MS = 3000; // 3 seconds
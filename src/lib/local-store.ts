@@ -0,0 +1,89 @@
+import { invoke } from '@tauri-apps/api/core';
+
+/**
+ * Base de datos local (AppLocalData/minerva.db, ver src-tauri/src/store).
+ * Cada guardado es una transacción; las listas solo reescriben las filas que cambiaron.
+ */
+
+// Base apartada al iniciar porque no se pudo abrir (dañada o bloqueada); se empezó con una nueva
+export interface RecoveredDatabase {
+    backup: string; // Path de la copia (minerva.db.corrupt-<ts>)
+    error: string;
+}
+export const getStorageRecovery = () => invoke<RecoveredDatabase | null>('get_storage_recovery');
+
+// Ajustes (versionados, con valores por defecto y validados en Rust, ver src-tauri/src/store/settings.rs).
+// Cada cambio se emite a todas las ventanas como SETTINGS_CHANGED_EVENT con los ajustes completos.
+export const SETTINGS_CHANGED_EVENT = 'settings://changed';
+export const getSettings = <T>() => invoke<T>('get_settings');
+/** Actualiza solo las claves de `patch`; si alguna es inválida no se guarda nada (INVALID_SETTINGS) */
+export const updateSettings = <T>(patch: Partial<T>) => invoke<T>('update_settings', { patch });
+/** Vuelve a los valores por defecto; retorna false si no había ajustes guardados */
+export const clearAppSettings = () => invoke<boolean>('clear_app_settings');
+
+// Borrador de horarios (filas validadas en Rust). Cada guardado deja un snapshot;
+// si el borrador no se puede leer, se recupera el snapshot válido más reciente.
+export interface LoadedDraft<T> {
+    schedules: T[];
+    source: 'draft' | 'snapshot';
+    snapshotAt?: number; // ms, solo si source = 'snapshot'
+    skipped: number; // Snapshots más recientes descartados por inválidos
+}
+
+export const loadDraft = <T>() => invoke<LoadedDraft<T>>('load_draft');
+/**
+ * Motivo del guardado (ver src-tauri/src/store/drafts.rs): sin motivo es un autoguardado.
+ * 'upload' y 'restore' conservan el borrador anterior en el historial; 'manual', el nuevo.
+ */
+// 'import': borrador de un JSON anterior que no reemplazó al actual (solo en el historial)
+export type SnapshotReason = 'autosave' | 'upload' | 'manual' | 'restore' | 'import';
+
+export const saveDraft = (schedules: object[], reason?: SnapshotReason) =>
+    invoke<void>('save_draft', { schedules, reason });
+export const clearDraft = () => invoke<boolean>('clear_draft');
+
+// Historial del borrador (línea de tiempo de snapshots, ver src-tauri/src/store/history.rs)
+export interface DraftChanges {
+    added: number;
+    removed: number;
+    instructorsChanged: number; // Misma clase (fecha, horario, programa) con otro instructor
+    modified: number; // Misma clase e instructor con otros datos
+}
+
+export interface DraftSnapshot {
+    id: number;
+    createdAt: number; // ms
+    rowCount: number;
+    changes: DraftChanges; // Respecto al snapshot anterior
+    reason: SnapshotReason;
+}
+
+export interface DraftDiff<T> {
+    added: T[];
+    removed: T[];
+    instructorChanges: { before: T; after: T }[];
+    modified: { before: T; after: T }[];
+}
+
+export const listDraftHistory = () => invoke<DraftSnapshot[]>('list_draft_history');
+/** Lo que cambiaría en el borrador actual al restaurar el snapshot */
+export const previewDraftSnapshot = <T>(id: number) => invoke<DraftDiff<T>>('preview_draft_snapshot', { id });
+/** Restaura el snapshot como borrador (queda como un snapshot más, así se puede deshacer) */
+export const restoreDraftSnapshot = <T>(id: number) => invoke<T[]>('restore_draft_snapshot', { id });
+
+// Registro de incidencias
+export const loadIncidences = <T>() => invoke<T[]>('load_incidences');
+export const saveIncidences = (incidences: object[]) => invoke<void>('save_incidences', { incidences });
+
+// Espejo del Excel vinculado
+export interface ExcelMirror<Row = unknown> {
+    fileId: string;
+    fileName: string;
+    timestamp: number;
+    sheets: unknown[];
+    data: Record<string, Row[]>;
+}
+
+export const loadExcelMirror = <Row = unknown>() => invoke<ExcelMirror<Row> | null>('load_excel_mirror');
+export const saveExcelMirror = (mirror: ExcelMirror) => invoke<void>('save_excel_mirror', { mirror });
+export const clearExcelMirror = () => invoke<boolean>('clear_excel_mirror');
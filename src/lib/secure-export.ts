@@ -1,11 +1,27 @@
 
 import { invoke } from "@tauri-apps/api/core";
 import { toast } from "sonner";
+import { describeAppError } from "@/lib/app-error";
+import type { Schedule, SheetError } from "@schedules/utils/excel-parser";
+
+// Tamaño máximo por request binario; archivos mayores se suben por partes
+const CHUNK_SIZE = 8 * 1024 * 1024;
+
+export interface SaveFileFilter {
+    name: string;
+    extensions: string[];
+}
+
+// Tipo de exportación: el diálogo abre en el último directorio usado para el mismo tipo
+export type SaveFileKind = "schedule_export" | "incidence_report" | "zoom_report";
 
 interface SaveFileOptions {
     title?: string;
     defaultName: string;
-    content: Uint8Array | number[];
+    // Tipos permitidos; la extensión se fuerza al primero si falta
+    filters?: SaveFileFilter[];
+    kind?: SaveFileKind;
+    content: Uint8Array;
     openAfterExport?: boolean;
 }
 
@@ -17,26 +33,138 @@ interface SaveFileOptions {
 export async function secureSaveFile({
     title = "Save File",
     defaultName,
+    filters = [],
+    kind,
     content,
     openAfterExport = true
 }: SaveFileOptions): Promise<boolean> {
     try {
-        // Convertir a Array.from si es estrictamente Uint8Array, ya que Tauri espera serialización
-        // number[] es seguro para Vec<u8>
-        const body = content instanceof Uint8Array ? Array.from(content) : content;
+        // Se envía como cuerpo binario, sin convertir a number[]
+        if (content.byteLength <= CHUNK_SIZE) {
+            return await invoke<boolean>("save_file_binary", content, {
+                headers: {
+                    "x-save-title": encodeURIComponent(title),
+                    "x-save-default-name": encodeURIComponent(defaultName),
+                    "x-save-filters": encodeURIComponent(JSON.stringify(filters)),
+                    ...(kind ? { "x-save-kind": kind } : {}),
+                    "x-save-open-file": String(openAfterExport)
+                }
+            });
+        }
+
+        // Archivos grandes: subida por partes antes de mostrar el diálogo
+        const id = await invoke<number>("save_upload_start", { name: defaultName });
+        try {
+            for (let offset = 0; offset < content.byteLength; offset += CHUNK_SIZE) {
+                await invoke("save_upload_chunk", content.subarray(offset, offset + CHUNK_SIZE), {
+                    headers: { "x-upload-id": String(id) }
+                });
+            }
+        } catch (error) {
+            await invoke("save_upload_cancel", { id }).catch(() => {});
+            throw error;
+        }
 
-        const saved = await invoke<boolean>("save_file", {
+        return await invoke<boolean>("save_upload_finish", {
+            id,
+            options: { title, defaultName, filters, kind },
+            openFile: openAfterExport
+        });
+    } catch (error) {
+        console.error("Failed to save file:", error);
+        const { message, fix } = describeAppError(error);
+        toast.error("Failed to save file: " + message, { description: fix });
+        throw error;
+    }
+}
+
+// Libro a generar en Rust: columnas de Schedule o de DailyIncidence
+export type ExcelExportKind = "schedule" | "incidences";
+
+interface ExportExcelOptions {
+    title?: string;
+    defaultName: string;
+    kind: ExcelExportKind;
+    rows: object[];
+    openAfterExport?: boolean;
+}
+
+/**
+ * Genera el libro .xlsx en Rust (tabla con estilos, anchos y cruces resaltados)
+ * y lo guarda con el diálogo nativo. Solo viajan las filas, no el archivo.
+ * Retorna true si se guardó, false si se canceló.
+ */
+export async function secureExportExcel({
+    title = "Save As",
+    defaultName,
+    kind,
+    rows,
+    openAfterExport = true
+}: ExportExcelOptions): Promise<boolean> {
+    try {
+        return await invoke<boolean>("export_excel", {
             title,
             defaultName,
-            content: body,
+            kind,
+            rows,
             openFile: openAfterExport
         });
-
-        return saved;
     } catch (error) {
-        console.error("Failed to save file:", error);
-        toast.error("Failed to save file: " + String(error));
+        console.error("Failed to export Excel:", error);
+        const { message, fix } = describeAppError(error);
+        toast.error("Failed to export Excel: " + message, { description: fix });
         throw error;
     }
 }
 
+interface OpenFileOptions {
+    title?: string;
+    filters?: SaveFileFilter[];
+    multiple?: boolean;
+    // Bytes por archivo; por defecto 20 MB (validado en Rust)
+    maxSize?: number;
+}
+
+export interface OpenedScheduleFile {
+    name: string;
+    size: number;
+    mode: "schedules";
+    schedules: Schedule[];
+    errors: SheetError[];
+}
+
+interface OpenedScheduleFiles {
+    files: OpenedScheduleFile[];
+    // Archivos elegidos que no se pudieron abrir (tipo, tamaño, lectura o libro inválido)
+    failed: { name: string; error: unknown }[];
+}
+
+/**
+ * Contraparte de secureSaveFile: el usuario elige los libros en el diálogo nativo
+ * y Rust valida tipo y tamaño antes de parsearlos.
+ * Los archivos que no se pudieron abrir se informan con un toast y se omiten.
+ * Retorna una lista vacía si se canceló.
+ */
+export async function secureOpenSchedules({
+    title = "Open File",
+    filters,
+    multiple = true,
+    maxSize
+}: OpenFileOptions = {}): Promise<OpenedScheduleFile[]> {
+    try {
+        const { files, failed } = await invoke<OpenedScheduleFiles>("open_file", {
+            options: { title, filters, multiple, maxSize, mode: "schedules" }
+        });
+        for (const { name, error } of failed) {
+            console.error(`Failed to open ${name}:`, error);
+            const { message, fix } = describeAppError(error);
+            toast.error(`Failed to open ${name}: ${message}`, { description: fix });
+        }
+        return files;
+    } catch (error) {
+        console.error("Failed to open file:", error);
+        const { message, fix } = describeAppError(error);
+        toast.error("Failed to open file: " + message, { description: fix });
+        throw error;
+    }
+}